@@ -0,0 +1,330 @@
+use std::env;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::blocking::{Client, Response};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+
+use crate::error::{Failure, GitError, Result};
+use crate::i18n::t;
+
+// ============================================================================
+// GITHUB API CLIENT
+// ============================================================================
+
+const DEFAULT_API_URL: &str = "https://api.github.com";
+
+const MAX_RETRIES: u32 = 3;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+// Waits longer than this are reported instead of slept through
+const MAX_RETRY_WAIT: Duration = Duration::from_secs(60);
+
+/// The API root: `GITHUB_API_URL` (as set by GitHub Actions and used for
+/// GitHub Enterprise, e.g. `https://github.example.com/api/v3`), then the
+/// `syncgit.apiUrl` git config, then public GitHub.
+pub fn base_url() -> String {
+    let configured = env::var("GITHUB_API_URL").ok().filter(|v| !v.trim().is_empty()).or_else(|| {
+        Command::new("git")
+            .args(["config", "--get", "syncgit.apiUrl"])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| String::from_utf8(o.stdout).ok())
+            .filter(|v| !v.trim().is_empty())
+    });
+    configured
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .unwrap_or_else(|| DEFAULT_API_URL.to_string())
+}
+
+/// The web host the API belongs to (`github.com` for the public API), used
+/// to look up stored credentials.
+pub fn web_host() -> String {
+    let base = base_url();
+    let host = base.split_once("://").map_or(base.as_str(), |(_, rest)| rest);
+    let host = host.split(['/', ':']).next().unwrap_or(host).to_lowercase();
+    host.strip_prefix("api.").map(str::to_string).unwrap_or(host)
+}
+
+/// A finished API call: status, headers, raw body and the `Link: rel="next"`
+/// URL.
+pub struct ApiResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+    next: Option<String>,
+}
+
+impl ApiResponse {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+
+    pub fn json(&self) -> Result<Value> {
+        if self.body.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        serde_json::from_str(&self.body)
+            .map_err(|e| GitError::Other(t!("github.parse_failed", error = e)))
+    }
+
+    /// Maps an unsuccessful response to the matching error.
+    pub fn into_error(self) -> GitError {
+        let error_msg = if self.body.is_empty() { t!("github.unknown_error") } else { self.body };
+
+        // Provide helpful error messages for common issues
+        if self.status == 401 {
+            GitError::Auth(Failure::new(t!("github.error_401", error = error_msg)))
+        } else if self.status == 403 {
+            GitError::Auth(Failure::new(t!("github.error_403", error = error_msg)))
+        } else if self.status == 404 {
+            GitError::Other(t!("github.error_404", error = error_msg))
+        } else if self.status == 422 {
+            GitError::Other(t!("github.error_422", error = error_msg))
+        } else {
+            GitError::Other(t!("github.error_status", status = self.status, error = error_msg))
+        }
+    }
+}
+
+/// Extracts the `rel="next"` target from a `Link` header.
+fn next_link(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get("link")?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        params.split(';')
+            .any(|p| p.trim() == "rel=\"next\"")
+            .then(|| url.trim().trim_start_matches('<').trim_end_matches('>').to_string())
+    })
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// What to do about a response that may be worth retrying.
+#[derive(Debug, PartialEq, Eq)]
+enum Throttle {
+    /// Retry after waiting this long.
+    Retry(Duration),
+    /// The primary rate limit is exhausted until this Unix time.
+    Exhausted(u64),
+    None,
+}
+
+/// A server error may come after the request took effect, so only requests
+/// that are safe to repeat are retried; a repeated POST could create the
+/// repository or release twice, or fail because the first one did. Rate
+/// limits reject the request before anything happens.
+fn throttle(method: &Method, status: StatusCode, headers: &HeaderMap, body: &str, attempt: u32) -> Throttle {
+    let backoff = INITIAL_BACKOFF * 2u32.pow(attempt);
+    if status.is_server_error() {
+        let idempotent = matches!(*method, Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS);
+        return if idempotent { Throttle::Retry(backoff) } else { Throttle::None };
+    }
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return Throttle::None;
+    }
+
+    // Secondary rate limits come with Retry-After or say so in the body
+    if let Some(seconds) = header_u64(headers, "retry-after") {
+        return Throttle::Retry(Duration::from_secs(seconds));
+    }
+    if body.to_lowercase().contains("secondary rate limit") {
+        return Throttle::Retry(backoff);
+    }
+    if header_u64(headers, "x-ratelimit-remaining") == Some(0) {
+        return Throttle::Exhausted(header_u64(headers, "x-ratelimit-reset").unwrap_or(0));
+    }
+    Throttle::None
+}
+
+fn rate_limit_error(reset: u64) -> GitError {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let minutes = reset.saturating_sub(now).div_ceil(60);
+    GitError::Network(Failure::new(t!("github.rate_limited", minutes = minutes)))
+}
+
+/// Shared client for every feature that talks to the GitHub REST API.
+pub struct ApiClient {
+    client: Client,
+    base_url: String,
+    token: String,
+}
+
+impl ApiClient {
+    pub fn new(token: &str) -> Self {
+        ApiClient {
+            client: Client::new(),
+            base_url: base_url(),
+            token: token.to_string(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            path.to_string()
+        } else {
+            format!("{}{}", self.base_url, path)
+        }
+    }
+
+    fn send_once(&self, method: &Method, url: &str, body: Option<&Value>) -> Result<Response> {
+        let mut request = self.client
+            .request(method.clone(), url)
+            .header("User-Agent", "syncgit")
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        request.send()
+            .map_err(|e| GitError::Network(Failure::new(t!("github.request_failed", error = e))))
+    }
+
+    /// Sends a request, retrying server errors (for idempotent methods) and
+    /// secondary rate limits with backoff. Unsuccessful statuses are returned, not turned into errors.
+    pub fn execute(&self, method: Method, path: &str, body: Option<&Value>) -> Result<ApiResponse> {
+        let url = self.url(path);
+        let mut attempt = 0;
+        loop {
+            let response = self.send_once(&method, &url, body)?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let text = response.text().unwrap_or_default();
+
+            if !status.is_success() {
+                match throttle(&method, status, &headers, &text, attempt) {
+                    Throttle::Retry(wait) if attempt < MAX_RETRIES && wait <= MAX_RETRY_WAIT => {
+                        println!("{}", t!("github.retrying", status = status, seconds = wait.as_secs()));
+                        thread::sleep(wait);
+                        attempt += 1;
+                        continue;
+                    }
+                    Throttle::Exhausted(reset) => return Err(rate_limit_error(reset)),
+                    _ => {}
+                }
+            }
+
+            let next = next_link(&headers);
+            return Ok(ApiResponse { status, headers, body: text, next });
+        }
+    }
+
+    fn call(&self, method: Method, path: &str, body: Option<&Value>) -> Result<Value> {
+        let response = self.execute(method, path, body)?;
+        if !response.is_success() {
+            return Err(response.into_error());
+        }
+        response.json()
+    }
+
+    pub fn get(&self, path: &str) -> Result<Value> {
+        self.call(Method::GET, path, None)
+    }
+
+    /// GETs a list endpoint, following `Link` pagination to the last page.
+    pub fn get_all(&self, path: &str) -> Result<Vec<Value>> {
+        let mut items = Vec::new();
+        let mut next = Some(path.to_string());
+        while let Some(page) = next {
+            let response = self.execute(Method::GET, &page, None)?;
+            if !response.is_success() {
+                return Err(response.into_error());
+            }
+            if let Value::Array(values) = response.json()? {
+                items.extend(values);
+            }
+            next = response.next;
+        }
+        Ok(items)
+    }
+
+    pub fn post(&self, path: &str, body: &Value) -> Result<Value> {
+        self.call(Method::POST, path, Some(body))
+    }
+
+    pub fn patch(&self, path: &str, body: &Value) -> Result<Value> {
+        self.call(Method::PATCH, path, Some(body))
+    }
+
+    pub fn put(&self, path: &str, body: &Value) -> Result<Value> {
+        self.call(Method::PUT, path, Some(body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn retries_server_errors_only_for_idempotent_methods() {
+        let none = HeaderMap::new();
+        let cases = [
+            (Method::GET, 502, 0, Throttle::Retry(Duration::from_secs(1))),
+            (Method::PUT, 500, 2, Throttle::Retry(Duration::from_secs(4))),
+            (Method::POST, 502, 0, Throttle::None),
+            (Method::PATCH, 503, 0, Throttle::None),
+            (Method::GET, 404, 0, Throttle::None),
+            (Method::GET, 422, 0, Throttle::None),
+        ];
+        for (method, status, attempt, expected) in cases {
+            let status = StatusCode::from_u16(status).unwrap();
+            assert_eq!(throttle(&method, status, &none, "", attempt), expected, "{} {}", method, status);
+        }
+    }
+
+    #[test]
+    fn recognises_rate_limits() {
+        let forbidden = StatusCode::FORBIDDEN;
+        assert_eq!(
+            throttle(&Method::POST, StatusCode::TOO_MANY_REQUESTS, &headers(&[("retry-after", "30")]), "", 0),
+            Throttle::Retry(Duration::from_secs(30))
+        );
+        assert_eq!(
+            throttle(&Method::POST, forbidden, &HeaderMap::new(), "You have exceeded a secondary rate limit", 1),
+            Throttle::Retry(Duration::from_secs(2))
+        );
+        assert_eq!(
+            throttle(&Method::GET, forbidden, &headers(&[("x-ratelimit-remaining", "0"), ("x-ratelimit-reset", "1700000000")]), "", 0),
+            Throttle::Exhausted(1700000000)
+        );
+        // A plain permission error isn't worth retrying
+        assert_eq!(
+            throttle(&Method::GET, forbidden, &headers(&[("x-ratelimit-remaining", "4999")]), "Resource not accessible", 0),
+            Throttle::None
+        );
+    }
+
+    #[test]
+    fn follows_the_next_link() {
+        let link = headers(&[(
+            "link",
+            "<https://api.github.com/user/repos?page=2>; rel=\"next\", <https://api.github.com/user/repos?page=5>; rel=\"last\"",
+        )]);
+        assert_eq!(next_link(&link).as_deref(), Some("https://api.github.com/user/repos?page=2"));
+
+        let last_page = headers(&[(
+            "link",
+            "<https://api.github.com/user/repos?page=4>; rel=\"prev\", <https://api.github.com/user/repos?page=1>; rel=\"first\"",
+        )]);
+        assert_eq!(next_link(&last_page), None);
+        assert_eq!(next_link(&HeaderMap::new()), None);
+    }
+}
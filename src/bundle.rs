@@ -0,0 +1,370 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::{check_git_conflicts, GitRepo, UI};
+
+// ============================================================================
+// BUNDLE SYNC
+// ============================================================================
+
+// For machines that never see the network, commits travel as git bundles.
+// `refs/syncgit/known/<branch>` records the newest commit the other machine
+// is known to have: updated to the exported tip on export and to the bundle
+// tip on import. Exports only contain commits beyond those refs, so each
+// bundle stays small; `--full` ignores them when a bundle went missing.
+
+const KNOWN_PREFIX: &str = "refs/syncgit/known/";
+// Where imported branches are kept for inspection, like a remote
+const IMPORTED_PREFIX: &str = "refs/remotes/bundle/";
+const PREVIEW_COMMITS: usize = 10;
+
+#[derive(Debug, Default)]
+pub struct ExportOptions {
+    pub file: Option<String>,
+    /// Every local branch instead of only the current one.
+    pub all: bool,
+    /// Ignore the known refs and include the whole history.
+    pub full: bool,
+}
+
+fn known_ref(branch: &str) -> String {
+    format!("{}{}", KNOWN_PREFIX, branch)
+}
+
+/// Makes a path given on the command line absolute, since git runs with
+/// `-C <repo root>`.
+fn absolute(file: &str) -> Result<PathBuf> {
+    let path = Path::new(file);
+    if path.is_absolute() {
+        return Ok(path.to_path_buf());
+    }
+    let path = env::current_dir()
+        .map(|dir| dir.join(path))
+        .map_err(|e| GitError::io(t!("error.current_dir"), e))?;
+    // Resolve `..` for display, as far as the directory exists
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name()) else {
+        return Ok(path);
+    };
+    Ok(fs::canonicalize(dir).map(|dir| dir.join(name)).unwrap_or(path))
+}
+
+fn local_branches(repo: &GitRepo) -> Result<Vec<String>> {
+    Ok(repo.run_command_with_output(&["for-each-ref", "--format=%(refname:short)", "refs/heads/"])?
+        .lines()
+        .map(str::to_string)
+        .collect())
+}
+
+fn resolve(repo: &GitRepo, reference: &str) -> Option<String> {
+    repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", reference]).ok()
+}
+
+fn count(repo: &GitRepo, range: &[&str]) -> usize {
+    let mut args = vec!["rev-list", "--count"];
+    args.extend_from_slice(range);
+    repo.run_command_with_output(&args).ok().and_then(|n| n.parse().ok()).unwrap_or(0)
+}
+
+fn print_commits(repo: &GitRepo, range: &str) {
+    let log = repo.run_command_with_output(&["log", "--format=%h %s (%an, %ar)", "-n", &PREVIEW_COMMITS.to_string(), range, "--"]);
+    for line in log.unwrap_or_default().lines() {
+        println!("      {}", line);
+    }
+}
+
+// ============================================================================
+// EXPORT
+// ============================================================================
+
+/// A branch to export and the commit the other side already has, if known.
+struct Export {
+    branch: String,
+    known: Option<String>,
+    commits: usize,
+}
+
+/// `syncgit bundle export`: writes the commits the other machine is missing
+/// to a bundle file.
+pub fn export(options: &ExportOptions) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    let branches = if options.all {
+        local_branches(&repo)?
+    } else {
+        vec![repo.run_command_with_output(&["symbolic-ref", "--short", "HEAD"])
+            .map_err(|_| GitError::Other(t!("error.detached_head")))?]
+    };
+
+    let exports: Vec<Export> = branches.into_iter()
+        .map(|branch| {
+            let known = if options.full { None } else { resolve(&repo, &known_ref(&branch)) };
+            let exclude = known.as_ref().map(|k| format!("^{}", k));
+            let mut range = vec![branch.as_str()];
+            range.extend(exclude.as_deref());
+            let commits = count(&repo, &range);
+            Export { branch, known, commits }
+        })
+        .filter(|e| e.commits > 0)
+        .collect();
+
+    if exports.is_empty() {
+        println!("{}", UI::center_text(&t!("bundle.nothing_to_export")));
+        println!("{}", UI::center_text(&t!("bundle.full_hint")));
+        return Ok(());
+    }
+
+    let default_name = if options.all {
+        format!("{}.bundle", repo.name)
+    } else {
+        format!("{}-{}.bundle", repo.name, exports[0].branch.replace('/', "-"))
+    };
+    let path = absolute(options.file.as_deref().unwrap_or(&default_name))?;
+    if path.exists() && !UI::prompt_yes_no(&t!("bundle.overwrite", path = path.display())) {
+        return Err(GitError::Cancelled(t!("cancel.operation")));
+    }
+
+    UI::print_separator();
+    println!("{}", UI::center_text(&t!("bundle.export_title", path = path.display())));
+    UI::print_separator();
+    let mut args = vec!["bundle".to_string(), "create".to_string(), "-q".to_string(), path.display().to_string()];
+    for export in &exports {
+        match &export.known {
+            Some(known) => println!("{}", tn!("bundle.export_branch_since", export.commits, branch = export.branch, known = &known[..7.min(known.len())])),
+            None => println!("{}", tn!("bundle.export_branch", export.commits, branch = export.branch)),
+        }
+        args.push(format!("refs/heads/{}", export.branch));
+        if let Some(known) = &export.known {
+            args.push(format!("^{}", known));
+        }
+    }
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    repo.run_command_with_output(&args)?;
+
+    // Assume the bundle arrives; `--full` recovers if it doesn't
+    for export in &exports {
+        repo.run_command_with_output(&["update-ref", &known_ref(&export.branch), &format!("refs/heads/{}", export.branch)])?;
+    }
+
+    let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    UI::print_separator();
+    println!("{}", UI::center_text(&t!("bundle.exported", path = path.display(), size = format_size(size))));
+    println!("{}", UI::center_text(&t!("bundle.import_hint", file = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default())));
+    Ok(())
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1024 * 1024 => format!("{:.1} MiB", b as f64 / (1024.0 * 1024.0)),
+        b if b >= 1024 => format!("{:.1} KiB", b as f64 / 1024.0),
+        b => format!("{} B", b),
+    }
+}
+
+// ============================================================================
+// IMPORT
+// ============================================================================
+
+/// The branches in a bundle, as `(branch, commit)`.
+fn bundle_heads(repo: &GitRepo, path: &str) -> Result<Vec<(String, String)>> {
+    Ok(repo.run_command_with_output(&["bundle", "list-heads", path])?
+        .lines()
+        .filter_map(|line| {
+            let (commit, name) = line.split_once(' ')?;
+            let branch = name.strip_prefix("refs/heads/")?;
+            Some((branch.to_string(), commit.to_string()))
+        })
+        .collect())
+}
+
+/// Checks the bundle is intact and that this repository has every commit it
+/// builds on.
+fn verify(repo: &GitRepo, path: &str) -> Result<()> {
+    let output = repo.create_command(["bundle", "verify", path])
+        .output()
+        .map_err(|e| GitError::io(t!("error.exec_git"), e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let missing = missing_prerequisites(&stderr);
+    if missing.is_empty() {
+        return Err(GitError::from_command(&["bundle", "verify", path], output.status, &stderr));
+    }
+    println!("{}", UI::center_text(&tn!("bundle.missing_prerequisites", missing.len())));
+    for commit in &missing {
+        println!("      {}", commit);
+    }
+    Err(GitError::Other(t!("bundle.export_full_hint")))
+}
+
+/// The commits `git bundle verify` says this repository lacks. They are
+/// listed one per line after the "lacks these prerequisite commits" line,
+/// as `error: <sha> [subject]`.
+fn missing_prerequisites(stderr: &str) -> Vec<&str> {
+    stderr.lines()
+        .map(|l| l.trim().trim_start_matches("error:").trim())
+        .filter(|l| l.len() >= 40 && l.chars().take(40).all(|c| c.is_ascii_hexdigit()))
+        .collect()
+}
+
+/// `syncgit bundle import <file>`: verifies a bundle, shows what it brings
+/// and merges it the way `git pull` would.
+pub fn import(file: &str) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    let path = absolute(file)?;
+    if !path.is_file() {
+        return Err(GitError::Usage(t!("bundle.not_found", path = path.display())));
+    }
+    let path = path.display().to_string();
+
+    check_git_conflicts(&repo)?;
+    verify(&repo, &path)?;
+    let heads = bundle_heads(&repo, &path)?;
+    if heads.is_empty() {
+        return Err(GitError::Other(t!("bundle.no_branches")));
+    }
+
+    // Keep a copy of every branch under bundle/ so it can be inspected
+    repo.run_command_with_output(&["fetch", "-q", "--no-tags", &path, &format!("+refs/heads/*:{}*", IMPORTED_PREFIX)])?;
+
+    let current = repo.run_command_with_output(&["symbolic-ref", "--short", "HEAD"]).ok();
+    UI::print_separator();
+    println!("{}", UI::center_text(&t!("bundle.import_title", path = path)));
+    UI::print_separator();
+    let mut incoming = Vec::new();
+    for (branch, commit) in &heads {
+        let local = format!("refs/heads/{}", branch);
+        let range = match resolve(&repo, &local) {
+            Some(_) => format!("{}..{}", local, commit),
+            None => commit.clone(),
+        };
+        let new_commits = count(&repo, &[&range]);
+        if new_commits == 0 {
+            println!("{}", t!("bundle.branch_up_to_date", branch = branch));
+            continue;
+        }
+        println!("{}", tn!("bundle.branch_incoming", new_commits, branch = branch));
+        print_commits(&repo, &range);
+        incoming.push((branch, commit));
+    }
+    UI::print_separator();
+
+    if incoming.is_empty() {
+        record_known(&repo, &heads)?;
+        println!("{}", UI::center_text(&t!("bundle.nothing_new")));
+        return Ok(());
+    }
+    if !UI::prompt_yes_no(&t!("bundle.import_confirm")) {
+        return Err(GitError::Cancelled(t!("cancel.operation")));
+    }
+
+    let mut diverged = Vec::new();
+    for (branch, commit) in incoming {
+        if current.as_deref() == Some(branch.as_str()) {
+            // Same as the normal sync: pull, honouring pull.rebase and pull.ff
+            println!("{}", t!("bundle.merging", branch = branch));
+            repo.run_command(&["pull", "--no-edit", &path, branch])?;
+        } else if resolve(&repo, &format!("refs/heads/{}", branch)).is_none() {
+            repo.run_command_with_output(&["branch", branch, commit])?;
+            println!("{}", t!("bundle.created_branch", branch = branch));
+        } else if repo.run_command_with_output(&["merge-base", "--is-ancestor", branch, commit]).is_ok() {
+            repo.run_command_with_output(&["update-ref", &format!("refs/heads/{}", branch), commit])?;
+            println!("{}", t!("bundle.fast_forwarded", branch = branch));
+        } else {
+            println!("{}", t!("bundle.diverged", branch = branch, imported = format!("bundle/{}", branch)));
+            diverged.push(branch.clone());
+        }
+    }
+
+    record_known(&repo, &heads)?;
+    UI::print_separator();
+    println!("{}", UI::center_text(&t!("bundle.imported")));
+    if !diverged.is_empty() {
+        println!("{}", UI::center_text(&t!("bundle.diverged_hint")));
+    }
+    Ok(())
+}
+
+/// Everything in the bundle is on the other machine.
+fn record_known(repo: &GitRepo, heads: &[(String, String)]) -> Result<()> {
+    for (branch, commit) in heads {
+        repo.run_command_with_output(&["update-ref", &known_ref(branch), commit])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_known_refs() {
+        assert_eq!(known_ref("main"), "refs/syncgit/known/main");
+        assert_eq!(known_ref("feature/login"), "refs/syncgit/known/feature/login");
+    }
+
+    #[test]
+    fn formats_sizes() {
+        let cases = [
+            (0, "0 B"),
+            (1023, "1023 B"),
+            (1536, "1.5 KiB"),
+            (5 * 1024 * 1024, "5.0 MiB"),
+        ];
+        for (bytes, expected) in cases {
+            assert_eq!(format_size(bytes), expected, "{}", bytes);
+        }
+    }
+
+    #[test]
+    fn makes_paths_absolute() {
+        let dir = env::temp_dir().join(format!("syncgit-bundle-absolute-{}", std::process::id()));
+        let file = dir.join("sync.bundle");
+        assert_eq!(absolute(&file.display().to_string()).unwrap(), file);
+
+        let relative = absolute("sync.bundle").unwrap();
+        assert!(relative.is_absolute());
+        assert_eq!(relative.file_name().unwrap(), "sync.bundle");
+        // A directory that doesn't exist is joined as given
+        let missing = absolute("no-such-dir/sync.bundle").unwrap();
+        assert!(missing.ends_with("no-such-dir/sync.bundle"));
+    }
+
+    #[test]
+    fn reads_missing_prerequisites() {
+        let stderr = "\
+error: Repository lacks these prerequisite commits:
+error: 6b1f0f5c6d1c9e2f3a4b5c6d7e8f90123456789a Add the settings page
+error: 0123456789abcdef0123456789abcdef01234567
+";
+        assert_eq!(
+            missing_prerequisites(stderr),
+            ["6b1f0f5c6d1c9e2f3a4b5c6d7e8f90123456789a Add the settings page", "0123456789abcdef0123456789abcdef01234567"],
+        );
+        assert!(missing_prerequisites("error: 'sync.bundle' does not look like a v2 or v3 bundle file").is_empty());
+    }
+
+    #[test]
+    fn lists_bundle_branches() {
+        let root = env::temp_dir().join(format!("syncgit-bundle-heads-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let repo = GitRepo { root: root.clone(), name: "heads".to_string() };
+        let git = |args: &[&str]| repo.run_command_with_output(args).unwrap();
+        git(&["init", "-q", "-b", "main"]);
+        git(&["-c", "user.name=Ada", "-c", "user.email=ada@example.com", "commit", "-q", "--allow-empty", "-m", "first"]);
+        git(&["branch", "feature/login"]);
+        git(&["tag", "v1.0.0"]);
+        let head = git(&["rev-parse", "HEAD"]);
+        let bundle = root.join("all.bundle").display().to_string();
+        git(&["bundle", "create", "-q", &bundle, "--all"]);
+
+        let mut heads = bundle_heads(&repo, &bundle).unwrap();
+        heads.sort();
+        let _ = fs::remove_dir_all(&root);
+        assert_eq!(heads, [("feature/login".to_string(), head.clone()), ("main".to_string(), head)]);
+    }
+}
@@ -0,0 +1,533 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+
+use crate::api;
+use crate::auth;
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::push;
+use crate::release::{self, Commit, Version};
+use crate::{GitRepo, UI};
+
+// ============================================================================
+// CHANGELOG
+// ============================================================================
+
+// The file follows Keep a Changelog (https://keepachangelog.com), whose
+// section names are part of the format, so the file itself is never
+// translated.
+
+const FILE_NAME: &str = "CHANGELOG.md";
+
+const HEADER: &str = "# Changelog\n\n\
+All notable changes to this project will be documented in this file.\n\n\
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),\n\
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).\n";
+
+const UNRELEASED: &str = "Unreleased";
+
+/// Keep a Changelog's categories, in the order it lists them, with the
+/// Conventional Commit types filed under each.
+const CATEGORIES: [(&str, &[&str]); 6] = [
+    ("Added", &["feat", "add"]),
+    ("Changed", &["perf", "refactor", "change", "revert"]),
+    ("Deprecated", &["deprecate"]),
+    ("Removed", &["remove"]),
+    ("Fixed", &["fix"]),
+    ("Security", &["security", "sec"]),
+];
+
+/// Types that don't change anything a user of the project would notice.
+const SKIPPED: [&str; 8] = ["docs", "style", "test", "tests", "build", "ci", "chore", "release"];
+
+/// Any other type counts as a change.
+const OTHER_CATEGORY: &str = "Changed";
+
+/// Heading for commits touching only files at the repository root.
+const ROOT_GROUP: &str = "(root)";
+
+#[derive(Debug, Default)]
+pub struct Options {
+    /// Print the changelog instead of writing `CHANGELOG.md`.
+    pub stdout: bool,
+}
+
+// ============================================================================
+// FORGE LINKS
+// ============================================================================
+
+enum ForgeKind {
+    GitHub,
+    GitLab,
+}
+
+/// The web pages of the repository the current branch pushes to, when they
+/// are on a forge whose URL scheme we know.
+struct Forge {
+    kind: ForgeKind,
+    /// `https://host/owner/repo`
+    base: String,
+}
+
+impl Forge {
+    fn detect(repo: &GitRepo) -> Option<Self> {
+        let (host, path) = push::push_url(repo).and_then(|url| auth::split_remote(&url))?;
+        let kind = if host == "github.com" || host == api::web_host() {
+            ForgeKind::GitHub
+        } else if host.split('.').any(|label| label == "gitlab") {
+            ForgeKind::GitLab
+        } else {
+            return None;
+        };
+        Some(Forge { kind, base: format!("https://{}/{}", host, path) })
+    }
+
+    fn commit(&self, hash: &str) -> String {
+        match self.kind {
+            ForgeKind::GitHub => format!("{}/commit/{}", self.base, hash),
+            ForgeKind::GitLab => format!("{}/-/commit/{}", self.base, hash),
+        }
+    }
+
+    fn compare(&self, from: &str, to: &str) -> String {
+        match self.kind {
+            ForgeKind::GitHub => format!("{}/compare/{}...{}", self.base, from, to),
+            ForgeKind::GitLab => format!("{}/-/compare/{}...{}", self.base, from, to),
+        }
+    }
+
+    fn tag(&self, tag: &str) -> String {
+        match self.kind {
+            ForgeKind::GitHub => format!("{}/releases/tag/{}", self.base, tag),
+            ForgeKind::GitLab => format!("{}/-/tags/{}", self.base, tag),
+        }
+    }
+
+    /// `#12` is an issue or pull request on GitHub (which redirects between
+    /// the two), an issue on GitLab, where `!12` is a merge request.
+    fn reference(&self, sigil: char, number: &str) -> Option<String> {
+        match (&self.kind, sigil) {
+            (ForgeKind::GitHub, '#') => Some(format!("{}/issues/{}", self.base, number)),
+            (ForgeKind::GitLab, '#') => Some(format!("{}/-/issues/{}", self.base, number)),
+            (ForgeKind::GitLab, '!') => Some(format!("{}/-/merge_requests/{}", self.base, number)),
+            _ => None,
+        }
+    }
+
+    /// Turns the `#12` and `!12` references in a commit summary into links.
+    fn link_references(&self, text: &str) -> String {
+        let mut linked = String::new();
+        let mut rest = text;
+        while let Some(index) = rest.find(['#', '!']) {
+            let (before, at) = rest.split_at(index);
+            let sigil = at.chars().next().unwrap_or('#');
+            let digits: String = at[1..].chars().take_while(char::is_ascii_digit).collect();
+            let starts_word = before.chars().last().is_none_or(|c| c.is_whitespace() || c == '(');
+            linked.push_str(before);
+            match self.reference(sigil, &digits).filter(|_| starts_word && !digits.is_empty()) {
+                Some(url) => {
+                    linked.push_str(&format!("[{}{}]({})", sigil, digits, url));
+                    rest = &at[1 + digits.len()..];
+                }
+                None => {
+                    linked.push(sigil);
+                    rest = &at[1..];
+                }
+            }
+        }
+        linked.push_str(rest);
+        linked
+    }
+}
+
+// ============================================================================
+// GENERATION
+// ============================================================================
+
+/// One `## [...]` section to generate: the commits after `from` up to `to`.
+struct Release {
+    /// `Unreleased` or the version, as in the heading.
+    name: String,
+    /// Tag the range starts after; `None` for the first release.
+    from: Option<String>,
+    /// Tag or `HEAD`.
+    to: String,
+    date: Option<String>,
+}
+
+/// The top-level folder each commit in `range` changed most files in.
+fn commit_folders(repo: &GitRepo, range: &str) -> HashMap<String, String> {
+    let output = repo.run_command_with_output(&["log", "--no-merges", "--name-only", "--format=%x1e%H", range, "--"])
+        .unwrap_or_default();
+    output.split('\x1e')
+        .filter_map(|record| {
+            let mut lines = record.lines().filter(|line| !line.is_empty());
+            let hash = lines.next()?.to_string();
+            let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+            for path in lines {
+                let folder = path.split_once('/').map_or(ROOT_GROUP, |(folder, _)| folder);
+                *counts.entry(folder).or_default() += 1;
+            }
+            // Ties go to the folder that sorts first
+            let folder = counts.iter().rev().max_by_key(|(_, count)| **count)?.0.to_string();
+            Some((hash, folder))
+        })
+        .collect()
+}
+
+fn category(commit: &Commit) -> Option<&'static str> {
+    let kind = commit.kind.as_deref()?;
+    if SKIPPED.contains(&kind) && !commit.breaking {
+        return None;
+    }
+    let category = CATEGORIES.iter().find(|(_, kinds)| kinds.contains(&kind));
+    Some(category.map_or(OTHER_CATEGORY, |(name, _)| name))
+}
+
+fn entry(commit: &Commit, forge: Option<&Forge>) -> String {
+    let mut line = String::from("- ");
+    if commit.breaking {
+        line.push_str("**BREAKING:** ");
+    }
+    if let Some(scope) = &commit.scope {
+        line.push_str(&format!("**{}:** ", scope));
+    }
+    match forge {
+        Some(forge) => {
+            line.push_str(&forge.link_references(&commit.summary));
+            line.push_str(&format!(" ([{}]({}))", commit.short_hash(), forge.commit(&commit.hash)));
+        }
+        None => line.push_str(&format!("{} ({})", commit.summary, commit.short_hash())),
+    }
+    line
+}
+
+/// The body of a release's section, or `None` if nothing in it is worth
+/// listing. Conventional commits go under their category, the rest under
+/// the top-level folder they touch.
+fn render_release(repo: &GitRepo, release: &Release, forge: Option<&Forge>) -> Option<String> {
+    let range = match &release.from {
+        Some(from) => format!("{}..{}", from, release.to),
+        None => release.to.clone(),
+    };
+    let commits = release::commits(repo, &range);
+    let mut categories: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+    let mut folders: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut folder_of: Option<HashMap<String, String>> = None;
+
+    // Oldest first, the order things happened in
+    for commit in commits.iter().rev() {
+        if commit.kind.is_some() {
+            let Some(name) = category(commit) else { continue };
+            let index = CATEGORIES.iter().position(|(n, _)| *n == name).unwrap_or(0);
+            categories.entry(index).or_default().push(entry(commit, forge));
+        } else {
+            let folder_of = folder_of.get_or_insert_with(|| commit_folders(repo, &range));
+            let folder = folder_of.get(&commit.hash).cloned().unwrap_or_else(|| ROOT_GROUP.to_string());
+            folders.entry(folder).or_default().push(entry(commit, forge));
+        }
+    }
+    if categories.is_empty() && folders.is_empty() {
+        return None;
+    }
+
+    let sections = categories.into_iter()
+        .map(|(index, entries)| (CATEGORIES[index].0.to_string(), entries))
+        .chain(folders);
+    let body: Vec<String> = sections
+        .map(|(title, entries)| format!("### {}\n\n{}\n", title, entries.join("\n")))
+        .collect();
+    Some(body.join("\n"))
+}
+
+fn heading(release: &Release) -> String {
+    match &release.date {
+        Some(date) => format!("## [{}] - {}", release.name, date),
+        None => format!("## [{}]", release.name),
+    }
+}
+
+/// `[1.2.0]: <compare link>` for each release, when the forge is known.
+fn link_definitions(releases: &[Release], forge: &Forge) -> Vec<(String, String)> {
+    releases.iter()
+        .filter_map(|release| {
+            let url = match &release.from {
+                Some(from) => forge.compare(from, &release.to),
+                None if release.name == UNRELEASED => return None,
+                None => forge.tag(&release.to),
+            };
+            Some((release.name.clone(), url))
+        })
+        .collect()
+}
+
+/// The releases to describe, newest first: what's unreleased, then one per
+/// version tag reachable from HEAD.
+fn releases(repo: &GitRepo) -> Vec<Release> {
+    let tags = release::version_tags(repo, "HEAD");
+    let date = |tag: &str| {
+        repo.run_command_with_output(&["for-each-ref", "--format=%(creatordate:short)", &format!("refs/tags/{}", tag)])
+            .ok()
+            .filter(|date| !date.is_empty())
+    };
+    let mut releases = vec![Release {
+        name: UNRELEASED.to_string(),
+        from: tags.first().map(|(tag, _)| tag.clone()),
+        to: String::from("HEAD"),
+        date: None,
+    }];
+    for (index, (tag, version)) in tags.iter().enumerate() {
+        releases.push(Release {
+            name: version.to_string(),
+            from: tags.get(index + 1).map(|(tag, _)| tag.clone()),
+            to: tag.clone(),
+            date: date(tag),
+        });
+    }
+    releases
+}
+
+// ============================================================================
+// EXISTING FILE
+// ============================================================================
+
+/// A changelog split into the text before the first release, its `## `
+/// sections and the link definitions at the bottom.
+#[derive(Default)]
+struct Document {
+    preamble: String,
+    /// Heading label (`Unreleased`, `1.2.0`, ...) and the full section text.
+    sections: Vec<(String, String)>,
+    links: Vec<(String, String)>,
+}
+
+fn link_definition(line: &str) -> Option<(String, String)> {
+    let (label, url) = line.strip_prefix('[')?.split_once("]: ")?;
+    Some((label.to_string(), url.trim().to_string()))
+}
+
+impl Document {
+    fn parse(text: &str) -> Self {
+        let mut document = Document::default();
+        let lines: Vec<&str> = text.lines().collect();
+        // Only the block of definitions ending the file is the link list; the
+        // same syntax inside a section is part of that section
+        let body_end = lines.iter()
+            .rposition(|line| !line.trim().is_empty() && link_definition(line).is_none())
+            .map_or(0, |index| index + 1);
+        document.links = lines[body_end..].iter().filter_map(|line| link_definition(line)).collect();
+
+        let mut current: Option<(String, String)> = None;
+        for line in &lines[..body_end] {
+            if let Some(title) = line.strip_prefix("## ") {
+                document.sections.extend(current.take());
+                let label = title.strip_prefix('[')
+                    .and_then(|t| t.split_once(']'))
+                    .map_or(title, |(label, _)| label);
+                current = Some((label.trim().to_string(), String::new()));
+            }
+            let target = match current.as_mut() {
+                Some((_, section)) => section,
+                None => &mut document.preamble,
+            };
+            target.push_str(line);
+            target.push('\n');
+        }
+        document.sections.extend(current);
+        document
+    }
+
+    fn section(&self, label: &str) -> Option<&String> {
+        self.sections.iter().find(|(l, _)| l == label).map(|(_, text)| text)
+    }
+
+    fn render(&self) -> String {
+        let mut text = self.preamble.trim_end().to_string();
+        for (_, section) in &self.sections {
+            text.push_str("\n\n");
+            text.push_str(section.trim_end());
+        }
+        if !self.links.is_empty() {
+            text.push_str("\n\n");
+            for (label, url) in &self.links {
+                text.push_str(&format!("[{}]: {}\n", label, url));
+            }
+        }
+        format!("{}\n", text.trim_end())
+    }
+}
+
+/// Merges the generated changelog into `existing`: the Unreleased section
+/// is regenerated, released versions already written up are kept as they
+/// are, and new versions are added in order. Returns the new document and
+/// how many versions it added.
+fn merge(existing: Option<&str>, generated: Vec<(String, String)>, links: Vec<(String, String)>) -> (Document, usize) {
+    let old = existing.map(Document::parse).unwrap_or_default();
+    let mut document = Document {
+        preamble: if old.preamble.trim().is_empty() { HEADER.to_string() } else { old.preamble.clone() },
+        ..Document::default()
+    };
+    let mut added = 0;
+    for (label, section) in generated {
+        match old.section(&label) {
+            Some(kept) if label != UNRELEASED => document.sections.push((label, kept.clone())),
+            _ => {
+                added += usize::from(label != UNRELEASED);
+                document.sections.push((label, section));
+            }
+        }
+    }
+    // Hand-written sections for versions that have no tag, in version order
+    for (label, section) in &old.sections {
+        if document.section(label).is_none() {
+            let position = Version::parse(label).and_then(|version| {
+                document.sections.iter().position(|(l, _)| Version::parse(l).is_some_and(|v| v < version))
+            });
+            let position = position.unwrap_or(document.sections.len());
+            document.sections.insert(position, (label.clone(), section.clone()));
+        }
+    }
+
+    document.links = links;
+    for (label, url) in old.links {
+        if !document.links.iter().any(|(l, _)| *l == label) {
+            document.links.push((label, url));
+        }
+    }
+    (document, added)
+}
+
+// ============================================================================
+// COMMAND
+// ============================================================================
+
+/// `syncgit changelog [--stdout]`: writes or updates `CHANGELOG.md` at the
+/// repository root from the commits between version tags.
+pub fn changelog(options: &Options) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    if repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", "HEAD"]).is_err() {
+        return Err(GitError::Usage(t!("release.no_commits")));
+    }
+    let forge = Forge::detect(&repo);
+    let releases = releases(&repo);
+
+    let generated: Vec<(String, String)> = releases.iter()
+        .filter_map(|release| {
+            let body = render_release(&repo, release, forge.as_ref());
+            // Keep a Changelog keeps an Unreleased section even when empty
+            if body.is_none() && release.name != UNRELEASED {
+                return None;
+            }
+            let section = match body {
+                Some(body) => format!("{}\n\n{}", heading(release), body),
+                None => heading(release),
+            };
+            Some((release.name.clone(), section))
+        })
+        .collect();
+    let links = forge.as_ref()
+        .map(|forge| link_definitions(&releases, forge))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(label, _)| generated.iter().any(|(l, _)| l == label))
+        .collect();
+
+    let path = repo.root.join(FILE_NAME);
+    let existing = match fs::read_to_string(&path) {
+        Ok(text) => Some(text),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(GitError::io(t!("error.read_file", path = FILE_NAME), e)),
+    };
+    let (document, added) = merge(existing.as_deref(), generated, links);
+    let text = document.render();
+
+    if options.stdout {
+        print!("{}", text);
+        return Ok(());
+    }
+    if existing.as_deref() == Some(text.as_str()) {
+        println!("{}", UI::center_text(&t!("changelog.up_to_date", file = FILE_NAME)));
+        return Ok(());
+    }
+    fs::write(&path, &text).map_err(|e| GitError::io(t!("error.write_file", path = FILE_NAME), e))?;
+    let message = match existing {
+        Some(_) => tn!("changelog.updated", added, file = FILE_NAME),
+        None => tn!("changelog.created", document.sections.len() - 1, file = FILE_NAME),
+    };
+    println!("{}", UI::center_text(&message));
+    if forge.is_none() {
+        println!("{}", UI::center_text(&t!("changelog.no_forge")));
+    }
+    println!("{}", UI::center_text(&t!("changelog.review_hint")));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn github() -> Forge {
+        Forge { kind: ForgeKind::GitHub, base: "https://github.com/o/r".to_string() }
+    }
+
+    fn gitlab() -> Forge {
+        Forge { kind: ForgeKind::GitLab, base: "https://gitlab.com/o/r".to_string() }
+    }
+
+    fn labels(document: &Document) -> Vec<&str> {
+        document.sections.iter().map(|(label, _)| label.as_str()).collect()
+    }
+
+    #[test]
+    fn links_issue_and_merge_request_references() {
+        assert_eq!(
+            github().link_references("fix crash (#12), see #3"),
+            "fix crash ([#12](https://github.com/o/r/issues/12)), see [#3](https://github.com/o/r/issues/3)"
+        );
+        assert_eq!(
+            gitlab().link_references("closes #4 via !7"),
+            "closes [#4](https://gitlab.com/o/r/-/issues/4) via [!7](https://gitlab.com/o/r/-/merge_requests/7)"
+        );
+        // Not references: mid-word, no digits, or `!` on GitHub
+        assert_eq!(github().link_references("C#12 and # and wow!1"), "C#12 and # and wow!1");
+    }
+
+    #[test]
+    fn keeps_link_definitions_inside_sections() {
+        let text = "# Changelog\n\n## [1.0.0] - 2024-01-01\n\n### Added\n\n- See [docs].\n\n[docs]: https://example.com/docs\n\n## [0.9.0] - 2023-12-01\n\n- First\n\n[1.0.0]: https://example.com/1.0.0\n[0.9.0]: https://example.com/0.9.0\n";
+        let document = Document::parse(text);
+        assert_eq!(labels(&document), ["1.0.0", "0.9.0"]);
+        assert!(document.sections[0].1.contains("[docs]: https://example.com/docs"));
+        assert_eq!(document.links.len(), 2);
+        assert_eq!(document.render(), text);
+    }
+
+    #[test]
+    fn merge_regenerates_unreleased_and_keeps_written_releases() {
+        let existing = "# My log\n\n## [Unreleased]\n\n- old\n\n## [1.0.0] - 2024-01-01\n\n- edited by hand\n\n## [0.5.0] - 2023-06-01\n\n- untagged\n\n[custom]: https://example.com\n";
+        let generated = vec![
+            (UNRELEASED.to_string(), "## [Unreleased]\n\n- new\n".to_string()),
+            ("1.1.0".to_string(), "## [1.1.0] - 2024-02-01\n\n- added\n".to_string()),
+            ("1.0.0".to_string(), "## [1.0.0] - 2024-01-01\n\n- generated\n".to_string()),
+        ];
+        let links = vec![("1.1.0".to_string(), "https://example.com/1.1.0".to_string())];
+        let (document, added) = merge(Some(existing), generated, links);
+
+        assert_eq!(added, 1);
+        assert!(document.preamble.starts_with("# My log"));
+        assert_eq!(labels(&document), ["Unreleased", "1.1.0", "1.0.0", "0.5.0"]);
+        assert!(document.sections[0].1.contains("- new"));
+        assert!(document.section("1.0.0").unwrap().contains("edited by hand"));
+        assert_eq!(document.links.iter().map(|(label, _)| label.as_str()).collect::<Vec<_>>(), ["1.1.0", "custom"]);
+    }
+
+    #[test]
+    fn merge_starts_a_new_file_with_the_header() {
+        let generated = vec![("1.0.0".to_string(), "## [1.0.0] - 2024-01-01\n\n- first\n".to_string())];
+        let (document, added) = merge(None, generated, Vec::new());
+        assert_eq!(added, 1);
+        assert_eq!(document.preamble, HEADER);
+        assert!(document.render().starts_with("# Changelog"));
+    }
+}
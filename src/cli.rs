@@ -0,0 +1,298 @@
+use crate::bundle;
+use crate::changelog;
+use crate::diff;
+use crate::error::{GitError, Result};
+use crate::gitignore;
+use crate::release;
+use crate::scaffold;
+use crate::stash;
+use crate::i18n::t;
+
+// ============================================================================
+// COMMAND LINE
+// ============================================================================
+
+#[derive(Debug)]
+pub enum Command {
+    /// The default interactive status/commit/push flow.
+    Sync,
+    Init(InitOptions),
+    Auth(AuthCommand),
+    Bundle(BundleCommand),
+    /// Retry every queued offline push.
+    Flush,
+    /// List the pushes waiting in the offline queue.
+    Queue,
+    /// Full-screen dashboard of the current repository.
+    Tui,
+    /// Review unstaged (or staged) changes in the diff viewer.
+    Diff(diff::Source),
+    Stash(StashCommand),
+    Worktree(WorktreeCommand),
+    /// Bump the version, tag and publish a release.
+    Release(release::Options),
+    /// Write or update CHANGELOG.md from the history between tags.
+    Changelog(changelog::Options),
+    Identity(IdentityCommand),
+    Help,
+}
+
+#[derive(Debug)]
+pub enum AuthCommand {
+    /// Validate the token and show its owner, scopes and expiry.
+    Status,
+    /// Diagnose SSH keys, agent and config for a remote name or URL
+    /// (default: the current branch's push remote).
+    Ssh(Option<String>),
+}
+
+#[derive(Debug)]
+pub enum BundleCommand {
+    /// Write the commits the other machine is missing to a bundle file.
+    Export(bundle::ExportOptions),
+    /// Verify a bundle and merge it into this repository.
+    Import(String),
+}
+
+/// Stashes are picked by index, as in `stash@{n}`; 0 is the newest.
+#[derive(Debug)]
+pub enum StashCommand {
+    /// List the stashes and pick one to act on.
+    Manage,
+    List,
+    /// Preview a stash in the diff viewer.
+    Show(usize),
+    Apply(usize),
+    Pop(usize),
+    Drop(usize),
+}
+
+#[derive(Debug)]
+pub enum WorktreeCommand {
+    /// Every worktree with its branch and state.
+    List,
+    /// Check a branch out in a new worktree, at the given path or next to
+    /// the main one.
+    Add { branch: String, path: Option<String> },
+    /// Remove the worktree of a branch, or at a path.
+    Remove(String),
+}
+
+#[derive(Debug)]
+pub enum IdentityCommand {
+    /// The identity in effect, the profiles and the team roster.
+    Status,
+    /// Set this repository's identity from a profile.
+    Use(String),
+    /// Create a profile in the global git config.
+    Add(String),
+}
+
+#[derive(Debug, Default)]
+pub struct InitOptions {
+    pub gitignore: gitignore::Selection,
+    pub scaffold: scaffold::Options,
+}
+
+/// Returns the value of a `--flag value` or `--flag=value` option.
+fn flag_value(flag: &str, arg: &str, rest: &mut impl Iterator<Item = String>) -> Result<Option<String>> {
+    if arg == flag {
+        return rest.next()
+            .map(Some)
+            .ok_or_else(|| GitError::Usage(t!("cli.missing_value", flag = flag)));
+    }
+    Ok(arg.strip_prefix(flag)
+        .and_then(|v| v.strip_prefix('='))
+        .map(str::to_string))
+}
+
+fn parse_init(mut args: impl Iterator<Item = String>) -> Result<Command> {
+    let mut options = InitOptions::default();
+    while let Some(arg) = args.next() {
+        if arg == "--no-gitignore" {
+            options.gitignore = gitignore::Selection::Skip;
+        } else if let Some(list) = flag_value("--gitignore", &arg, &mut args)? {
+            let names = list.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+            options.gitignore = gitignore::Selection::Named(names);
+        } else if arg == "--scaffold" {
+            options.scaffold.selection = scaffold::Selection::Yes;
+        } else if arg == "--no-scaffold" {
+            options.scaffold.selection = scaffold::Selection::Skip;
+        } else if let Some(license) = flag_value("--license", &arg, &mut args)? {
+            options.scaffold.license = Some(license);
+        } else {
+            return Err(GitError::Usage(t!("cli.unknown_argument", arg = arg)));
+        }
+    }
+    Ok(Command::Init(options))
+}
+
+fn parse_auth(mut args: impl Iterator<Item = String>) -> Result<Command> {
+    match args.next().as_deref() {
+        Some("status") | None => match args.next() {
+            Some(arg) => Err(GitError::Usage(t!("cli.unknown_argument", arg = arg))),
+            None => Ok(Command::Auth(AuthCommand::Status)),
+        },
+        Some("ssh") => {
+            let target = args.next();
+            match args.next() {
+                Some(arg) => Err(GitError::Usage(t!("cli.unknown_argument", arg = arg))),
+                None => Ok(Command::Auth(AuthCommand::Ssh(target))),
+            }
+        }
+        Some(other) => Err(GitError::Usage(t!("cli.unknown_command", command = format!("auth {}", other)))),
+    }
+}
+
+fn parse_diff(args: impl Iterator<Item = String>) -> Result<Command> {
+    let mut source = diff::Source::Unstaged;
+    for arg in args {
+        match arg.as_str() {
+            "--staged" | "--cached" => source = diff::Source::Staged,
+            _ => return Err(GitError::Usage(t!("cli.unknown_argument", arg = arg))),
+        }
+    }
+    Ok(Command::Diff(source))
+}
+
+fn parse_stash(mut args: impl Iterator<Item = String>) -> Result<Command> {
+    let action = args.next();
+    let target = args.next();
+    if let Some(arg) = args.next() {
+        return Err(GitError::Usage(t!("cli.unknown_argument", arg = arg)));
+    }
+    let index = || match &target {
+        Some(arg) => stash::parse_index(arg).ok_or_else(|| GitError::Usage(t!("stash.usage"))),
+        None => Ok(0),
+    };
+    let command = match action.as_deref() {
+        None => StashCommand::Manage,
+        Some("list") => match target {
+            Some(arg) => return Err(GitError::Usage(t!("cli.unknown_argument", arg = arg))),
+            None => StashCommand::List,
+        },
+        Some("show") => StashCommand::Show(index()?),
+        Some("apply") => StashCommand::Apply(index()?),
+        Some("pop") => StashCommand::Pop(index()?),
+        Some("drop") => StashCommand::Drop(index()?),
+        Some(_) => return Err(GitError::Usage(t!("stash.usage"))),
+    };
+    Ok(Command::Stash(command))
+}
+
+fn parse_worktree(mut args: impl Iterator<Item = String>) -> Result<Command> {
+    let command = match args.next().as_deref() {
+        None | Some("list") => WorktreeCommand::List,
+        Some("add") => match args.next() {
+            Some(branch) => WorktreeCommand::Add { branch, path: args.next() },
+            None => return Err(GitError::Usage(t!("worktree.usage"))),
+        },
+        Some("remove" | "rm") => match args.next() {
+            Some(target) => WorktreeCommand::Remove(target),
+            None => return Err(GitError::Usage(t!("worktree.usage"))),
+        },
+        Some(_) => return Err(GitError::Usage(t!("worktree.usage"))),
+    };
+    match args.next() {
+        Some(arg) => Err(GitError::Usage(t!("cli.unknown_argument", arg = arg))),
+        None => Ok(Command::Worktree(command)),
+    }
+}
+
+fn parse_bundle(mut args: impl Iterator<Item = String>) -> Result<Command> {
+    match args.next().as_deref() {
+        Some("export") => {
+            let mut options = bundle::ExportOptions::default();
+            for arg in args {
+                match arg.as_str() {
+                    "--all" => options.all = true,
+                    "--full" => options.full = true,
+                    _ if arg.starts_with('-') || options.file.is_some() => {
+                        return Err(GitError::Usage(t!("cli.unknown_argument", arg = arg)));
+                    }
+                    _ => options.file = Some(arg),
+                }
+            }
+            Ok(Command::Bundle(BundleCommand::Export(options)))
+        }
+        Some("import") => match (args.next(), args.next()) {
+            (Some(file), None) => Ok(Command::Bundle(BundleCommand::Import(file))),
+            (None, _) => Err(GitError::Usage(t!("bundle.usage"))),
+            (Some(_), Some(arg)) => Err(GitError::Usage(t!("cli.unknown_argument", arg = arg))),
+        },
+        Some(other) => Err(GitError::Usage(t!("cli.unknown_command", command = format!("bundle {}", other)))),
+        None => Err(GitError::Usage(t!("bundle.usage"))),
+    }
+}
+
+fn parse_release(args: impl Iterator<Item = String>) -> Result<Command> {
+    let mut options = release::Options::default();
+    for arg in args {
+        match arg.as_str() {
+            "--sign" | "-s" => options.sign = true,
+            _ if arg.starts_with('-') || options.version.is_some() => {
+                return Err(GitError::Usage(t!("cli.unknown_argument", arg = arg)));
+            }
+            _ => options.version = Some(arg),
+        }
+    }
+    Ok(Command::Release(options))
+}
+
+fn parse_identity(mut args: impl Iterator<Item = String>) -> Result<Command> {
+    let command = match args.next().as_deref() {
+        None | Some("status") => IdentityCommand::Status,
+        Some(action @ ("use" | "add")) => match args.next() {
+            Some(profile) if action == "use" => IdentityCommand::Use(profile),
+            Some(profile) => IdentityCommand::Add(profile),
+            None => return Err(GitError::Usage(t!("identity.usage"))),
+        },
+        Some(_) => return Err(GitError::Usage(t!("identity.usage"))),
+    };
+    match args.next() {
+        Some(arg) => Err(GitError::Usage(t!("cli.unknown_argument", arg = arg))),
+        None => Ok(Command::Identity(command)),
+    }
+}
+
+fn parse_changelog(args: impl Iterator<Item = String>) -> Result<Command> {
+    let mut options = changelog::Options::default();
+    for arg in args {
+        match arg.as_str() {
+            "--stdout" => options.stdout = true,
+            _ => return Err(GitError::Usage(t!("cli.unknown_argument", arg = arg))),
+        }
+    }
+    Ok(Command::Changelog(options))
+}
+
+pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Command> {
+    match args.next().as_deref() {
+        None => Ok(Command::Sync),
+        Some("init") => parse_init(args),
+        Some("auth") => parse_auth(args),
+        Some("bundle") => parse_bundle(args),
+        Some("diff") => parse_diff(args),
+        Some("stash") => parse_stash(args),
+        Some("worktree") => parse_worktree(args),
+        Some("release") => parse_release(args),
+        Some("changelog") => parse_changelog(args),
+        Some("identity") => parse_identity(args),
+        Some(command @ ("flush" | "queue" | "tui")) => match (args.next(), command) {
+            (Some(arg), _) => Err(GitError::Usage(t!("cli.unknown_argument", arg = arg))),
+            (None, "flush") => Ok(Command::Flush),
+            (None, "queue") => Ok(Command::Queue),
+            (None, _) => Ok(Command::Tui),
+        },
+        Some("help") | Some("-h") | Some("--help") => Ok(Command::Help),
+        Some(other) => Err(GitError::Usage(t!("cli.unknown_command", command = other))),
+    }
+}
+
+pub fn print_help() {
+    println!("{}", t!(
+        "cli.help",
+        templates = gitignore::template_names().join(", "),
+        licenses = scaffold::license_ids().join(", ")
+    ));
+}
@@ -0,0 +1,31 @@
+use std::fs;
+use std::io;
+
+use crate::error::{GitError, Result};
+use crate::i18n::t;
+use crate::GitRepo;
+
+// ============================================================================
+// PROJECT CONFIG
+// ============================================================================
+
+/// Per-project settings, committed at the repository root so the whole team
+/// shares them.
+pub const FILE_NAME: &str = ".syncgit.toml";
+
+/// The parsed `.syncgit.toml`, or `None` if the repository has none.
+pub fn load(repo: &GitRepo) -> Result<Option<toml::Table>> {
+    let text = match fs::read_to_string(repo.root.join(FILE_NAME)) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(GitError::io(t!("config.read_failed", file = FILE_NAME), e)),
+    };
+    text.parse::<toml::Table>()
+        .map(Some)
+        .map_err(|e| invalid(e.message()))
+}
+
+/// An error pointing at the config file, for values of the wrong shape.
+pub fn invalid(error: impl std::fmt::Display) -> GitError {
+    GitError::Other(t!("config.invalid", file = FILE_NAME, error = error))
+}
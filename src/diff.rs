@@ -0,0 +1,883 @@
+use std::io::{self, IsTerminal, Write};
+use std::ops::Range;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Color;
+use crossterm::{cursor, queue, terminal};
+
+use crate::error::Result;
+use crate::i18n::t;
+use crate::tui::{self, char_width, print_line, Line, Screen, Span};
+use crate::GitRepo;
+
+// ============================================================================
+// DIFF VIEWER
+// ============================================================================
+
+// A scrollable review of `git diff` inside syncgit. Changed lines are paired
+// up within each hunk and diffed word by word, so the exact edit stands out;
+// code gets light keyword/string/comment coloring by file extension.
+
+/// Wide enough for two readable columns; narrower terminals get unified.
+const SIDE_BY_SIDE_MIN_WIDTH: usize = 120;
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 6;
+const TAB: &str = "    ";
+/// Word diffs of longer line pairs are skipped: the whole line is marked.
+const MAX_WORD_DIFF_CELLS: usize = 40_000;
+/// Untracked files shown as new files in the unstaged diff.
+const MAX_UNTRACKED: usize = 100;
+
+const ADDED_BG: Color = Color::AnsiValue(22);
+const ADDED_WORD_BG: Color = Color::AnsiValue(28);
+const REMOVED_BG: Color = Color::AnsiValue(52);
+const REMOVED_WORD_BG: Color = Color::AnsiValue(88);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// Working tree against the index, untracked files included.
+    Unstaged,
+    /// Index against HEAD: what the next commit contains.
+    Staged,
+    /// What `stash@{n}` would bring back.
+    Stash(usize),
+}
+
+impl Source {
+    fn toggled(self) -> Self {
+        match self {
+            Source::Unstaged => Source::Staged,
+            Source::Staged => Source::Unstaged,
+            Source::Stash(_) => self,
+        }
+    }
+
+    fn title(self) -> String {
+        match self {
+            Source::Unstaged => t!("diff.unstaged"),
+            Source::Staged => t!("diff.staged"),
+            Source::Stash(index) => t!("diff.stash", stash = format!("stash@{{{}}}", index)),
+        }
+    }
+
+    /// The git command showing this source with `flags`.
+    fn args(self, flags: &[&str], pathspec: &str) -> Vec<String> {
+        let mut args: Vec<String> = match self {
+            Source::Unstaged => vec!["diff".into()],
+            Source::Staged => vec!["diff".into(), "--cached".into()],
+            Source::Stash(_) => vec!["stash".into(), "show".into(), "-p".into(), "--include-untracked".into()],
+        };
+        args.extend(flags.iter().map(|f| f.to_string()));
+        match self {
+            Source::Stash(index) => args.push(format!("stash@{{{}}}", index)),
+            _ => args.extend(["--".to_string(), pathspec.to_string()]),
+        }
+        args
+    }
+}
+
+// ============================================================================
+// PARSING
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Context,
+    Added,
+    Removed,
+}
+
+struct DiffLine {
+    kind: Kind,
+    old: Option<usize>,
+    new: Option<usize>,
+    text: String,
+    /// Byte ranges of `text` that differ from the paired line.
+    changed: Vec<Range<usize>>,
+}
+
+struct Hunk {
+    header: String,
+    lines: Vec<DiffLine>,
+}
+
+struct FileDiff {
+    path: String,
+    /// New, deleted, renamed, mode changed or binary.
+    notes: Vec<String>,
+    hunks: Vec<Hunk>,
+}
+
+/// The `b/` side of a `diff --git a/x b/x` line, for diffs without `+++`
+/// lines (binary files, pure renames, mode changes).
+fn header_path(rest: &str) -> String {
+    let b_side = match rest.find(" \"b/") {
+        Some(i) => tui::unquote(&rest[i + 1..]),
+        None => rest.rsplit_once(" b/").map(|(_, p)| format!("b/{}", p)).unwrap_or_else(|| rest.to_string()),
+    };
+    b_side.strip_prefix("b/").unwrap_or(&b_side).to_string()
+}
+
+/// `@@ -12,5 +12,6 @@` → (12, 12)
+fn hunk_start(header: &str) -> (usize, usize) {
+    let number = |s: &str| s.chars().take_while(char::is_ascii_digit).collect::<String>().parse().unwrap_or(1);
+    let old = header.split_once(" -").map_or(1, |(_, rest)| number(rest));
+    let new = header.split_once(" +").map_or(1, |(_, rest)| number(rest));
+    (old, new)
+}
+
+fn parse(diff: &str) -> Vec<FileDiff> {
+    let mut files: Vec<FileDiff> = Vec::new();
+    let (mut old, mut new) = (0, 0);
+    for line in diff.lines() {
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            files.push(FileDiff { path: header_path(rest), notes: Vec::new(), hunks: Vec::new() });
+            continue;
+        }
+        let Some(file) = files.last_mut() else { continue };
+
+        if line.starts_with("@@") {
+            (old, new) = hunk_start(line);
+            file.hunks.push(Hunk { header: line.to_string(), lines: Vec::new() });
+            continue;
+        }
+
+        let Some(hunk) = file.hunks.last_mut() else {
+            // Extended header, before the first hunk
+            if let Some(path) = line.strip_prefix("+++ ").filter(|p| *p != "/dev/null") {
+                let path = tui::unquote(path);
+                file.path = path.strip_prefix("b/").unwrap_or(&path).to_string();
+            } else if line.starts_with("new file mode") {
+                file.notes.push(t!("diff.new_file"));
+            } else if line.starts_with("deleted file mode") {
+                file.notes.push(t!("diff.deleted_file"));
+            } else if let Some(from) = line.strip_prefix("rename from ") {
+                file.notes.push(t!("diff.renamed", from = tui::unquote(from)));
+            } else if let Some(mode) = line.strip_prefix("new mode ") {
+                file.notes.push(t!("diff.mode_changed", mode = mode));
+            } else if line.starts_with("Binary files") {
+                file.notes.push(t!("diff.binary"));
+            }
+            continue;
+        };
+
+        let text = line.get(1..).unwrap_or_default().replace('\t', TAB);
+        let (kind, old_no, new_no) = match line.chars().next() {
+            Some(' ') => (Kind::Context, Some(old), Some(new)),
+            Some('-') => (Kind::Removed, Some(old), None),
+            Some('+') => (Kind::Added, None, Some(new)),
+            // "\ No newline at end of file"
+            _ => continue,
+        };
+        old += usize::from(old_no.is_some());
+        new += usize::from(new_no.is_some());
+        hunk.lines.push(DiffLine { kind, old: old_no, new: new_no, text, changed: Vec::new() });
+    }
+
+    for hunk in files.iter_mut().flat_map(|f| f.hunks.iter_mut()) {
+        mark_word_changes(&mut hunk.lines);
+    }
+    files
+}
+
+// ============================================================================
+// WORD DIFF
+// ============================================================================
+
+/// Splits a line into words, runs of whitespace and single punctuation
+/// characters, as byte ranges.
+fn tokens(text: &str) -> Vec<Range<usize>> {
+    let class = |c: char| if c.is_alphanumeric() || c == '_' { 0 } else if c.is_whitespace() { 1 } else { 2 };
+    let mut tokens: Vec<Range<usize>> = Vec::new();
+    let mut previous = None;
+    for (i, c) in text.char_indices() {
+        let current = class(c);
+        match tokens.last_mut() {
+            Some(last) if previous == Some(current) && current != 2 => last.end = i + c.len_utf8(),
+            _ => tokens.push(i..i + c.len_utf8()),
+        }
+        previous = Some(current);
+    }
+    tokens
+}
+
+/// Pairs each run of removed lines with the added lines right after it.
+fn mark_word_changes(lines: &mut [DiffLine]) {
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].kind != Kind::Removed {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < lines.len() && lines[i].kind == Kind::Removed {
+            i += 1;
+        }
+        let middle = i;
+        while i < lines.len() && lines[i].kind == Kind::Added {
+            i += 1;
+        }
+        let (removed, added) = lines[start..i].split_at_mut(middle - start);
+        for (old, new) in removed.iter_mut().zip(added.iter_mut()) {
+            word_diff(old, new);
+        }
+    }
+}
+
+/// Marks the tokens outside the longest common subsequence of both lines.
+fn word_diff(old: &mut DiffLine, new: &mut DiffLine) {
+    let a = tokens(&old.text);
+    let b = tokens(&new.text);
+    if a.is_empty() || b.is_empty() || a.len() * b.len() > MAX_WORD_DIFF_CELLS {
+        return;
+    }
+    let same = |i: usize, j: usize| old.text[a[i].clone()] == new.text[b[j].clone()];
+
+    // lengths[i][j]: LCS of a[i..] and b[j..]
+    let mut lengths = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i][j] = if same(i, j) { lengths[i + 1][j + 1] + 1 } else { lengths[i + 1][j].max(lengths[i][j + 1]) };
+        }
+    }
+
+    let (mut keep_a, mut keep_b) = (vec![false; a.len()], vec![false; b.len()]);
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if same(i, j) {
+            keep_a[i] = true;
+            keep_b[j] = true;
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    // Nothing but whitespace in common: the whole line is new, so emphasizing
+    // every word would only add noise
+    let shared = a.iter().zip(&keep_a).any(|(r, kept)| *kept && !old.text[r.clone()].trim().is_empty());
+    if !shared {
+        return;
+    }
+    old.changed = changed_ranges(&a, &keep_a);
+    new.changed = changed_ranges(&b, &keep_b);
+}
+
+fn changed_ranges(tokens: &[Range<usize>], kept: &[bool]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (token, kept) in tokens.iter().zip(kept) {
+        if *kept {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if last.end == token.start => last.end = token.end,
+            _ => ranges.push(token.clone()),
+        }
+    }
+    ranges
+}
+
+// ============================================================================
+// SYNTAX COLORING
+// ============================================================================
+
+struct Syntax {
+    line_comments: &'static [&'static str],
+    quotes: &'static [char],
+    keywords: &'static [&'static str],
+}
+
+const RUST: Syntax = Syntax {
+    line_comments: &["//"],
+    quotes: &['"'],
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "false", "fn", "for",
+        "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self",
+        "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+    ],
+};
+
+const C_LIKE: Syntax = Syntax {
+    line_comments: &["//", "/*"],
+    quotes: &['"', '\'', '`'],
+    keywords: &[
+        "break", "case", "catch", "class", "const", "continue", "default", "defer", "delete", "do", "else", "enum",
+        "export", "extends", "false", "final", "for", "func", "function", "go", "if", "implements", "import",
+        "interface", "let", "new", "null", "package", "private", "protected", "public", "return", "static", "struct",
+        "switch", "this", "throw", "true", "try", "type", "typedef", "var", "void", "while",
+    ],
+};
+
+const PYTHON: Syntax = Syntax {
+    line_comments: &["#"],
+    quotes: &['"', '\''],
+    keywords: &[
+        "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else", "except",
+        "False", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda", "None", "not", "or",
+        "pass", "raise", "return", "True", "try", "while", "with", "yield",
+    ],
+};
+
+const SHELL: Syntax = Syntax {
+    line_comments: &["#"],
+    quotes: &['"', '\''],
+    keywords: &[
+        "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if", "in", "local",
+        "return", "then", "while",
+    ],
+};
+
+/// Comments and strings only, for configuration files.
+const CONFIG: Syntax = Syntax { line_comments: &["#"], quotes: &['"', '\''], keywords: &["true", "false"] };
+
+fn syntax_for(path: &str) -> Option<&'static Syntax> {
+    let extension = path.rsplit_once('.').map(|(_, e)| e.to_lowercase())?;
+    match extension.as_str() {
+        "rs" => Some(&RUST),
+        "c" | "h" | "cc" | "cpp" | "hpp" | "cs" | "go" | "java" | "js" | "jsx" | "kt" | "mjs" | "swift" | "ts" | "tsx" => Some(&C_LIKE),
+        "py" => Some(&PYTHON),
+        "sh" | "bash" | "zsh" => Some(&SHELL),
+        "toml" | "yaml" | "yml" | "ini" | "conf" => Some(&CONFIG),
+        _ => None,
+    }
+}
+
+/// Foreground color of every byte of `text`.
+fn highlight(text: &str, syntax: &Syntax) -> Vec<Option<Color>> {
+    let mut colors = vec![None; text.len()];
+    let mut rest = text.char_indices().peekable();
+    while let Some((start, c)) = rest.next() {
+        if syntax.line_comments.iter().any(|prefix| text[start..].starts_with(prefix)) {
+            colors[start..].fill(Some(Color::DarkGrey));
+            break;
+        }
+        if syntax.quotes.contains(&c) {
+            let mut end = text.len();
+            let mut escaped = false;
+            for (i, next) in rest.by_ref() {
+                if !escaped && next == c {
+                    end = i + next.len_utf8();
+                    break;
+                }
+                escaped = !escaped && next == '\\';
+            }
+            colors[start..end].fill(Some(Color::Yellow));
+            continue;
+        }
+        if c.is_alphanumeric() || c == '_' {
+            let mut end = start + c.len_utf8();
+            while let Some(&(i, next)) = rest.peek() {
+                if !(next.is_alphanumeric() || next == '_') {
+                    break;
+                }
+                end = i + next.len_utf8();
+                rest.next();
+            }
+            let word = &text[start..end];
+            if c.is_ascii_digit() {
+                colors[start..end].fill(Some(Color::Cyan));
+            } else if syntax.keywords.contains(&word) {
+                colors[start..end].fill(Some(Color::Magenta));
+            }
+        }
+    }
+    colors
+}
+
+// ============================================================================
+// LAYOUT
+// ============================================================================
+
+/// The content of a diff line as spans: syntax colors in front, the line's
+/// change as background, brighter where the words changed.
+fn content_spans(line: &DiffLine, syntax: Option<&Syntax>) -> Vec<Span> {
+    let (background, word_background) = match line.kind {
+        Kind::Context => (None, None),
+        Kind::Added => (Some(ADDED_BG), Some(ADDED_WORD_BG)),
+        Kind::Removed => (Some(REMOVED_BG), Some(REMOVED_WORD_BG)),
+    };
+    let colors = syntax.map(|s| highlight(&line.text, s));
+
+    let mut spans: Vec<Span> = Vec::new();
+    for (i, c) in line.text.char_indices() {
+        let color = colors.as_ref().and_then(|colors| colors[i]);
+        let changed = line.changed.iter().any(|r| r.contains(&i));
+        let bg = if changed { word_background } else { background };
+        match spans.last_mut() {
+            Some(last) if last.color == color && last.background == bg => last.text.push(c),
+            _ => spans.push(Span { text: c.to_string(), color, background: bg }),
+        }
+    }
+    spans
+}
+
+/// Cuts `spans` to `width` columns, padding with `background` so changed
+/// lines are marked across the whole column.
+fn fit(spans: Vec<Span>, width: usize, background: Option<Color>) -> Vec<Span> {
+    let mut fitted = Vec::new();
+    let mut remaining = width;
+    for mut span in spans {
+        let mut text = String::new();
+        for c in span.text.chars() {
+            let columns = char_width(c);
+            if columns > remaining {
+                break;
+            }
+            remaining -= columns;
+            text.push(c);
+        }
+        let cut = text.len() < span.text.len();
+        span.text = text;
+        fitted.push(span);
+        if cut {
+            break;
+        }
+    }
+    fitted.push(Span { text: " ".repeat(remaining), color: None, background });
+    fitted
+}
+
+fn line_background(kind: Kind) -> Option<Color> {
+    match kind {
+        Kind::Context => None,
+        Kind::Added => Some(ADDED_BG),
+        Kind::Removed => Some(REMOVED_BG),
+    }
+}
+
+fn sign(kind: Kind) -> Span {
+    match kind {
+        Kind::Context => Span::plain(" "),
+        Kind::Added => Span { text: "+".to_string(), color: Some(Color::Green), background: Some(ADDED_BG) },
+        Kind::Removed => Span { text: "-".to_string(), color: Some(Color::Red), background: Some(REMOVED_BG) },
+    }
+}
+
+fn number(n: Option<usize>, width: usize) -> String {
+    n.map_or_else(|| " ".repeat(width), |n| format!("{:>width$}", n, width = width))
+}
+
+/// One side of a side-by-side row: line number, sign and content, or blank.
+fn side(line: Option<&DiffLine>, syntax: Option<&Syntax>, gutter: usize, width: usize) -> Vec<Span> {
+    let Some(line) = line else {
+        return vec![Span::plain(" ".repeat(width))];
+    };
+    let n = if line.kind == Kind::Added { line.new } else { line.old.or(line.new) };
+    let mut spans = vec![Span::colored(format!("{} ", number(n, gutter)), Color::DarkGrey), sign(line.kind)];
+    spans.extend(fit(content_spans(line, syntax), width.saturating_sub(gutter + 2), line_background(line.kind)));
+    spans
+}
+
+struct Rows {
+    lines: Vec<Line>,
+    /// Row where each file starts.
+    file_starts: Vec<usize>,
+}
+
+fn build_rows(files: &[FileDiff], side_by_side: bool, width: usize) -> Rows {
+    let gutter = files.iter()
+        .flat_map(|f| f.hunks.iter().flat_map(|h| &h.lines))
+        .flat_map(|l| [l.old, l.new])
+        .flatten()
+        .max()
+        .unwrap_or(1)
+        .to_string()
+        .len();
+    let mut rows = Rows { lines: Vec::new(), file_starts: Vec::new() };
+
+    for file in files {
+        rows.file_starts.push(rows.lines.len());
+        let mut title = format!(" {}", file.path);
+        if !file.notes.is_empty() {
+            title.push_str(&format!("  ({})", file.notes.join(", ")));
+        }
+        rows.lines.push(Line { highlight: true, bold: true, ..Line::plain(title) });
+        let syntax = syntax_for(&file.path);
+
+        for hunk in &file.hunks {
+            rows.lines.push(Line::colored(hunk.header.as_str(), Color::Cyan));
+            if !side_by_side {
+                for line in &hunk.lines {
+                    let mut spans = vec![
+                        Span::colored(format!("{} {} ", number(line.old, gutter), number(line.new, gutter)), Color::DarkGrey),
+                        sign(line.kind),
+                    ];
+                    spans.extend(fit(content_spans(line, syntax), width.saturating_sub(gutter * 2 + 3), line_background(line.kind)));
+                    rows.lines.push(Line { spans, ..Default::default() });
+                }
+                continue;
+            }
+
+            let half = width.saturating_sub(1) / 2;
+            let right_width = width.saturating_sub(half + 1);
+            let mut i = 0;
+            while i < hunk.lines.len() {
+                // Pair a block of removed lines with the added lines after it
+                let (removed, added): (Vec<&DiffLine>, Vec<&DiffLine>) = if hunk.lines[i].kind == Kind::Context {
+                    i += 1;
+                    (vec![&hunk.lines[i - 1]], vec![&hunk.lines[i - 1]])
+                } else {
+                    let start = i;
+                    while i < hunk.lines.len() && hunk.lines[i].kind == Kind::Removed {
+                        i += 1;
+                    }
+                    let middle = i;
+                    while i < hunk.lines.len() && hunk.lines[i].kind == Kind::Added {
+                        i += 1;
+                    }
+                    (hunk.lines[start..middle].iter().collect(), hunk.lines[middle..i].iter().collect())
+                };
+                for row in 0..removed.len().max(added.len()) {
+                    let mut spans = side(removed.get(row).copied(), syntax, gutter, half);
+                    spans.push(Span::colored("│", Color::DarkGrey));
+                    spans.extend(side(added.get(row).copied(), syntax, gutter, right_width));
+                    rows.lines.push(Line { spans, ..Default::default() });
+                }
+            }
+        }
+    }
+    rows
+}
+
+// ============================================================================
+// VIEWER
+// ============================================================================
+
+fn load(repo: &GitRepo, pathspec: &str, source: Source) -> Vec<FileDiff> {
+    let args = source.args(&["--no-color", "--no-ext-diff", "-M"], pathspec);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let mut diff = repo.run_command_with_output(&args).unwrap_or_default();
+
+    if source == Source::Unstaged {
+        // git diff leaves out untracked files; show them as new files
+        let untracked = repo.run_command_with_output(&["ls-files", "--others", "--exclude-standard", "--", pathspec]).unwrap_or_default();
+        for path in untracked.lines().take(MAX_UNTRACKED) {
+            // Exits with 1 when the files differ, which they always do here
+            if let Ok(output) = repo.create_command(["diff", "--no-color", "--no-index", "--", "/dev/null", path]).output() {
+                diff.push('\n');
+                diff.push_str(&String::from_utf8_lossy(&output.stdout));
+            }
+        }
+    }
+    parse(&diff)
+}
+
+struct Viewer<'a> {
+    repo: &'a GitRepo,
+    pathspec: &'a str,
+    source: Source,
+    files: Vec<FileDiff>,
+    /// Chosen with `v`; otherwise decided by the terminal width.
+    side_by_side: Option<bool>,
+    rows: Rows,
+    /// Width and layout the rows were built for.
+    built_for: Option<(usize, bool)>,
+    offset: usize,
+}
+
+impl<'a> Viewer<'a> {
+    fn new(repo: &'a GitRepo, pathspec: &'a str, source: Source) -> Self {
+        Viewer {
+            repo,
+            pathspec,
+            source,
+            files: load(repo, pathspec, source),
+            side_by_side: None,
+            rows: Rows { lines: Vec::new(), file_starts: Vec::new() },
+            built_for: None,
+            offset: 0,
+        }
+    }
+
+    fn current_file(&self) -> usize {
+        self.rows.file_starts.iter().rposition(|&start| start <= self.offset).unwrap_or(0)
+    }
+
+    /// Rebuilds the rows when the width or layout changed, keeping the
+    /// current file on screen.
+    fn layout(&mut self, width: usize) -> bool {
+        let side_by_side = self.side_by_side.unwrap_or(width >= SIDE_BY_SIDE_MIN_WIDTH);
+        if self.built_for != Some((width, side_by_side)) {
+            let file = self.current_file();
+            self.rows = build_rows(&self.files, side_by_side, width);
+            self.offset = self.rows.file_starts.get(file).copied().unwrap_or(0);
+            self.built_for = Some((width, side_by_side));
+        }
+        side_by_side
+    }
+
+    fn draw(&mut self, out: &mut io::Stdout) -> io::Result<usize> {
+        let (width, height) = terminal::size()?;
+        if width < MIN_WIDTH || height < MIN_HEIGHT {
+            queue!(out, terminal::Clear(terminal::ClearType::All), cursor::MoveTo(0, 0),
+                crossterm::style::Print(t!("tui.too_small", width = MIN_WIDTH, height = MIN_HEIGHT)))?;
+            out.flush()?;
+            return Ok(1);
+        }
+        let side_by_side = self.layout(width as usize);
+        let body = height as usize - 2;
+        self.offset = self.offset.min(self.max_offset(body));
+
+        let layout = if side_by_side { t!("diff.side_by_side") } else { t!("diff.unified") };
+        let mut title = format!(" {}", self.source.title());
+        if let Some(file) = self.files.get(self.current_file()) {
+            title.push_str(&format!(" · {} · {}", t!("diff.file_position", index = self.current_file() + 1, total = self.files.len()), file.path));
+        }
+        title.push_str(&format!(" · {}", layout));
+        queue!(out, cursor::MoveTo(0, 0))?;
+        print_line(out, &Line { highlight: true, ..Line::plain(title) }, width as usize)?;
+
+        let empty = match self.source {
+            Source::Unstaged => t!("diff.no_unstaged"),
+            Source::Staged => t!("diff.no_staged"),
+            Source::Stash(_) => t!("diff.no_stash_changes"),
+        };
+        for row in 0..body {
+            queue!(out, cursor::MoveTo(0, row as u16 + 1))?;
+            match self.rows.lines.get(self.offset + row) {
+                Some(line) => print_line(out, line, width as usize)?,
+                None if row == 0 && self.files.is_empty() => print_line(out, &Line::colored(format!(" {}", empty), Color::DarkGrey), width as usize)?,
+                None => print_line(out, &Line::default(), width as usize)?,
+            }
+        }
+
+        queue!(out, cursor::MoveTo(0, height - 1))?;
+        let position = if self.rows.lines.is_empty() {
+            String::new()
+        } else {
+            let last = (self.offset + body).min(self.rows.lines.len());
+            format!("{}-{}/{} ", self.offset + 1, last, self.rows.lines.len())
+        };
+        let footer = Line {
+            spans: vec![Span::colored(format!(" {}", position), Color::Cyan), Span::colored(t!("diff.keys"), Color::DarkGrey)],
+            ..Default::default()
+        };
+        print_line(out, &footer, width as usize)?;
+        out.flush()?;
+        Ok(body)
+    }
+
+    /// The last page, or further down when that's what it takes to show the
+    /// last file at the top.
+    fn max_offset(&self, page: usize) -> usize {
+        let last_file = self.rows.file_starts.last().copied().unwrap_or(0);
+        self.rows.lines.len().saturating_sub(page).max(last_file)
+    }
+
+    /// Staged ↔ unstaged, keeping the chosen layout.
+    fn switch_source(&mut self) {
+        self.source = self.source.toggled();
+        self.files = load(self.repo, self.pathspec, self.source);
+        self.built_for = None;
+        self.rows.file_starts.clear();
+        self.offset = 0;
+    }
+
+    fn jump_file(&mut self, forward: bool) {
+        let current = self.current_file();
+        let target = if forward {
+            current + 1
+        } else if self.rows.file_starts.get(current).is_some_and(|&start| start < self.offset) {
+            // Back to the top of this file first, like a pager
+            current
+        } else {
+            current.saturating_sub(1)
+        };
+        if let Some(&start) = self.rows.file_starts.get(target) {
+            self.offset = start;
+        }
+    }
+}
+
+/// Runs the viewer on a screen that is already set up, until `q`.
+pub fn run_viewer(screen: &mut Screen, repo: &GitRepo, pathspec: &str, source: Source) -> Result<()> {
+    let mut viewer = Viewer::new(repo, pathspec, source);
+    queue!(screen.out, terminal::Clear(terminal::ClearType::All)).map_err(tui::terminal_error)?;
+    loop {
+        let page = viewer.draw(&mut screen.out).map_err(tui::terminal_error)?.max(1);
+        let key = match event::read().map_err(tui::terminal_error)? {
+            Event::Resize(..) => {
+                queue!(screen.out, terminal::Clear(terminal::ClearType::All)).map_err(tui::terminal_error)?;
+                continue;
+            }
+            Event::Key(key) if key.kind != KeyEventKind::Release => key,
+            _ => continue,
+        };
+        let last = viewer.max_offset(page);
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::Enter => viewer.offset = (viewer.offset + 1).min(last),
+            KeyCode::Up | KeyCode::Char('k') => viewer.offset = viewer.offset.saturating_sub(1),
+            KeyCode::PageDown | KeyCode::Char(' ') => viewer.offset = (viewer.offset + page).min(last),
+            KeyCode::PageUp | KeyCode::Char('b') => viewer.offset = viewer.offset.saturating_sub(page),
+            KeyCode::Home | KeyCode::Char('g') => viewer.offset = 0,
+            KeyCode::End | KeyCode::Char('G') => viewer.offset = last,
+            KeyCode::Char('n') | KeyCode::Char(']') => viewer.jump_file(true),
+            KeyCode::Char('N') | KeyCode::Char('p') | KeyCode::Char('[') => viewer.jump_file(false),
+            KeyCode::Char('v') => {
+                let current = viewer.built_for.is_some_and(|(_, side_by_side)| side_by_side);
+                viewer.side_by_side = Some(!current);
+            }
+            KeyCode::Tab => viewer.switch_source(),
+            _ => {}
+        }
+    }
+    queue!(screen.out, terminal::Clear(terminal::ClearType::All)).map_err(tui::terminal_error)?;
+    Ok(())
+}
+
+/// Opens the viewer full screen. Without a terminal, prints the plain diff.
+pub fn view(repo: &GitRepo, pathspec: &str, source: Source) -> Result<()> {
+    if !io::stdout().is_terminal() {
+        let args = source.args(&["--stat", "--patch"], pathspec);
+        return repo.run_command(&args.iter().map(String::as_str).collect::<Vec<_>>());
+    }
+    let mut screen = Screen::enter().map_err(tui::terminal_error)?;
+    run_viewer(&mut screen, repo, pathspec, source)?;
+    screen.leave().map_err(tui::terminal_error)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_paths_from_headers() {
+        let cases = [
+            ("a/src/main.rs b/src/main.rs", "src/main.rs"),
+            ("a/old name.txt b/new name.txt", "new name.txt"),
+            ("\"a/caf\\303\\251.md\" \"b/caf\\303\\251.md\"", "café.md"),
+        ];
+        for (rest, expected) in cases {
+            assert_eq!(header_path(rest), expected, "{}", rest);
+        }
+    }
+
+    #[test]
+    fn reads_hunk_starts() {
+        let cases = [
+            ("@@ -12,5 +12,6 @@ fn main() {", (12, 12)),
+            ("@@ -1 +1 @@", (1, 1)),
+            ("@@ -0,0 +1,3 @@", (0, 1)),
+            ("@@ -40,7 +38,9 @@", (40, 38)),
+        ];
+        for (header, expected) in cases {
+            assert_eq!(hunk_start(header), expected, "{}", header);
+        }
+    }
+
+    #[test]
+    fn parses_files_hunks_and_line_numbers() {
+        let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -3,3 +3,3 @@ mod parse;
+ fn one() {}
+-fn two() -> u8 { 2 }
++fn two() -> u16 { 2 }
+ fn three() {}
+\\ No newline at end of file
+diff --git a/logo.png b/logo.png
+new file mode 100644
+index 0000000..3333333
+Binary files /dev/null and b/logo.png differ
+diff --git a/old.txt b/new.txt
+similarity index 100%
+rename from old.txt
+rename to new.txt
+";
+        let files = parse(diff);
+        let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["src/lib.rs", "logo.png", "new.txt"]);
+
+        let lines = &files[0].hunks[0].lines;
+        let numbers: Vec<(Kind, Option<usize>, Option<usize>)> = lines.iter().map(|l| (l.kind, l.old, l.new)).collect();
+        assert_eq!(numbers, [
+            (Kind::Context, Some(3), Some(3)),
+            (Kind::Removed, Some(4), None),
+            (Kind::Added, None, Some(4)),
+            (Kind::Context, Some(5), Some(5)),
+        ]);
+        // Only the changed type is marked in the paired lines
+        assert_eq!(&lines[1].text[lines[1].changed[0].clone()], "u8");
+        assert_eq!(&lines[2].text[lines[2].changed[0].clone()], "u16");
+
+        assert_eq!(files[1].notes, [t!("diff.new_file"), t!("diff.binary")]);
+        assert!(files[1].hunks.is_empty());
+        assert_eq!(files[2].notes, [t!("diff.renamed", from = "old.txt")]);
+    }
+
+    #[test]
+    fn splits_words_spaces_and_punctuation() {
+        let text = "let total_ms = a+b;  // é";
+        let words: Vec<&str> = tokens(text).into_iter().map(|r| &text[r]).collect();
+        assert_eq!(words, ["let", " ", "total_ms", " ", "=", " ", "a", "+", "b", ";", "  ", "/", "/", " ", "é"]);
+    }
+
+    #[test]
+    fn skips_word_diffs_of_unrelated_lines() {
+        let line = |kind, text: &str| DiffLine { kind, old: None, new: None, text: text.to_string(), changed: Vec::new() };
+        let mut old = line(Kind::Removed, "alpha beta");
+        let mut new = line(Kind::Added, "gamma delta");
+        word_diff(&mut old, &mut new);
+        assert!(old.changed.is_empty() && new.changed.is_empty());
+
+        let mut old = line(Kind::Removed, "let x = 1;");
+        let mut new = line(Kind::Added, "let x = 10;");
+        word_diff(&mut old, &mut new);
+        assert_eq!(&old.text[old.changed[0].clone()], "1");
+        assert_eq!(&new.text[new.changed[0].clone()], "10");
+        assert_eq!((old.changed.len(), new.changed.len()), (1, 1));
+    }
+
+    #[test]
+    fn picks_syntax_by_extension() {
+        let name = |path: &str| syntax_for(path).map(|s| s.keywords[0]);
+        assert_eq!(name("src/main.rs"), Some(RUST.keywords[0]));
+        assert_eq!(name("app/index.TSX"), Some(C_LIKE.keywords[0]));
+        assert_eq!(name("tool.py"), Some(PYTHON.keywords[0]));
+        assert_eq!(name("Cargo.toml"), Some(CONFIG.keywords[0]));
+        assert_eq!(name("README.md"), None);
+        assert_eq!(name("Makefile"), None);
+    }
+
+    #[test]
+    fn highlights_keywords_strings_and_comments() {
+        let text = "let s = \"a\\\"b\"; // 42";
+        let colors = highlight(text, &RUST);
+        let color_of = |needle: &str| colors[text.find(needle).unwrap()];
+        assert_eq!(color_of("let"), Some(Color::Magenta));
+        assert_eq!(color_of("s ="), None);
+        assert_eq!(color_of("\"a"), Some(Color::Yellow));
+        // The escaped quote doesn't end the string
+        assert_eq!(color_of("b\";"), Some(Color::Yellow));
+        assert_eq!(color_of(";"), None);
+        assert_eq!(color_of("42"), Some(Color::DarkGrey));
+        assert_eq!(highlight("x = 42", &CONFIG)[4], Some(Color::Cyan));
+    }
+
+    #[test]
+    fn fits_spans_to_the_column() {
+        let fitted = fit(vec![Span::plain("ab"), Span::plain("中文字")], 5, Some(ADDED_BG));
+        let texts: Vec<&str> = fitted.iter().map(|s| s.text.as_str()).collect();
+        // A wide character that doesn't fit leaves its last column padded
+        assert_eq!(texts, ["ab", "中", " "]);
+        assert_eq!(fitted[2].background, Some(ADDED_BG));
+    }
+
+    #[test]
+    fn builds_git_arguments_per_source() {
+        assert_eq!(Source::Unstaged.args(&["--no-color"], "src"), ["diff", "--no-color", "--", "src"]);
+        assert_eq!(Source::Staged.args(&[], "."), ["diff", "--cached", "--", "."]);
+        assert_eq!(Source::Stash(2).args(&["--no-color"], "src"), ["stash", "show", "-p", "--include-untracked", "--no-color", "stash@{2}"]);
+        assert_eq!(Source::Stash(2).toggled(), Source::Stash(2));
+        assert_eq!(Source::Staged.toggled(), Source::Unstaged);
+    }
+}
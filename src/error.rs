@@ -0,0 +1,275 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::process::ExitStatus;
+
+use crate::i18n::t;
+use crate::push::PushRejection;
+
+// ============================================================================
+// EXIT CODES
+// ============================================================================
+
+// Process exit codes, one per failure category. Documented in the README so
+// scripts wrapping syncgit can branch on them; don't renumber.
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_GENERAL: i32 = 1;
+pub const EXIT_NO_CHANGES: i32 = 2;
+pub const EXIT_CANCELLED: i32 = 3;
+pub const EXIT_AUTH: i32 = 4;
+pub const EXIT_NETWORK: i32 = 5;
+pub const EXIT_CONFLICT: i32 = 6;
+pub const EXIT_PUSH_REJECTED: i32 = 7;
+pub const EXIT_MISSING_UPSTREAM: i32 = 8;
+pub const EXIT_IO: i32 = 9;
+pub const EXIT_USAGE: i32 = 10;
+
+// ============================================================================
+// FAILURE DETAILS
+// ============================================================================
+
+/// What went wrong, plus the exit status and stderr of the git process when
+/// the failure came from one.
+#[derive(Debug, Clone)]
+pub struct Failure {
+    pub message: String,
+    pub status: Option<i32>,
+    pub stderr: String,
+}
+
+impl Failure {
+    pub fn new(message: impl Into<String>) -> Self {
+        Failure { message: message.into(), status: None, stderr: String::new() }
+    }
+
+    pub fn command(args: &[&str], status: ExitStatus, stderr: &str) -> Self {
+        Failure {
+            message: format!("git {}", args.join(" ")),
+            status: status.code(),
+            stderr: stderr.trim().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(code) = self.status {
+            write!(f, " ({})", t!("error.exit_status", code = code))?;
+        }
+        if !self.stderr.is_empty() {
+            write!(f, "\n{}", self.stderr)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ERROR TYPE
+// ============================================================================
+
+#[derive(Debug)]
+pub enum GitError {
+    NoChanges,
+    NoCommitMessage,
+    Cancelled(String),
+    NoToken,
+    NoInternet,
+    Auth(Failure),
+    Network(Failure),
+    Conflict(Failure),
+    PushRejected { kind: PushRejection, failure: Failure },
+    MissingUpstream(Failure),
+    CommandFailed(Failure),
+    Io { context: String, source: io::Error },
+    Usage(String),
+    Other(String),
+}
+
+impl GitError {
+    /// Maps a failed git invocation to the most specific error variant
+    /// based on what git printed to stderr.
+    pub fn from_command(args: &[&str], status: ExitStatus, stderr: &str) -> Self {
+        Self::from_failure(Failure::command(args, status, stderr))
+    }
+
+    pub fn from_failure(failure: Failure) -> Self {
+        let text = failure.stderr.to_lowercase();
+        let any = |needles: &[&str]| needles.iter().any(|n| text.contains(n));
+
+        if failure.message.starts_with("git push") && text.contains("rejected") {
+            GitError::PushRejected { kind: PushRejection::classify(&failure.stderr), failure }
+        } else if any(&["no upstream configured", "no tracking information", "has no upstream branch", "no upstream branch"]) {
+            GitError::MissingUpstream(failure)
+        } else if any(&["authentication failed", "could not read username", "invalid username or password", "permission denied (publickey", "returned error: 403", "returned error: 401"]) {
+            GitError::Auth(failure)
+        } else if any(&["could not resolve host", "could not resolve hostname", "connection timed out", "connection refused", "network is unreachable", "failed to connect", "operation timed out"]) {
+            GitError::Network(failure)
+        } else if any(&["conflict", "needs merge", "unmerged", "would be overwritten", "you have not concluded your merge"]) {
+            GitError::Conflict(failure)
+        } else {
+            GitError::CommandFailed(failure)
+        }
+    }
+
+    pub fn io(context: impl Into<String>, source: io::Error) -> Self {
+        GitError::Io { context: context.into(), source }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            GitError::NoChanges => EXIT_NO_CHANGES,
+            GitError::NoCommitMessage | GitError::Cancelled(_) => EXIT_CANCELLED,
+            GitError::NoToken | GitError::Auth(_) => EXIT_AUTH,
+            GitError::NoInternet | GitError::Network(_) => EXIT_NETWORK,
+            GitError::Conflict(_) => EXIT_CONFLICT,
+            GitError::PushRejected { kind: PushRejection::AuthFailed, .. } => EXIT_AUTH,
+            GitError::PushRejected { .. } => EXIT_PUSH_REJECTED,
+            GitError::MissingUpstream(_) => EXIT_MISSING_UPSTREAM,
+            GitError::Io { .. } => EXIT_IO,
+            GitError::Usage(_) => EXIT_USAGE,
+            GitError::CommandFailed(_) | GitError::Other(_) => EXIT_GENERAL,
+        }
+    }
+}
+
+impl fmt::Display for GitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GitError::NoChanges => write!(f, "{}", t!("error.no_changes")),
+            GitError::NoCommitMessage => write!(f, "{}", t!("error.no_commit_message")),
+            GitError::Cancelled(what) => write!(f, "{}", what),
+            GitError::NoToken => write!(f, "{}", t!("error.no_token")),
+            GitError::NoInternet => write!(f, "{}", t!("error.no_internet")),
+            GitError::Auth(failure) => write!(f, "{}", t!("error.auth", details = failure)),
+            GitError::Network(failure) => write!(f, "{}", t!("error.network", details = failure)),
+            GitError::Conflict(failure) => write!(f, "{}", t!("error.conflict", details = failure)),
+            GitError::PushRejected { kind, failure } => write!(f, "{}", t!("error.push_rejected", reason = kind, details = failure)),
+            GitError::MissingUpstream(failure) => write!(f, "{}", t!("error.missing_upstream", details = failure)),
+            GitError::CommandFailed(failure) => write!(f, "{}", t!("error.command_failed", details = failure)),
+            GitError::Io { context, source } => write!(f, "{}: {}", context, source),
+            GitError::Usage(msg) => write!(f, "{}", msg),
+            GitError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl Error for GitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GitError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+pub type Result<T = ()> = std::result::Result<T, GitError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A command, its stderr, a check on the resulting variant and the exit code
+    type Case = (&'static str, &'static str, fn(&GitError) -> bool, i32);
+
+    fn classify(command: &str, stderr: &str) -> GitError {
+        GitError::from_failure(Failure { message: command.to_string(), status: Some(1), stderr: stderr.to_string() })
+    }
+
+    #[test]
+    fn maps_git_failures_to_exit_codes() {
+        let push = "git push origin main";
+        let cases: &[Case] = &[
+            (
+                push,
+                " ! [rejected]        main -> main (non-fast-forward)\nerror: failed to push some refs to 'github.com:ada/app.git'",
+                |e| matches!(e, GitError::PushRejected { kind: PushRejection::NonFastForward, .. }),
+                EXIT_PUSH_REJECTED,
+            ),
+            (
+                push,
+                " ! [rejected]        main -> main (fetch first)\nerror: failed to push some refs to 'github.com:ada/app.git'",
+                |e| matches!(e, GitError::PushRejected { kind: PushRejection::NonFastForward, .. }),
+                EXIT_PUSH_REJECTED,
+            ),
+            (
+                push,
+                " ! [rejected]        main -> main (stale info)\nerror: failed to push some refs to 'github.com:ada/app.git'",
+                |e| matches!(e, GitError::PushRejected { kind: PushRejection::NonFastForward, .. }),
+                EXIT_PUSH_REJECTED,
+            ),
+            (
+                push,
+                "remote: error: GH006: Protected branch update failed for refs/heads/main.\n ! [remote rejected] main -> main (protected branch hook declined)",
+                |e| matches!(e, GitError::PushRejected { kind: PushRejection::ProtectedBranch, .. }),
+                EXIT_PUSH_REJECTED,
+            ),
+            (
+                push,
+                "remote: error: GH013: Repository rule violations found for refs/heads/main.\n ! [remote rejected] main -> main (push declined due to repository rule violations)",
+                |e| matches!(e, GitError::PushRejected { kind: PushRejection::ProtectedBranch, .. }),
+                EXIT_PUSH_REJECTED,
+            ),
+            (
+                push,
+                "remote: Permission to ada/app.git denied to bob.\nfatal: unable to access 'https://github.com/ada/app.git/': The requested URL returned error: 403",
+                |e| matches!(e, GitError::Auth(_)),
+                EXIT_AUTH,
+            ),
+            (
+                "git fetch origin",
+                "fatal: Authentication failed for 'https://github.com/ada/app.git/'",
+                |e| matches!(e, GitError::Auth(_)),
+                EXIT_AUTH,
+            ),
+            (
+                push,
+                "git@github.com: Permission denied (publickey).\nfatal: Could not read from remote repository.",
+                |e| matches!(e, GitError::Auth(_)),
+                EXIT_AUTH,
+            ),
+            (
+                push,
+                "fatal: unable to access 'https://github.com/ada/app.git/': Could not resolve host: github.com",
+                |e| matches!(e, GitError::Network(_)),
+                EXIT_NETWORK,
+            ),
+            (
+                "git fetch origin",
+                "ssh: connect to host github.com port 22: Connection timed out\nfatal: Could not read from remote repository.",
+                |e| matches!(e, GitError::Network(_)),
+                EXIT_NETWORK,
+            ),
+            (
+                "git pull",
+                "There is no tracking information for the current branch.\nPlease specify which branch you want to merge with.",
+                |e| matches!(e, GitError::MissingUpstream(_)),
+                EXIT_MISSING_UPSTREAM,
+            ),
+            (
+                "git pull --no-rebase origin main",
+                "CONFLICT (content): Merge conflict in src/lib.rs\nAutomatic merge failed; fix conflicts and then commit the result.",
+                |e| matches!(e, GitError::Conflict(_)),
+                EXIT_CONFLICT,
+            ),
+            (
+                "git status",
+                "fatal: not a git repository (or any of the parent directories): .git",
+                |e| matches!(e, GitError::CommandFailed(_)),
+                EXIT_GENERAL,
+            ),
+        ];
+        for &(command, stderr, is_expected, code) in cases {
+            let error = classify(command, stderr);
+            assert!(is_expected(&error), "{}: {:?}", stderr, error);
+            assert_eq!(error.exit_code(), code, "{}", stderr);
+        }
+    }
+
+    #[test]
+    fn only_push_failures_are_rejections() {
+        // A rejected fetch is not a push rejection
+        let error = classify("git fetch origin main", " ! [rejected]        main -> origin/main (would clobber existing tag)");
+        assert!(matches!(error, GitError::CommandFailed(_)), "{:?}", error);
+    }
+}
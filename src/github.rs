@@ -0,0 +1,648 @@
+use std::thread;
+use std::time::Duration;
+
+use reqwest::Method;
+use serde_json::{json, Value};
+
+use crate::api::{ApiClient, ApiResponse};
+
+use crate::error::{Failure, GitError, Result};
+use crate::i18n::{t, tn};
+use crate::{check_internet_connection, get_github_token, push, scaffold, ssh, GitRepo, UI};
+
+// ============================================================================
+// TEMPLATE POLLING
+// ============================================================================
+
+// Repositories generated from a template are filled in asynchronously, so the
+// first branch can take a moment to show up.
+const TEMPLATE_POLL_ATTEMPTS: u32 = 10;
+const TEMPLATE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// ============================================================================
+// REPOSITORY SETTINGS
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visibility {
+    Public,
+    Private,
+    /// Visible to every member of the enterprise; organizations only.
+    Internal,
+}
+
+impl Visibility {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Public => "public",
+            Visibility::Private => "private",
+            Visibility::Internal => "internal",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "public" => Some(Visibility::Public),
+            "private" => Some(Visibility::Private),
+            "internal" => Some(Visibility::Internal),
+            _ => None,
+        }
+    }
+}
+
+/// Everything the user chose for the new repository.
+struct RepoSettings {
+    /// Login of the account or organization that will own the repository.
+    owner: String,
+    /// Whether `owner` is an organization rather than the token's own user.
+    is_org: bool,
+    name: String,
+    description: String,
+    visibility: Visibility,
+    /// `(owner, name)` of a template repository to generate from.
+    template: Option<(String, String)>,
+    topics: Vec<String>,
+    homepage: String,
+    default_branch: String,
+    has_issues: bool,
+    has_wiki: bool,
+    has_projects: bool,
+}
+
+fn prompt_repo_name(default_repo_name: &str) -> String {
+    loop {
+        let input_name = UI::prompt_input(&t!("github.name_prompt", default = default_repo_name));
+        let repo_name = if input_name.trim().is_empty() {
+            default_repo_name.to_string()
+        } else {
+            input_name.trim().to_string()
+        };
+
+        // Validate repository name (GitHub requirements: alphanumeric, -, _, and . only)
+        if repo_name.is_empty() {
+            println!("{}", UI::center_text(&t!("github.name_empty")));
+            continue;
+        }
+        if !repo_name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.') {
+            println!("{}", UI::center_text(&t!("github.name_invalid")));
+            continue;
+        }
+        return repo_name;
+    }
+}
+
+/// Lets the user pick their own account or one of their organizations.
+/// Returns the owner login and whether it is an organization.
+fn choose_owner(api: &ApiClient, login: &str) -> Result<(String, bool)> {
+    let orgs: Vec<String> = api.get_all("/user/orgs?per_page=100")?
+        .iter()
+        .filter_map(|o| o["login"].as_str().map(str::to_string))
+        .collect();
+    if orgs.is_empty() {
+        return Ok((login.to_string(), false));
+    }
+
+    println!("\n{}", t!("github.owner_title"));
+    println!("  1) {}", t!("github.owner_personal", login = login));
+    for (i, org) in orgs.iter().enumerate() {
+        println!("  {}) {}", i + 2, org);
+    }
+    loop {
+        let input = UI::prompt_input(&t!("github.owner_prompt"));
+        if input.is_empty() || input == "1" {
+            return Ok((login.to_string(), false));
+        }
+        match input.parse::<usize>().ok().and_then(|n| n.checked_sub(2)).and_then(|i| orgs.get(i)) {
+            Some(org) => return Ok((org.clone(), true)),
+            None => println!("{}", UI::center_text(&t!("github.owner_invalid"))),
+        }
+    }
+}
+
+fn prompt_visibility(is_org: bool) -> Visibility {
+    if !is_org {
+        return if UI::prompt_yes_no(&t!("github.private_prompt")) {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        };
+    }
+    loop {
+        let input = UI::prompt_input(&t!("github.visibility_prompt"));
+        if input.is_empty() {
+            return Visibility::Public;
+        }
+        match Visibility::parse(&input) {
+            Some(visibility) => return visibility,
+            None => println!("{}", UI::center_text(&t!("github.visibility_invalid"))),
+        }
+    }
+}
+
+fn prompt_template() -> Option<(String, String)> {
+    loop {
+        let input = UI::prompt_input(&t!("github.template_prompt"));
+        if input.is_empty() {
+            return None;
+        }
+        match input.split_once('/') {
+            Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/') => {
+                return Some((owner.to_string(), name.to_string()));
+            }
+            _ => println!("{}", UI::center_text(&t!("github.template_invalid"))),
+        }
+    }
+}
+
+/// GitHub topics: lowercase letters, digits and hyphens, at most 50
+/// characters, not starting with a hyphen.
+fn is_valid_topic(topic: &str) -> bool {
+    !topic.is_empty()
+        && topic.len() <= 50
+        && !topic.starts_with('-')
+        && topic.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+fn prompt_topics() -> Vec<String> {
+    loop {
+        let input = UI::prompt_input(&t!("github.topics_prompt"));
+        let topics: Vec<String> = input.split(',')
+            .map(|s| s.trim().to_lowercase())
+            .filter(|s| !s.is_empty())
+            .collect();
+        match topics.iter().find(|topic| !is_valid_topic(topic)) {
+            Some(bad) => println!("{}", UI::center_text(&t!("github.topic_invalid", topic = bad))),
+            None => return topics,
+        }
+    }
+}
+
+fn collect_settings(api: &ApiClient, repo: &GitRepo) -> Result<RepoSettings> {
+    let login = api.get("/user")?["login"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| GitError::Other(t!("github.no_login")))?;
+
+    let default_repo_name = repo.root.file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("new-repo")
+        .to_string();
+    let branch = repo.get_branch();
+
+    let (owner, is_org) = choose_owner(api, &login)?;
+    let name = prompt_repo_name(&default_repo_name);
+    let description = UI::prompt_input(&t!("github.description_prompt"));
+    let visibility = prompt_visibility(is_org);
+
+    let mut settings = RepoSettings {
+        owner,
+        is_org,
+        name,
+        description: description.trim().to_string(),
+        visibility,
+        template: None,
+        topics: Vec::new(),
+        homepage: String::new(),
+        default_branch: branch.clone(),
+        has_issues: true,
+        has_wiki: true,
+        has_projects: true,
+    };
+
+    if UI::prompt_yes_no(&t!("github.advanced_prompt")) {
+        settings.template = prompt_template();
+        settings.topics = prompt_topics();
+        settings.homepage = UI::prompt_input(&t!("github.homepage_prompt"));
+        let default_branch = UI::prompt_input(&t!("github.default_branch_prompt", default = branch));
+        if !default_branch.is_empty() {
+            settings.default_branch = default_branch;
+        }
+        settings.has_issues = UI::prompt_yes_no(&t!("github.enable_issues"));
+        settings.has_wiki = UI::prompt_yes_no(&t!("github.enable_wiki"));
+        settings.has_projects = UI::prompt_yes_no(&t!("github.enable_projects"));
+    }
+
+    Ok(settings)
+}
+
+// ============================================================================
+// REPOSITORY CREATION
+// ============================================================================
+
+/// Sends the create (or generate-from-template) request; the raw response is
+/// returned so the caller can recognise "already exists".
+fn send_create(api: &ApiClient, settings: &RepoSettings) -> Result<ApiResponse> {
+    let mut body = json!({
+        "name": settings.name,
+        "private": settings.visibility != Visibility::Public,
+    });
+    if !settings.description.is_empty() {
+        body["description"] = Value::String(settings.description.clone());
+    }
+
+    if let Some((template_owner, template_name)) = &settings.template {
+        body["owner"] = Value::String(settings.owner.clone());
+        let path = format!("/repos/{}/{}/generate", template_owner, template_name);
+        return api.execute(Method::POST, &path, Some(&body));
+    }
+
+    body["homepage"] = Value::String(settings.homepage.clone());
+    body["has_issues"] = Value::Bool(settings.has_issues);
+    body["has_wiki"] = Value::Bool(settings.has_wiki);
+    body["has_projects"] = Value::Bool(settings.has_projects);
+    if settings.is_org {
+        body["visibility"] = Value::String(settings.visibility.as_str().to_string());
+        api.execute(Method::POST, &format!("/orgs/{}/repos", settings.owner), Some(&body))
+    } else {
+        api.execute(Method::POST, "/user/repos", Some(&body))
+    }
+}
+
+/// Applies what the create call could not: the template endpoint only takes
+/// name/description/private, and topics always need their own request.
+fn apply_settings(api: &ApiClient, settings: &RepoSettings) -> Result<()> {
+    let path = format!("/repos/{}/{}", settings.owner, settings.name);
+
+    if settings.template.is_some() {
+        let mut body = json!({
+            "homepage": settings.homepage,
+            "has_issues": settings.has_issues,
+            "has_wiki": settings.has_wiki,
+            "has_projects": settings.has_projects,
+        });
+        if settings.visibility == Visibility::Internal {
+            body["visibility"] = Value::String(settings.visibility.as_str().to_string());
+        }
+        api.patch(&path, &body)?;
+    }
+
+    if !settings.topics.is_empty() {
+        let body = json!({ "names": settings.topics });
+        api.put(&format!("{}/topics", path), &body)?;
+    }
+
+    Ok(())
+}
+
+/// Points GitHub's default branch at `settings.default_branch` once it has
+/// been pushed, if it differs from what GitHub picked.
+fn apply_default_branch(api: &ApiClient, settings: &RepoSettings, current: &str) {
+    if settings.default_branch.is_empty() || settings.default_branch == current {
+        return;
+    }
+    let path = format!("/repos/{}/{}", settings.owner, settings.name);
+    let body = json!({ "default_branch": settings.default_branch });
+    if let Err(e) = api.patch(&path, &body) {
+        println!("{}", t!("github.default_branch_failed", branch = settings.default_branch, error = e));
+    }
+}
+
+/// Merges the content GitHub generated from the template into the local
+/// history so the first push is a fast-forward.
+fn merge_template_content(repo: &GitRepo, remote_branch: &str) -> Result<()> {
+    println!("\n{}", t!("github.waiting_template"));
+    for _ in 0..TEMPLATE_POLL_ATTEMPTS {
+        let heads = repo.run_command_with_output(&["ls-remote", "--heads", "origin", remote_branch])?;
+        if !heads.is_empty() {
+            repo.run_command(&["fetch", "origin", remote_branch])?;
+            repo.run_command(&["merge", "--allow-unrelated-histories", "--no-edit", "FETCH_HEAD"])?;
+            println!("{}", t!("github.template_merged"));
+            return Ok(());
+        }
+        thread::sleep(TEMPLATE_POLL_INTERVAL);
+    }
+    Err(GitError::Other(t!("github.template_not_ready")))
+}
+
+// ============================================================================
+// EXISTING REPOSITORIES
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Protocol {
+    Ssh,
+    Https,
+}
+
+fn is_ssh_url(url: &str) -> bool {
+    url.starts_with("git@") || url.starts_with("ssh://")
+}
+
+/// Picks SSH or HTTPS for talking to GitHub: `syncgit.protocol` in git config
+/// wins, then the scheme `origin` already uses, then whether SSH keys are
+/// available. The flag tells whether the choice was explicit.
+fn preferred_protocol(repo: &GitRepo) -> (Protocol, bool) {
+    match repo.run_command_with_output(&["config", "--get", "syncgit.protocol"]).map(|v| v.to_lowercase()).as_deref() {
+        Ok("ssh") => return (Protocol::Ssh, true),
+        Ok("https") => return (Protocol::Https, true),
+        _ => {}
+    }
+    if GitRepo::get_remote_url(&repo.root).is_some_and(|url| is_ssh_url(&url)) {
+        return (Protocol::Ssh, true);
+    }
+    (if ssh::has_identity() { Protocol::Ssh } else { Protocol::Https }, false)
+}
+
+/// How the local branch relates to the existing remote repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum History {
+    /// The remote has no branches yet.
+    Empty,
+    UpToDate,
+    /// The remote branch is an ancestor of the local one.
+    FastForward,
+    /// The local branch is an ancestor of the remote one.
+    Behind,
+    Diverged,
+    /// No common ancestor at all.
+    Unrelated,
+}
+
+/// Runs git with `token` as the only credential for this command, through
+/// the environment: nothing lands in the repository's config before the
+/// user has agreed to use the repository.
+fn run_with_token(repo: &GitRepo, token: Option<&str>, args: &[&str]) -> Result<String> {
+    let Some(token) = token else {
+        return repo.run_command_with_output(args);
+    };
+    repo.run_command_with_env(args, &[
+        ("SYNCGIT_TOKEN", token),
+        ("GIT_CONFIG_COUNT", "2"),
+        // An empty helper clears the ones from config
+        ("GIT_CONFIG_KEY_0", "credential.helper"),
+        ("GIT_CONFIG_VALUE_0", ""),
+        ("GIT_CONFIG_KEY_1", "credential.helper"),
+        ("GIT_CONFIG_VALUE_1", r#"!f() { test "$1" = get && echo username=x-access-token && echo "password=$SYNCGIT_TOKEN"; }; f"#),
+    ])
+}
+
+/// Fetches the matching remote branch into `FETCH_HEAD` (without touching any
+/// remote or ref) and classifies the local history against it. Returns the
+/// remote branch that was compared. `token` authenticates HTTPS URLs.
+fn compare_history(repo: &GitRepo, url: &str, token: Option<&str>, local_branch: &str, default_branch: &str) -> Result<(History, String)> {
+    let heads = run_with_token(repo, token, &["ls-remote", "--heads", url])?;
+    if heads.is_empty() {
+        return Ok((History::Empty, local_branch.to_string()));
+    }
+
+    let local_ref = format!("refs/heads/{}", local_branch);
+    let branch = if heads.lines().any(|line| line.split_whitespace().nth(1) == Some(local_ref.as_str())) {
+        local_branch
+    } else {
+        default_branch
+    };
+    run_with_token(repo, token, &["fetch", "--quiet", url, branch])?;
+
+    let remote = repo.run_command_with_output(&["rev-parse", "FETCH_HEAD"])?;
+    let history = match repo.run_command_with_output(&["rev-parse", "--verify", "HEAD"]) {
+        // Nothing committed locally yet: the remote simply has more
+        Err(_) => History::Behind,
+        Ok(local) if local == remote => History::UpToDate,
+        Ok(_) if repo.run_command_with_output(&["merge-base", "--is-ancestor", "FETCH_HEAD", "HEAD"]).is_ok() => History::FastForward,
+        Ok(_) if repo.run_command_with_output(&["merge-base", "--is-ancestor", "HEAD", "FETCH_HEAD"]).is_ok() => History::Behind,
+        Ok(_) if repo.run_command_with_output(&["merge-base", "HEAD", "FETCH_HEAD"]).is_ok() => History::Diverged,
+        Ok(_) => History::Unrelated,
+    };
+    Ok((history, branch.to_string()))
+}
+
+/// Pushes to a repository that already exists under the chosen owner, after
+/// checking push access and how its history relates to the local one.
+fn use_existing_repo(api: &ApiClient, token: &str, repo: &GitRepo, settings: &RepoSettings) -> Result<()> {
+    let info = api.get(&format!("/repos/{}/{}", settings.owner, settings.name))?;
+    let full_name = info["full_name"].as_str().unwrap_or(&settings.name).to_string();
+    if !info["permissions"]["push"].as_bool().unwrap_or(false) {
+        return Err(GitError::Auth(Failure::new(t!("github.no_push_permission", repo = full_name))));
+    }
+
+    let ssh_url = info["ssh_url"].as_str().ok_or_else(|| GitError::Other(t!("github.no_clone_url")))?;
+    let https_url = info["clone_url"].as_str().ok_or_else(|| GitError::Other(t!("github.no_clone_url")))?;
+    let html_url = info["html_url"].as_str().unwrap_or(https_url);
+    let default_branch = info["default_branch"].as_str().unwrap_or("main");
+    let local_branch = repo.get_branch();
+
+    let (protocol, explicit) = preferred_protocol(repo);
+    let mut url = if protocol == Protocol::Ssh { ssh_url } else { https_url };
+    let https_token = |url: &str| (url == https_url).then_some(token);
+
+    println!("\n{}", t!("github.comparing_history", repo = full_name));
+    let (history, remote_branch) = match compare_history(repo, url, https_token(url), &local_branch, default_branch) {
+        Err(GitError::Auth(_)) if protocol == Protocol::Ssh && !explicit => {
+            // The detected keys aren't registered with GitHub; HTTPS still works with the token
+            println!("{}", t!("github.ssh_fallback"));
+            url = https_url;
+            compare_history(repo, url, https_token(url), &local_branch, default_branch)?
+        }
+        other => other?,
+    };
+
+    let plan_key = match history {
+        History::Empty => "github.history.empty",
+        History::UpToDate => "github.history.up_to_date",
+        History::FastForward => "github.history.fast_forward",
+        History::Behind => "github.history.behind",
+        History::Diverged => "github.history.diverged",
+        History::Unrelated => "github.history.unrelated",
+    };
+    println!("{}", t!(plan_key, branch = remote_branch));
+    if !UI::prompt_yes_no(&t!("github.confirm_existing", url = url)) {
+        return Err(GitError::Cancelled(t!("cancel.repo_creation")));
+    }
+
+    // Only a fast-forward happens on the strength of that answer; a merge
+    // brings another history into the branch and is asked for on its own
+    if matches!(history, History::Diverged | History::Unrelated) {
+        let incoming: usize = repo.run_command_with_output(&["rev-list", "--count", "HEAD..FETCH_HEAD"])?
+            .parse()
+            .unwrap_or(0);
+        let unrelated = history == History::Unrelated;
+        let question = if unrelated {
+            tn!("github.confirm_unrelated_merge", incoming, branch = remote_branch, local = local_branch)
+        } else {
+            tn!("github.confirm_merge", incoming, branch = remote_branch, local = local_branch)
+        };
+        if !UI::prompt_yes_no(&question) {
+            let flag = if unrelated { " --allow-unrelated-histories" } else { "" };
+            let command = format!("git pull --no-rebase{} {} {}", flag, url, remote_branch);
+            return Err(GitError::Cancelled(t!("github.merge_declined", command = command)));
+        }
+    }
+    match history {
+        History::Behind => repo.run_command(&["merge", "--ff-only", "FETCH_HEAD"])?,
+        History::Diverged => repo.run_command(&["merge", "--no-edit", "FETCH_HEAD"])?,
+        History::Unrelated => repo.run_command(&["merge", "--allow-unrelated-histories", "--no-edit", "FETCH_HEAD"])?,
+        History::Empty | History::UpToDate | History::FastForward => {}
+    }
+
+    // Add remote origin if it doesn't exist
+    if !repo.has_remote() {
+        repo.run_command(&["remote", "add", "origin", url])?;
+    } else {
+        // Update existing remote
+        repo.run_command(&["remote", "set-url", "origin", url])?;
+    }
+
+    println!("\n{}", UI::center_text(&t!("github.pushing_existing")));
+    // Credentials are only kept now that the plan is confirmed
+    repo.configure_auth_remote()?;
+    push::push_current_branch(repo, true)?;
+    println!("\n{}", UI::center_text(&t!("github.pushed_to", url = html_url)));
+    Ok(())
+}
+
+pub fn create_github_repo(repo: &GitRepo, scaffolding: &scaffold::Options) -> Result<()> {
+    if !check_internet_connection() {
+        return Err(GitError::NoInternet);
+    }
+
+    let token = get_github_token().ok_or(GitError::NoToken)?;
+    let api = ApiClient::new(&token);
+    let settings = collect_settings(&api, repo)?;
+
+    println!("\n{}", UI::center_text(&t!("github.creating")));
+
+    let response = send_create(&api, &settings)?;
+
+    if !response.is_success() {
+        // Check if repository already exists (422 status with "already exists" message)
+        if response.status == 422 && response.body.contains("already exists") {
+            println!("\n{}", UI::center_text(&t!("github.already_exists", name = settings.name)));
+            if UI::prompt_yes_no(&t!("github.use_existing")) {
+                return use_existing_repo(&api, &token, repo, &settings);
+            } else {
+                return Err(GitError::Cancelled(t!("cancel.repo_creation")));
+            }
+        }
+
+        return Err(response.into_error());
+    }
+
+    let response_json = response.json()?;
+
+    let repo_url = response_json["html_url"]
+        .as_str()
+        .ok_or_else(|| GitError::Other(t!("github.no_html_url")))?;
+
+    if let Err(e) = apply_settings(&api, &settings) {
+        println!("{}", t!("github.settings_failed", error = e));
+    }
+
+    // Offer README/LICENSE now that the name and description are known. A
+    // template brings its own files, which would clash with generated ones.
+    if settings.template.is_none() {
+        let project = scaffold::Project { name: &settings.name, description: &settings.description };
+        scaffold::scaffold(repo, &project, scaffolding)?;
+    }
+
+    // Get current branch name (defaults to main)
+    let branch = repo.run_command_with_output(&["rev-parse", "--abbrev-ref", "HEAD"])
+        .map(|b| b.trim().to_string())
+        .unwrap_or_else(|_| "main".to_string());
+
+    // Get the clone URL (SSH or HTTPS) from the response
+    let clone_url = response_json["clone_url"]
+        .as_str()
+        .ok_or_else(|| GitError::Other(t!("github.no_clone_url")))?;
+
+    // Add remote origin
+    if let Err(e) = repo.run_command(&["remote", "add", "origin", clone_url]) {
+        if let Ok(output) = repo.run_command_with_output(&["remote", "get-url", "origin"]) {
+            println!("{}", t!("github.origin_exists", url = output.trim()));
+            if !UI::prompt_yes_no(&t!("github.update_remote")) {
+                println!("\n{}", t!("github.using_existing_remote"));
+                return Ok(());
+            }
+            repo.run_command(&["remote", "set-url", "origin", clone_url])?;
+        } else {
+            return Err(e);
+        }
+    }
+
+    // Ask for initial commit message if there are no commits yet
+    let has_commits = repo.run_command_with_output(&["rev-list", "--count", "--all"])
+        .map(|output| output.trim() != "0")  // If output is not "0", then there are commits
+        .unwrap_or(false);
+
+    if !has_commits {
+        let commit_message = UI::prompt_input(&t!("github.initial_commit_prompt"));
+        let commit_message = if commit_message.trim().is_empty() {
+            "Initial commit"
+        } else {
+            commit_message.trim()
+        };
+
+        // Stage all files
+        repo.run_command(&["add", "."])?;
+
+        // Create initial commit
+        repo.run_command(&["commit", "-m", commit_message])?;
+        println!("\n{}", t!("github.initial_commit_created", message = commit_message));
+    }
+
+    if settings.template.is_some() {
+        repo.configure_auth_remote()?;
+        let template_branch = response_json["default_branch"].as_str().unwrap_or("main");
+        merge_template_content(repo, template_branch)?;
+    }
+
+    // GitHub makes the first pushed branch the default one
+    let remote_default = response_json["default_branch"].as_str()
+        .filter(|_| settings.template.is_some())
+        .unwrap_or(&branch)
+        .to_string();
+
+    println!("\n{}", t!("github.pushing"));
+
+    // First, try to push with -u (which sets upstream)
+    match repo.run_command(&["push", "-u", "origin", &branch]) {
+        Ok(_) => {
+            apply_default_branch(&api, &settings, &remote_default);
+            println!("\n{}", t!("github.pushed_to", url = repo_url));
+            Ok(())
+        },
+        Err(e) => {
+            println!("\n{}", t!("github.push_failed", error = e));
+
+            // Try to fetch first in case the remote has changes
+            println!("\n{}", t!("github.fetching"));
+            if let Err(e) = repo.run_command(&["fetch"]) {
+                println!("{}", t!("github.fetch_failed", error = e));
+            }
+
+            // Try to set up tracking with a more robust approach
+            println!("\n{}", t!("github.setting_tracking"));
+
+            // Create commands with proper references to branch
+            let branch_ref = branch.as_str();
+            let setup_commands = [
+                ("branch", vec!["--set-upstream-to".to_string(), format!("origin/{}", branch_ref), branch_ref.to_string()]),
+                ("push", vec!["-u".to_string(), "origin".to_string(), branch_ref.to_string()]),
+            ];
+
+            for (cmd, args) in setup_commands.iter() {
+                let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+                if let Err(e) = repo.run_command(&args_refs) {
+                    println!("{}", t!("github.command_failed", command = format!("{} {}", cmd, args.join(" "))));
+                    println!("{}", t!("github.error_detail", error = e));
+                }
+            }
+
+            // Final attempt to push
+            if UI::prompt_yes_no(&t!("github.retry_push")) {
+                if let Err(e) = repo.run_command(&["push"]) {
+                    println!("\n{}", t!("github.final_push_failed", error = e));
+                    println!("\n{}", t!("github.manual_tracking"));
+                    println!("  git branch --set-upstream-to=origin/{} {}", branch, branch);
+                    println!("  git push -u origin {}", branch);
+                    return Err(GitError::Other(t!("github.push_incomplete_failed")));
+                } else {
+                    apply_default_branch(&api, &settings, &remote_default);
+                    println!("\n{}", t!("github.pushed"));
+                    return Ok(());
+                }
+            }
+
+            Err(GitError::Other(t!("github.push_not_completed")))
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::error::{GitError, Result};
+use crate::i18n::t;
+use crate::UI;
+
+// ============================================================================
+// TEMPLATES
+// ============================================================================
+
+struct Template {
+    name: &'static str,
+    // Files whose presence in the project root selects this template; a
+    // leading `*` matches by extension (e.g. `*.csproj`). Empty markers mean
+    // the template is always suggested.
+    markers: &'static [&'static str],
+    body: &'static str,
+}
+
+const TEMPLATES: &[Template] = &[
+    Template {
+        name: "rust",
+        markers: &["Cargo.toml"],
+        body: "/target/\n**/*.rs.bk\n*.pdb",
+    },
+    Template {
+        name: "node",
+        markers: &["package.json"],
+        body: "node_modules/\nnpm-debug.log*\nyarn-debug.log*\nyarn-error.log*\n.pnpm-debug.log*\n.npm/\n.env\n.env.local\ncoverage/",
+    },
+    Template {
+        name: "python",
+        markers: &["pyproject.toml", "requirements.txt", "setup.py", "setup.cfg", "Pipfile"],
+        body: "__pycache__/\n*.py[cod]\n*.egg-info/\n.venv/\nvenv/\nbuild/\ndist/\n.pytest_cache/\n.mypy_cache/\n.ruff_cache/\n.coverage\nhtmlcov/",
+    },
+    Template {
+        name: "go",
+        markers: &["go.mod"],
+        body: "*.exe\n*.exe~\n*.dll\n*.so\n*.dylib\n*.test\n*.out\ngo.work.sum",
+    },
+    Template {
+        name: "maven",
+        markers: &["pom.xml"],
+        body: "target/\npom.xml.tag\npom.xml.releaseBackup\npom.xml.versionsBackup\nrelease.properties",
+    },
+    Template {
+        name: "gradle",
+        markers: &["build.gradle", "build.gradle.kts", "settings.gradle", "settings.gradle.kts"],
+        body: ".gradle/\nbuild/\n!gradle/wrapper/gradle-wrapper.jar\nlocal.properties",
+    },
+    Template {
+        name: "dotnet",
+        markers: &["*.csproj", "*.fsproj", "*.sln"],
+        body: "bin/\nobj/\n*.user\n*.suo\n.vs/",
+    },
+    Template {
+        name: "ruby",
+        markers: &["Gemfile"],
+        body: ".bundle/\nvendor/bundle/\nlog/\ntmp/\n*.gem",
+    },
+    Template {
+        name: "php",
+        markers: &["composer.json"],
+        body: "vendor/\n.phpunit.result.cache",
+    },
+    Template {
+        name: "cmake",
+        markers: &["CMakeLists.txt"],
+        body: "build/\nCMakeCache.txt\nCMakeFiles/\ncmake_install.cmake\ncompile_commands.json",
+    },
+    Template {
+        name: "macos",
+        markers: &[],
+        body: ".DS_Store\n.DS_Store?\n._*\n.Spotlight-V100\n.Trashes",
+    },
+    Template {
+        name: "windows",
+        markers: &[],
+        body: "Thumbs.db\nehthumbs.db\nDesktop.ini",
+    },
+    Template {
+        name: "editors",
+        markers: &[],
+        body: ".idea/\n.vscode/\n*.swp\n*.swo\n*~",
+    },
+];
+
+fn find_template(name: &str) -> Option<&'static Template> {
+    TEMPLATES.iter().find(|t| t.name.eq_ignore_ascii_case(name.trim()))
+}
+
+pub fn template_names() -> Vec<&'static str> {
+    TEMPLATES.iter().map(|t| t.name).collect()
+}
+
+// ============================================================================
+// DETECTION
+// ============================================================================
+
+fn marker_present(root: &Path, marker: &str, entries: &[String]) -> bool {
+    match marker.strip_prefix('*') {
+        Some(suffix) => entries.iter().any(|e| e.ends_with(suffix)),
+        None => root.join(marker).exists(),
+    }
+}
+
+/// Suggests templates for the project in `root`: every language whose marker
+/// files are present, plus the always-on OS and editor templates.
+pub fn detect_templates(root: &Path) -> Vec<&'static str> {
+    let entries: Vec<String> = fs::read_dir(root)
+        .map(|dir| dir.flatten().map(|e| e.file_name().to_string_lossy().to_string()).collect())
+        .unwrap_or_default();
+
+    TEMPLATES.iter()
+        .filter(|t| t.markers.is_empty() || t.markers.iter().any(|m| marker_present(root, m, &entries)))
+        .map(|t| t.name)
+        .collect()
+}
+
+// ============================================================================
+// SELECTION AND MERGE
+// ============================================================================
+
+/// How the `.gitignore` templates for a new repository are chosen.
+#[derive(Debug, Clone, Default)]
+pub enum Selection {
+    /// Detect from project files and let the user adjust the list.
+    #[default]
+    Interactive,
+    /// Use exactly these templates (from `--gitignore a,b,c`).
+    Named(Vec<String>),
+    /// Leave `.gitignore` alone (`--no-gitignore`).
+    Skip,
+}
+
+fn parse_names(list: &str) -> Result<Vec<&'static Template>> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .map(|name| find_template(name).ok_or_else(|| GitError::Usage(t!(
+            "gitignore.unknown_template",
+            name = name,
+            available = template_names().join(", ")
+        ))))
+        .collect()
+}
+
+fn choose_templates(root: &Path, selection: &Selection) -> Result<Vec<&'static Template>> {
+    match selection {
+        Selection::Skip => Ok(Vec::new()),
+        Selection::Named(names) => parse_names(&names.join(",")),
+        Selection::Interactive => {
+            let detected = detect_templates(root).join(",");
+            println!("{}", UI::center_text(&t!("gitignore.detected", templates = detected)));
+            println!("{}", UI::center_text(&t!("gitignore.available", templates = template_names().join(", "))));
+            loop {
+                let input = UI::prompt_input(&t!("gitignore.choose_prompt", default = detected));
+                let list = if input.is_empty() { detected.clone() } else { input };
+                if list == "-" {
+                    return Ok(Vec::new());
+                }
+                match parse_names(&list) {
+                    Ok(templates) => return Ok(templates),
+                    Err(e) => println!("{}", UI::center_text(&format!("❌ {}", e))),
+                }
+            }
+        }
+    }
+}
+
+fn is_rule(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && !line.starts_with('#')
+}
+
+/// Appends the template sections to `existing`, skipping every rule that is
+/// already present (in the file or in an earlier template). Returns `None`
+/// when there is nothing new to add.
+fn merge(existing: &str, templates: &[&Template]) -> Option<String> {
+    let mut seen: HashSet<String> = existing.lines().filter(|l| is_rule(l)).map(|l| l.trim().to_string()).collect();
+    let mut out = existing.to_string();
+    let mut added = false;
+
+    for template in templates {
+        let new_rules: Vec<&str> = template.body.lines()
+            .filter(|l| is_rule(l) && seen.insert(l.trim().to_string()))
+            .collect();
+        if new_rules.is_empty() {
+            continue;
+        }
+
+        if !out.is_empty() && !out.ends_with('\n') {
+            out.push('\n');
+        }
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("# {}\n", template.name));
+        for rule in new_rules {
+            out.push_str(rule);
+            out.push('\n');
+        }
+        added = true;
+    }
+
+    added.then_some(out)
+}
+
+/// Creates or extends `.gitignore` in `root` from the selected templates.
+pub fn write_gitignore(root: &Path, selection: &Selection) -> Result<()> {
+    let templates = choose_templates(root, selection)?;
+    if templates.is_empty() {
+        return Ok(());
+    }
+
+    let path = root.join(".gitignore");
+    let existing = if path.exists() {
+        fs::read_to_string(&path).map_err(|e| GitError::io(t!("error.read_gitignore"), e))?
+    } else {
+        String::new()
+    };
+
+    let names: Vec<&str> = templates.iter().map(|t| t.name).collect();
+    match merge(&existing, &templates) {
+        Some(content) => {
+            fs::write(&path, content).map_err(|e| GitError::io(t!("error.create_gitignore"), e))?;
+            let key = if existing.is_empty() { "gitignore.created" } else { "gitignore.merged" };
+            println!("{}", UI::center_text(&t!(key, templates = names.join(", "))));
+        }
+        None => println!("{}", UI::center_text(&t!("gitignore.up_to_date"))),
+    }
+
+    Ok(())
+}
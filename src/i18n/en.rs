@@ -0,0 +1,754 @@
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Prompts and terminal I/O
+    ("prompt.yes_no_suffix", "(y/n)"),
+    ("prompt.yes_answers", "y,yes"),
+    ("prompt.continue_anyway", "Do you want to continue anyway?"),
+    ("io.flush_failed", "Error flushing stdout: {error}"),
+    ("io.read_failed", "Error reading input: {error}"),
+
+    // Repository helpers
+    ("repo.branch_error", "Error getting branch: {error}"),
+    ("repo.status_check_failed", "Error checking for changes: {error}"),
+    ("repo.status_run_failed", "Error running git status: {error}"),
+    ("repo.git_stderr", "git stderr: {stderr}"),
+
+    // Errors
+    ("error.no_changes", "No changes to commit"),
+    ("error.no_commit_message", "No commit message provided"),
+    ("error.no_token", "No GitHub token found"),
+    ("error.no_internet", "No internet connection"),
+    ("error.auth", "Authentication failed: {details}"),
+    ("error.network", "Network error: {details}"),
+    ("error.conflict", "Conflict: {details}"),
+    ("error.push_rejected", "Push rejected ({reason}): {details}"),
+    ("error.missing_upstream", "No upstream branch configured: {details}"),
+    ("error.command_failed", "Command failed: {details}"),
+    ("error.exit_status", "exit status {code}"),
+    ("error.exec_git", "Failed to execute git command"),
+    ("error.parse_output", "Failed to parse command output: {error}"),
+    ("error.root_missing", "Repository root directory does not exist: {path}"),
+    ("error.root_not_dir", "Repository root is not a directory: {path}"),
+    ("error.root_access", "Cannot access repository directory {path}"),
+    ("error.root_readonly", "Insufficient permissions to read repository: {path}"),
+    ("error.spawn_git", "Failed to spawn git command"),
+    ("error.wait_git", "Failed to wait for git command"),
+    ("error.no_remote_url", "Failed to get remote URL"),
+    ("error.credential_spawn", "Failed to spawn git credential command"),
+    ("error.credential_write", "Failed to write to git credential stdin"),
+    ("error.credential_wait", "Failed to wait for git credential command"),
+    ("error.credential_store", "Failed to store credentials"),
+    ("error.check_conflicts", "Failed to check for merge conflicts"),
+    ("error.git_init", "Failed to run git init"),
+    ("error.rename_branch", "Failed to rename branch to main: {error}"),
+    ("error.create_gitignore", "Failed to create .gitignore"),
+    ("error.current_dir", "Failed to get current directory"),
+    ("error.detached_head", "Cannot push from a detached HEAD"),
+    ("error.git_push", "Failed to execute git push"),
+
+    // Cancellations
+    ("cancel.operation", "User cancelled the operation"),
+    ("cancel.commit", "User cancelled the commit"),
+    ("cancel.by_user", "Operation cancelled by user"),
+    ("cancel.repo_creation", "Repository creation cancelled by user"),
+
+    // Authentication
+    ("auth.token_found", "🔑 Found GitHub token"),
+    ("auth.no_token", "ℹ️  No GitHub token found"),
+    ("auth.helper_configured", "✅ Configured secure credential helper"),
+    ("auth.existing_login", "🔑 Using your existing login ({source})"),
+    ("auth.gh_setup_git_hint", "💡 If git asks for a password, run 'gh auth setup-git' once."),
+    ("auth.using_ssh", "ℹ️  Using SSH authentication (no token needed)"),
+    ("auth.setup.title", "❌ No GitHub token found in the environment"),
+    ("auth.setup.intro", "   Please set it up before continuing:"),
+    ("auth.setup.step1", "   1. Create a Personal Access Token in GitHub with 'repo' scope"),
+    ("auth.setup.step2", "   2. Add it to your shell configuration (e.g., ~/.zshrc):"),
+    ("auth.setup.step3", "   3. Reload your shell: source ~/.zshrc"),
+    ("auth.setup.ssh", "   Alternatively, you can use SSH for authentication instead."),
+
+    // Status display
+    ("status.no_changes_subpath", "🟢 No changes in current subpath"),
+    ("status.root_group", "(root)"),
+
+    // Staging and committing
+    ("stage.header", "📄 Changes to be staged:"),
+    ("stage.no_changes", "🟢 No changes to add in the current folder"),
+    ("stage.confirm", "Press Enter to stage these changes, d + Enter to review the diff, or Ctrl+C to cancel..."),
+    ("stage.cancelled", "❌ Operation cancelled"),
+    ("stage.staging", "⏳ Staging changes..."),
+    ("stage.added", "✅ Changes added"),
+    ("stage.nothing_to_commit", "ℹ️  There's nothing to commit"),
+    ("stage.all_committed", "   All changes are already committed"),
+    ("commit.staged_header", "📝 Staged changes to be committed:"),
+    ("commit.confirm", "Press Enter to commit these changes, d + Enter to review the diff, or anything else to cancel"),
+    ("commit.cancelled", "❌ Commit cancelled"),
+    ("commit.message_prompt", "Enter commit message (or leave empty to cancel)"),
+    ("commit.no_message", "❌ Commit cancelled - no message provided"),
+
+    // Conflict checks
+    ("conflicts.unresolved", "You have unresolved conflicts. Please resolve them before continuing."),
+    ("conflicts.merge_in_progress", "A merge is in progress. Please complete or abort the merge before continuing."),
+
+    // Pending pushes
+    ("pending.verification_error", "⚠️  Verification error:"),
+    ("pending.none", "✅ No pending commits to push"),
+    ("pending.warning", "⚠️  WARNING: You have commits that need to be pushed"),
+    ("pending.ahead.one", "   {count} commit ahead of remote repository"),
+    ("pending.ahead.other", "   {count} commits ahead of remote repository"),
+    ("pending.risk", "   This could cause conflicts or duplicate commits."),
+    ("pending.push_first", "Do you want to push the existing commits first?"),
+    ("pending.continue_without", "⚠️  Continuing with the new commit without pushing changes..."),
+    ("pending.pushing", "⬆️  Pushing existing commits..."),
+    ("pending.no_token", "❌ Cannot push: GitHub token not found"),
+    ("pending.configure_token", "   Please configure your GitHub token"),
+    ("pending.no_internet", "⚠️  No internet connection. Cannot push existing commits."),
+    ("pending.pushed", "✅ Existing commits pushed successfully!"),
+
+    // Pushing
+    ("push.success", "✅ Changes pushed successfully!"),
+    ("push.no_internet", "⚠️  No internet connection. Changes have been saved locally but not pushed."),
+    ("push.run_manually", "    Please run 'git push' manually when you have connection."),
+    ("push.rejected", "❌ Push rejected: {reason}"),
+    ("push.reason.non_fast_forward", "branch has diverged (non-fast-forward)"),
+    ("push.reason.protected", "protected branch"),
+    ("push.reason.hook", "declined by server hook"),
+    ("push.reason.auth", "authentication failed"),
+    ("push.reason.too_large", "push too large"),
+    ("push.reason.unknown", "unknown reason"),
+    ("push.explain.non_fast_forward", "The remote branch has commits that your local branch doesn't have.\nPushing now would discard them, so git refused."),
+    ("push.explain.protected", "The remote branch is protected and doesn't accept direct pushes.\nPush to another branch and open a pull request instead."),
+    ("push.explain.hook", "A server-side hook rejected the push.\nCheck the 'remote:' lines above for the reason given by the server."),
+    ("push.explain.auth", "The remote refused your credentials.\nCheck your token or SSH key and that you have write access to the repository."),
+    ("push.explain.too_large", "The push contains files or a pack larger than the server accepts.\nRemove the large files from the unpushed commits or track them with Git LFS."),
+    ("push.explain.unknown", "git couldn't push your changes; see the error output above."),
+    ("push.fetching_compare", "🔄 Fetching {remote}/{branch} to compare histories..."),
+    ("push.fetch_failed", "⚠️  Could not fetch the remote branch: {error}"),
+    ("push.local_only.one", "⬆️  {count} local commit not on {remote}/{branch}:"),
+    ("push.local_only.other", "⬆️  {count} local commits not on {remote}/{branch}:"),
+    ("push.remote_only.one", "⬇️  {count} remote commit not in your branch:"),
+    ("push.remote_only.other", "⬇️  {count} remote commits not in your branch:"),
+    ("push.recommend_pull", "Recommended: pull the remote commits, then run syncgit again."),
+    ("push.overwrite_warning", "Overwriting will permanently remove the remote commits listed above."),
+    ("push.overwrite_prompt", "Do you want to overwrite the remote branch with your local history?"),
+    ("push.cancelled_local_kept", "ℹ️  Push cancelled. Your local commits are untouched."),
+    ("push.type_to_confirm", "Type '{branch}' to confirm the overwrite"),
+    ("push.confirm_mismatch", "❌ Confirmation didn't match. Push cancelled."),
+    ("push.force_pushing", "⬆️  Force-pushing with lease..."),
+    ("push.overwritten", "✅ Remote branch overwritten with your local history"),
+    ("push.stale_lease", "❌ The remote branch changed again since it was fetched."),
+    ("push.stale_lease_hint", "   Nothing was overwritten. Run syncgit again to review the new commits."),
+
+    // Repository initialization
+    ("init.no_repo_found", "No Git repository found in current directory or its parents."),
+    ("init.ask_init", "Do you want to initialize a new Git repository here?"),
+    ("init.created_commit", "✅ Created initial commit"),
+    ("init.nothing_to_commit", "ℹ️  No files to commit in the initial repository"),
+    ("init.exiting", "Exiting..."),
+
+    // GitHub repository creation
+    ("github.create_prompt", "Do you want to create a GitHub repository and push to it?"),
+    ("github.create_later", "You can create the repository manually later."),
+    ("github.created", "✅ Repository created and pushed to GitHub!"),
+    ("github.name_prompt", "Enter GitHub repository name [{default}]"),
+    ("github.name_empty", "❌ Repository name cannot be empty. Please try again."),
+    ("github.name_invalid", "❌ Repository name can only contain alphanumeric characters, hyphens, underscores, and dots. Please try again."),
+    ("github.description_prompt", "Enter repository description (optional)"),
+    ("github.private_prompt", "Should this repository be private?"),
+    ("github.creating", "🔄 Creating GitHub repository..."),
+    ("github.request_failed", "Failed to send request to GitHub API: {error}"),
+    ("github.unknown_error", "Unknown error"),
+    ("github.already_exists", "⚠️  Repository '{name}' already exists on GitHub"),
+    ("github.use_existing", "Do you want to use the existing repository and push to it?"),
+    ("github.pushing_existing", "🚀 Pushing to existing GitHub repository..."),
+    ("github.pushed_to", "✅ Successfully pushed to GitHub repository: {url}"),
+    ("github.error_401", "Please check your GitHub token. Error: {error}"),
+    ("github.error_403", "Permission denied. Your token may not have 'repo' scope. Error: {error}"),
+    ("github.error_422", "Invalid repository name or repository already exists. Error: {error}"),
+    ("github.error_status", "GitHub API error (status {status}): {error}"),
+    ("github.parse_failed", "Failed to parse GitHub response: {error}"),
+    ("github.no_html_url", "Failed to get repository URL from GitHub response"),
+    ("github.no_clone_url", "Failed to get clone URL from GitHub response"),
+    ("github.origin_exists", "ℹ️  Remote 'origin' already exists: {url}"),
+    ("github.update_remote", "Do you want to update the existing remote URL?"),
+    ("github.using_existing_remote", "⚠️  Using existing remote. You may need to manually set up tracking."),
+    ("github.initial_commit_prompt", "Enter initial commit message (or press Enter for 'Initial commit')"),
+    ("github.initial_commit_created", "✅ Created initial commit with message: {message}"),
+    ("github.pushing", "🚀 Pushing to GitHub repository..."),
+    ("github.push_failed", "⚠️  Failed to push to remote repository: {error}"),
+    ("github.fetching", "🔄 Fetching from remote..."),
+    ("github.fetch_failed", "⚠️  Failed to fetch from remote: {error}"),
+    ("github.setting_tracking", "🔗 Setting up tracking..."),
+    ("github.command_failed", "⚠️  Command failed: git {command}"),
+    ("github.error_detail", "   Error: {error}"),
+    ("github.retry_push", "Would you like to try pushing again?"),
+    ("github.final_push_failed", "❌ Final push attempt failed: {error}"),
+    ("github.manual_tracking", "You may need to manually set up tracking with these commands:"),
+    ("github.push_incomplete_failed", "Failed to push to remote repository"),
+    ("github.pushed", "✅ Successfully pushed to GitHub repository!"),
+    ("github.push_not_completed", "Push to remote repository was not completed"),
+
+    // Sync status
+    ("sync.unpushed", "⚠️  You have unpushed changes:"),
+    ("sync.commits_ahead.one", "{count} commit ahead of remote"),
+    ("sync.commits_ahead.other", "{count} commits ahead of remote"),
+    ("sync.push_confirm", "Press Enter to push changes, or Ctrl+C to cancel"),
+    ("sync.offline_local", "ℹ️  No internet connection. Changes will remain local for now."),
+    ("sync.behind.one", "⚠️  Your local branch is behind: {count} commit behind remote"),
+    ("sync.behind.other", "⚠️  Your local branch is behind: {count} commits behind remote"),
+    ("sync.to_sync.one", "You have {count} commit to sync from remote"),
+    ("sync.to_sync.other", "You have {count} commits to sync from remote"),
+    ("sync.view_confirm", "Press Enter to view and sync these changes, or Ctrl+C to cancel"),
+    ("sync.cancelled", "❌ Sync cancelled"),
+    ("sync.confirm", "Press Enter to confirm sync, or Ctrl+C to cancel"),
+    ("sync.syncing", "🔄 Syncing changes..."),
+    ("sync.done", "✅ Successfully synced with remote!"),
+    ("sync.offline_working", "ℹ️  No internet connection. Working with local version for now."),
+    ("sync.in_sync", "✅ Your repository is in sync with remote"),
+
+    // Main workflow
+    ("main.warning", "⚠️  Warning"),
+    ("main.repo_root", "📁 Repository root: {name}"),
+    ("main.subpath_root", ". (repo root)"),
+    ("main.subpath", "🧭 Subpath: {path}"),
+    ("main.status_header", "🔍 Repository status:"),
+    ("main.checking_pending", "🔍 Checking for pending pushes..."),
+    ("main.pulling", "⬇️  Pulling changes..."),
+    ("main.no_remote_skip_pull", "ℹ️  No remote configured. Skipping pull."),
+    ("main.checking_changes", "📦 Checking local changes..."),
+    ("main.no_changes_here", "ℹ️  No changes detected in the current folder"),
+    ("main.changes_elsewhere", "   However, there are pending changes elsewhere in the repository."),
+    ("main.tip_root", "   Tip: run this tool from the repo root or navigate to the folder with changes."),
+    ("main.push_about", "⚠️  You're about to push your changes to the remote repository."),
+    ("main.push_confirm", "   Press Enter to confirm push, or Ctrl+C to cancel"),
+    ("main.push_cancelled", "❌ Push cancelled"),
+    ("main.pushing", "⬆️  Pushing changes..."),
+    ("main.committed_locally", "ℹ️  No remote configured. Changes committed locally."),
+
+    // Command line and .gitignore generation
+    ("error.read_gitignore", "Failed to read .gitignore"),
+    ("gitignore.detected", "🔎 Detected .gitignore templates: {templates}"),
+    ("gitignore.available", "   Available: {templates}"),
+    ("gitignore.choose_prompt", "Templates to use, comma separated ('-' for none) [{default}]"),
+    ("gitignore.unknown_template", "Unknown .gitignore template '{name}'. Available: {available}"),
+    ("gitignore.created", "✅ Created .gitignore from templates: {templates}"),
+    ("gitignore.merged", "✅ Added new rules to the existing .gitignore from: {templates}"),
+    ("gitignore.up_to_date", "ℹ️  .gitignore already contains every rule from the selected templates"),
+    ("cli.missing_value", "Missing value for {flag}"),
+    ("cli.unknown_argument", "Unknown argument '{arg}'. Run 'syncgit help' for usage."),
+    ("cli.unknown_command", "Unknown command '{command}'. Run 'syncgit help' for usage."),
+    ("cli.help", "Usage: syncgit [command]\n\nCommands:\n  (none)                 Review, commit and sync the current repository\n  init [options]         Initialize a repository here\n  auth status            Check the GitHub token: owner, scopes and expiry\n  auth ssh [remote]      Diagnose SSH keys, agent and config for a remote\n  bundle export [file]   Write unsynced commits to a bundle (--all branches, --full history)\n  bundle import <file>   Verify a bundle and merge it like a pull\n  flush                  Retry pushes queued while offline\n  queue                  List pushes waiting for a connection, in every repository\n  diff [--staged]        Review unstaged (or staged) changes in the diff viewer\n  stash [action] [n]     List, preview, apply, pop or drop stashes (list, show, apply, pop, drop)\n  worktree [action]      List worktrees, or add <branch> [path] / remove <branch|path>\n  release [version]      Bump the version, tag, push and publish a release (major, minor, patch or X.Y.Z; --sign)\n  changelog [--stdout]   Write or update CHANGELOG.md from the commits between version tags\n  identity [action]      Show who commits here, or use <profile> / add <profile> identity profiles\n  tui                    Full-screen dashboard: stage, commit, pull, push and stash\n  help                   Show this help\n\nInit options:\n  --gitignore <list>     Comma-separated .gitignore templates\n  --no-gitignore         Don't create or modify .gitignore\n  --scaffold             Generate README, LICENSE and editor config without asking\n  --no-scaffold          Don't offer README/LICENSE scaffolding\n  --license <id>         License for the scaffolded LICENSE file (or 'none')\n\nTemplates: {templates}\nLicenses: {licenses}"),
+
+    // Project scaffolding
+    ("error.read_file", "Failed to read {path}"),
+    ("error.write_file", "Failed to write {path}"),
+    ("scaffold.ask", "Generate README, LICENSE, .editorconfig and .gitattributes?"),
+    ("scaffold.licenses_available", "📜 Available licenses: {licenses}"),
+    ("scaffold.license_prompt", "License ('none' for no LICENSE) [{default}]"),
+    ("scaffold.unknown_license", "Unknown license '{license}'. Available: {available}"),
+    ("scaffold.author_prompt", "Copyright holder for LICENSE"),
+    ("scaffold.readme_getting_started", "## Getting Started\n\nDescribe how to install, build and use the project here."),
+    ("scaffold.readme_license", "## License\n\nDistributed under the {license} license. See [LICENSE](LICENSE) for details."),
+    ("scaffold.preview_title", "👀 Files to be created:"),
+    ("scaffold.preview_lines.one", "{count} line"),
+    ("scaffold.preview_lines.other", "{count} lines"),
+    ("scaffold.confirm", "Write these files?"),
+    ("scaffold.skipped", "ℹ️  Scaffolding skipped, nothing was written"),
+    ("scaffold.nothing_to_do", "ℹ️  README, LICENSE, .editorconfig and .gitattributes already exist"),
+    ("scaffold.created", "✅ Created and committed: {files}"),
+
+    // GitHub repository options
+    ("github.no_login", "Failed to read your GitHub login from the API"),
+    ("github.owner_title", "👥 Where should the repository be created?"),
+    ("github.owner_personal", "{login} (personal account)"),
+    ("github.owner_prompt", "Owner number [1]"),
+    ("github.owner_invalid", "❌ Please enter one of the numbers listed above."),
+    ("github.visibility_prompt", "Visibility (public, private, internal) [public]"),
+    ("github.visibility_invalid", "❌ Visibility must be public, private or internal."),
+    ("github.advanced_prompt", "Configure template, topics, homepage, default branch and features?"),
+    ("github.template_prompt", "Template repository as owner/name (empty for none)"),
+    ("github.template_invalid", "❌ Use the form owner/name, e.g. octo-org/rust-template."),
+    ("github.topics_prompt", "Topics, comma separated (empty for none)"),
+    ("github.topic_invalid", "❌ Invalid topic '{topic}': use lowercase letters, digits and hyphens (max 50)."),
+    ("github.homepage_prompt", "Homepage URL (empty for none)"),
+    ("github.default_branch_prompt", "Default branch [{default}]"),
+    ("github.enable_issues", "Enable issues?"),
+    ("github.enable_wiki", "Enable the wiki?"),
+    ("github.enable_projects", "Enable projects?"),
+    ("github.settings_failed", "⚠️  Repository created, but some settings could not be applied: {error}"),
+    ("github.default_branch_failed", "⚠️  Could not set the default branch to '{branch}': {error}"),
+    ("github.waiting_template", "⏳ Waiting for GitHub to copy the template content..."),
+    ("github.template_merged", "✅ Merged the template content into your local history"),
+    ("github.template_not_ready", "The repository was created from the template, but its content is not available yet. Run 'git pull --allow-unrelated-histories origin' later and push again."),
+
+    // Existing repository fallback
+    ("github.no_push_permission", "Your account can't push to {repo}. Ask an owner for write access or choose another name."),
+    ("github.comparing_history", "🔍 Comparing your history with {repo}..."),
+    ("github.ssh_fallback", "⚠️  SSH authentication failed; falling back to HTTPS with your token"),
+    ("github.history.empty", "   The remote repository is empty; your branch will be pushed as is."),
+    ("github.history.up_to_date", "   The remote branch '{branch}' already matches your local history."),
+    ("github.history.fast_forward", "   The remote branch '{branch}' is behind yours; the push will be a fast-forward."),
+    ("github.history.behind", "   The remote branch '{branch}' has newer commits; they will be fast-forwarded into your branch first."),
+    ("github.history.diverged", "   Your history and '{branch}' have diverged; the remote commits need to be merged before pushing."),
+    ("github.history.unrelated", "   '{branch}' has a history unrelated to yours; pushing would need a merge commit combining both."),
+    ("github.confirm_existing", "Continue and set 'origin' to {url}?"),
+    ("github.confirm_merge.one", "Merge {count} remote commit from '{branch}' into your branch '{local}' now?"),
+    ("github.confirm_merge.other", "Merge {count} remote commits from '{branch}' into your branch '{local}' now?"),
+    ("github.confirm_unrelated_merge.one", "'{branch}' shares no history with '{local}'. Merge its {count} commit into your branch anyway, combining two unrelated projects?"),
+    ("github.confirm_unrelated_merge.other", "'{branch}' shares no history with '{local}'. Merge its {count} commits into your branch anyway, combining two unrelated projects?"),
+    ("github.merge_declined", "Nothing was merged and 'origin' was not changed. To combine the histories yourself, run: {command}"),
+
+    // GitHub API client
+    ("github.error_404", "Not found on GitHub (or your token can't see it): {error}"),
+    ("github.rate_limited", "GitHub API rate limit exceeded; it resets in about {minutes} min"),
+    ("github.retrying", "⏳ GitHub answered {status}; retrying in {seconds}s..."),
+
+    // Auth status
+    ("auth.source.env", "{var} environment variable"),
+    ("auth.kind.fine_grained", "fine-grained personal access token"),
+    ("auth.kind.classic", "classic personal access token"),
+    ("auth.kind.oauth", "OAuth token"),
+    ("auth.kind.app", "GitHub App token"),
+    ("auth.kind.unknown", "unrecognized token format"),
+    ("auth.yes", "yes"),
+    ("auth.no", "no"),
+    ("auth.status.title", "🔐 GitHub authentication"),
+    ("auth.status.source", "   Token from:   {source}"),
+    ("auth.status.kind", "   Token type:   {kind}"),
+    ("auth.status.api", "   API:          {url}"),
+    ("auth.status.login", "   Logged in as: {login}"),
+    ("auth.status.login_named", "   Logged in as: {login} ({name})"),
+    ("auth.status.scopes", "   Scopes:       {scopes}"),
+    ("auth.status.none", "(none)"),
+    ("auth.status.fine_grained", "   Scopes:       not reported; fine-grained tokens need Contents (read/write) to push and Administration (read/write) to create repositories"),
+    ("auth.status.expires", "   Expires:      {expiry}"),
+    ("auth.status.never", "never"),
+    ("auth.status.push_access", "   Push access to {repo}: {answer}"),
+    ("auth.status.repo_permissions", "   Permissions on {repo}: {permissions}"),
+    ("auth.status.repo_unavailable", "   Could not check access to {repo}: {error}"),
+    ("auth.status.ok", "✅ The token is valid and can create repositories and push"),
+    ("auth.warn.public_only", "The token only has 'public_repo': it can't create or push to private repositories. Add the 'repo' scope."),
+    ("auth.warn.no_repo_scope", "The token lacks the 'repo' scope: creating repositories and pushing over HTTPS will fail."),
+    ("auth.warn.no_org_scope", "'{org}' owns this repository, but without 'read:org' the token can't see your organizations: they won't be offered as owners for new repositories."),
+    ("auth.warn.no_push", "You don't have push access to {repo}."),
+
+    // Token discovery
+    ("auth.source.credential_helper", "git credential helper"),
+    ("auth.source.gh", "GitHub CLI ({origin})"),
+    ("auth.source.netrc", "netrc file {path}"),
+    ("auth.searched", "   Tried: {vars}, git credential helpers, GitHub CLI (gh) and ~/.netrc"),
+
+    // Per-remote authentication
+    ("auth.other_host", "ℹ️  Remote on {host}: using your existing git credentials (no GitHub token needed)"),
+    ("auth.local_remote", "ℹ️  Local remote: no authentication needed"),
+
+    // SSH diagnostics
+    ("error.ssh_run", "Failed to run ssh"),
+    ("ssh.no_remote", "No remote to check: run this inside a repository with a remote, or pass a remote name or URL"),
+    ("ssh.not_ssh", "{url} is not an SSH remote"),
+    ("ssh.report.title", "🔑 SSH diagnostics"),
+    ("ssh.report.remote", "   Remote:       {destination}"),
+    ("ssh.report.connects", "   Connects to:  {user}@{host}, port {port}"),
+    ("ssh.report.config", "   ~/.ssh/config blocks that apply:"),
+    ("ssh.report.config_none", "   ~/.ssh/config: no matching Host blocks"),
+    ("ssh.report.agent_off", "   Agent:        not running (SSH_AUTH_SOCK is not set)"),
+    ("ssh.report.agent_unreachable", "   Agent:        not responding at {socket}"),
+    ("ssh.report.agent_unknown", "   Agent:        unknown (could not run ssh-add)"),
+    ("ssh.report.agent_keys.one", "   Agent:        {count} key loaded"),
+    ("ssh.report.agent_keys.other", "   Agent:        {count} keys loaded"),
+    ("ssh.report.keys", "   Keys ssh will offer:"),
+    ("ssh.report.keys_none", "   Keys ssh will offer: none besides the agent's"),
+    ("ssh.report.unused", "   Other keys in ~/.ssh, not offered to this host: {keys}"),
+    ("ssh.key.missing", "missing"),
+    ("ssh.key.too_open", "ignored, permissions too open"),
+    ("ssh.key.loaded", "loaded in the agent"),
+    ("ssh.key.locked", "passphrase-protected, not in the agent"),
+    ("ssh.key.ready", "ready, no passphrase"),
+    ("ssh.report.testing", "⏳ Testing the connection to {host}..."),
+    ("ssh.report.connected", "✅ Authenticated: {greeting}"),
+    ("ssh.report.connected_plain", "✅ Authenticated"),
+    ("ssh.report.ok", "✅ No SSH problems found"),
+    ("ssh.report.hint", "   Run 'syncgit auth ssh' for a full SSH report"),
+    ("ssh.diagnosis", "🔎 Why the SSH connection failed:"),
+    ("ssh.problem.agent_unreachable", "SSH_AUTH_SOCK points to {socket}, but no agent answers there. Start one with 'eval \"$(ssh-agent -s)\"' and load your key with 'ssh-add'."),
+    ("ssh.problem.missing_identity", "~/.ssh/config sets IdentityFile {path} for {host}, but that file does not exist."),
+    ("ssh.problem.permissions", "ssh ignores {path} because other users can read it. Fix it with 'chmod 600 {path}'."),
+    ("ssh.problem.locked", "{path} is protected by a passphrase and not loaded in the agent, so pushes without a terminal can't use it. Load it once with 'ssh-add {path}'."),
+    ("ssh.problem.locked_no_agent", "{path} is protected by a passphrase and no SSH agent is running. Start one with 'eval \"$(ssh-agent -s)\"' and run 'ssh-add {path}'."),
+    ("ssh.problem.identities_only", "IdentitiesOnly is set for {host}, so the keys in the agent aren't offered unless they are also listed as IdentityFile."),
+    ("ssh.problem.unused_key", "{path} exists but ssh doesn't offer it to {host}. Add 'IdentityFile {path}' to a 'Host {host}' block in ~/.ssh/config, or load it with 'ssh-add {path}'."),
+    ("ssh.problem.no_keys", "No SSH key is available for {host}. Create one with 'ssh-keygen -t ed25519' and add the .pub file to your account."),
+    ("ssh.fail.host_key_changed", "The host key of {host} changed since you last connected. The server may have been reinstalled, or someone may be intercepting the connection. Check the new fingerprint with the provider before removing the old one with 'ssh-keygen -R {host}'."),
+    ("ssh.fail.host_key_unknown", "{host} isn't in ~/.ssh/known_hosts and ssh couldn't ask you to trust it. Connect once with 'ssh -T {destination}' and confirm its fingerprint."),
+    ("ssh.fail.resolve", "{host} could not be resolved. Check the host in the remote URL, or the HostName of its block in ~/.ssh/config."),
+    ("ssh.fail.connect", "Could not reach {host} on port {port}. A firewall or proxy may be blocking SSH."),
+    ("ssh.fail.github_443", "GitHub also accepts SSH on port 443: add a 'Host github.com' block with 'Hostname ssh.github.com' and 'Port 443' to ~/.ssh/config."),
+    ("ssh.fail.passphrase", "The key's passphrase was wrong or could not be asked for."),
+    ("ssh.fail.agent_refused", "The SSH agent refused to sign with the key. It may be locked, or a hardware key may be waiting for a touch."),
+    ("ssh.fail.wrong_account", "The key logged in as {account}, who can't push to this repository. If you have several accounts, pick the right key with a Host alias in ~/.ssh/config."),
+    ("ssh.fail.publickey", "{host} rejected every key ssh offered."),
+    ("ssh.fail.register_key", "These keys look fine locally; make sure one of them is added to your account on {host}:"),
+
+    // Offline push queue
+    ("error.create_dir", "Failed to create {path}"),
+    ("queue.no_state_dir", "Could not find a directory for the push queue (set HOME or XDG_STATE_HOME)"),
+    ("queue.queued", "📥 Queued: syncgit will push when you're back online (or run 'syncgit flush')"),
+    ("queue.queue_failed", "⚠️  Could not queue the push: {error}"),
+    ("queue.retrying.one", "📤 Retrying {count} queued push"),
+    ("queue.retrying.other", "📤 Retrying {count} queued pushes"),
+    ("queue.entry", "   {repo}: {branch} → {remote}/{remote_branch}"),
+    ("queue.pushed", "      ✅ Pushed"),
+    ("queue.gone", "      🗑️  Removed: the repository, branch or remote no longer exists"),
+    ("queue.offline", "      ⏸️  Still offline, kept in the queue"),
+    ("queue.failed", "      ❌ {error}"),
+    ("queue.failed_hint", "      Kept in the queue; run syncgit in {path} to resolve it"),
+    ("queue.flushed", "✅ Every queued push has been sent"),
+    ("queue.still_waiting.one", "⏳ {count} push still waiting"),
+    ("queue.still_waiting.other", "⏳ {count} pushes still waiting"),
+    ("queue.empty", "✅ Nothing is waiting to be pushed"),
+    ("queue.title.one", "📥 {count} push waiting for a connection"),
+    ("queue.title.other", "📥 {count} pushes waiting for a connection"),
+    ("queue.details", "      {path} · {commits} · queued {age}"),
+    ("queue.commits.one", "{count} commit"),
+    ("queue.commits.other", "{count} commits"),
+    ("queue.new_branch", "new branch"),
+    ("queue.missing_repo", "repository not found"),
+    ("queue.age.now", "just now"),
+    ("queue.age.minutes.one", "{count} minute ago"),
+    ("queue.age.minutes.other", "{count} minutes ago"),
+    ("queue.age.hours.one", "{count} hour ago"),
+    ("queue.age.hours.other", "{count} hours ago"),
+    ("queue.age.days.one", "{count} day ago"),
+    ("queue.age.days.other", "{count} days ago"),
+    ("queue.flush_hint", "Run 'syncgit flush' to retry them now"),
+    ("queue.startup_confirm", "Push them now? Each push runs that repository's pre-push checks and sets up its credentials"),
+
+    // Bundle sync
+    ("bundle.usage", "Usage: syncgit bundle export [file] [--all] [--full] | syncgit bundle import <file>"),
+    ("bundle.nothing_to_export", "✅ Nothing to export: the other machine already has every commit"),
+    ("bundle.full_hint", "Use --full to export the whole history anyway, e.g. if a bundle was lost"),
+    ("bundle.overwrite", "{path} already exists. Overwrite it?"),
+    ("bundle.export_title", "📦 Writing {path}"),
+    ("bundle.export_branch.one", "   {branch}: {count} commit (whole history)"),
+    ("bundle.export_branch.other", "   {branch}: {count} commits (whole history)"),
+    ("bundle.export_branch_since.one", "   {branch}: {count} new commit since {known}"),
+    ("bundle.export_branch_since.other", "   {branch}: {count} new commits since {known}"),
+    ("bundle.exported", "✅ Exported to {path} ({size})"),
+    ("bundle.import_hint", "On the other machine, run: syncgit bundle import {file}"),
+    ("bundle.not_found", "Bundle file not found: {path}"),
+    ("bundle.missing_prerequisites.one", "❌ The bundle builds on {count} commit this repository doesn't have:"),
+    ("bundle.missing_prerequisites.other", "❌ The bundle builds on {count} commits this repository doesn't have:"),
+    ("bundle.export_full_hint", "An earlier bundle never made it here. Export again with 'syncgit bundle export --full' on the other machine."),
+    ("bundle.no_branches", "The bundle contains no branches"),
+    ("bundle.import_title", "📦 Importing {path}"),
+    ("bundle.branch_up_to_date", "   {branch}: already up to date"),
+    ("bundle.branch_incoming.one", "   {branch}: {count} incoming commit"),
+    ("bundle.branch_incoming.other", "   {branch}: {count} incoming commits"),
+    ("bundle.nothing_new", "✅ Nothing new in the bundle"),
+    ("bundle.import_confirm", "Merge these commits into your branches?"),
+    ("bundle.merging", "⬇️  Pulling {branch} from the bundle..."),
+    ("bundle.created_branch", "✅ Created branch {branch}"),
+    ("bundle.fast_forwarded", "✅ Fast-forwarded {branch}"),
+    ("bundle.diverged", "⚠️  {branch} has diverged; the imported version is kept as {imported}"),
+    ("bundle.imported", "✅ Bundle imported"),
+    ("bundle.diverged_hint", "Check out each diverged branch and run 'syncgit bundle import' again to merge it"),
+
+    // Incoming changes
+    ("incoming.commits_title.one", "⬇️  {count} incoming commit"),
+    ("incoming.commits_title.other", "⬇️  {count} incoming commits"),
+    ("incoming.commit", "   {hash} {date} {author}: {subject}"),
+    ("incoming.files_title.one", "📄 {count} file changed"),
+    ("incoming.files_title.other", "📄 {count} files changed"),
+    ("incoming.binary", "binary"),
+    ("incoming.totals", "   Total: +{added} -{removed}"),
+    ("incoming.conflict_title.one", "⚠️  {count} file is also modified locally and may conflict:"),
+    ("incoming.conflict_title.other", "⚠️  {count} files are also modified locally and may conflict:"),
+    ("incoming.conflict_file", "   ⚠️  {path}"),
+    ("incoming.conflict_hint", "Commit or back up your changes to these files before syncing"),
+
+    // TUI dashboard
+    ("tui.terminal_error", "Couldn't set up the terminal"),
+    ("tui.too_small", "Terminal too small for the dashboard: it needs at least {width}x{height}. Resize it or press q to quit."),
+    ("tui.pane.changes.one", "Changes ({count} file)"),
+    ("tui.pane.changes.other", "Changes ({count} files)"),
+    ("tui.pane.staged_diff", "Staged diff"),
+    ("tui.pane.log", "Recent commits"),
+    ("tui.pane.remote", "Remote"),
+    ("tui.detached", "detached HEAD"),
+    ("tui.in_sync", "in sync"),
+    ("tui.no_upstream", "no upstream"),
+    ("tui.stashes.one", "{count} stash"),
+    ("tui.stashes.other", "{count} stashes"),
+    ("tui.nothing_staged", "Nothing staged: select a file and press space, or press a to stage everything"),
+    ("tui.no_commits", "No commits yet"),
+    ("tui.keys", "↑↓ select · space stage/unstage · a stage all · c commit · d diff · p pull · P push · s stash · S pop stash · f fetch · PgUp/PgDn scroll diff · q quit"),
+    ("tui.commit_prompt", "Commit message (Enter to commit, Esc to cancel):"),
+    ("tui.committed", "✅ Committed"),
+    ("tui.nothing_to_stash", "No changes to stash"),
+    ("tui.stashed", "Changes stashed: press S to bring them back"),
+    ("tui.no_stash", "No stash to restore"),
+    ("tui.stash_popped", "Stash restored"),
+    ("tui.fetched", "Fetched from the remote"),
+    ("tui.press_enter", "Press Enter to return to the dashboard"),
+    ("tui.no_upstream_to_pull", "This branch has no upstream to pull from"),
+    ("tui.pull_confirm", "Pull these changes?"),
+    ("tui.remote.detached", "Not on a branch"),
+    ("tui.remote.none", "No remote configured: commits stay local"),
+    ("tui.remote.name", "{remote}: {url}"),
+    ("tui.remote.kind_github", "GitHub over HTTPS ({host}), pushes with the token"),
+    ("tui.remote.kind_ssh", "SSH ({host}), pushes with your keys"),
+    ("tui.remote.kind_other", "HTTPS ({host}), pushes with git's credential helpers"),
+    ("tui.remote.kind_local", "Local path, no network needed"),
+    ("tui.remote.tracking", "Tracking {upstream}: {ahead} to push, {behind} to pull"),
+    ("tui.remote.no_upstream", "'{branch}' has no upstream yet: P pushes and sets it"),
+    ("tui.remote.fetched", "Last fetched {age}"),
+    ("tui.remote.never_fetched", "Never fetched: press f to fetch"),
+    ("tui.remote.queued", "⏳ Push queued {age}, waiting for a connection"),
+
+    // Diff viewer
+    ("diff.unstaged", "Unstaged changes"),
+    ("diff.staged", "Staged changes"),
+    ("diff.no_unstaged", "No unstaged changes. Press Tab to see the staged ones."),
+    ("diff.no_staged", "No staged changes. Press Tab to see the unstaged ones."),
+    ("diff.file_position", "file {index}/{total}"),
+    ("diff.unified", "unified"),
+    ("diff.side_by_side", "side by side"),
+    ("diff.new_file", "new file"),
+    ("diff.deleted_file", "deleted"),
+    ("diff.renamed", "renamed from {from}"),
+    ("diff.mode_changed", "mode {mode}"),
+    ("diff.binary", "binary"),
+    ("diff.keys", "↑↓ scroll · PgUp/PgDn page · n/N next/previous file · v side by side/unified · Tab staged/unstaged · q back"),
+
+    // Stash manager
+    ("stash.auto_message", "sync of {branch} with its upstream"),
+    ("stash.auto_kept", "⚠️  Your local changes didn't apply cleanly on top of the synced branch. They are kept in the stash as a syncgit auto-stash."),
+    ("stash.manage_hint", "Run 'syncgit stash' to review, restore or drop them"),
+    ("stash.leftover_warning.one", "⚠️  Warning: 1 stash was left behind by an interrupted syncgit sync"),
+    ("stash.leftover_warning.other", "⚠️  Warning: {count} stashes were left behind by interrupted syncgit syncs"),
+    ("stash.existing_note.one", "ℹ️  You have 1 stash ('syncgit stash' to review it)"),
+    ("stash.existing_note.other", "ℹ️  You have {count} stashes ('syncgit stash' to review them)"),
+    ("stash.empty", "✅ No stashes"),
+    ("stash.title.one", "📦 1 stash"),
+    ("stash.title.other", "📦 {count} stashes"),
+    ("stash.entry", "  {stash}  on {branch} · {age} · {files}"),
+    ("stash.files.one", "1 file (+{added} -{removed})"),
+    ("stash.files.other", "{count} files (+{added} -{removed})"),
+    ("stash.entry_message", "      {message}"),
+    ("stash.entry_auto", "      🤖 Left by syncgit: {message}"),
+    ("stash.restore_hint", "🤖 marks changes syncgit stashed during a sync that didn't finish. Restore with 'syncgit stash pop {index}'"),
+    ("stash.not_found", "No stash '{stash}'. Run 'syncgit stash list' to see them."),
+    ("stash.choose", "Stash number to manage (Enter to quit)"),
+    ("stash.action_prompt", "{stash}: [s]how, [a]pply, [p]op, [d]rop, or Enter to go back"),
+    ("stash.answers.show", "s"),
+    ("stash.answers.apply", "a"),
+    ("stash.answers.pop", "p"),
+    ("stash.answers.drop", "d"),
+    ("stash.applied", "✅ Applied {stash}; it is still in the list"),
+    ("stash.popped", "✅ Applied and removed {stash}"),
+    ("stash.drop_confirm", "Drop {stash} for good?"),
+    ("stash.dropped", "🗑️  Dropped {stash}"),
+    ("stash.usage", "Usage: syncgit stash [list | show [n] | apply [n] | pop [n] | drop [n]]"),
+    ("diff.stash", "Stash {stash}"),
+    ("diff.no_stash_changes", "This stash has no changes"),
+
+    // Submodules
+    ("submodule.using_superproject", "📂 This folder is a submodule; syncing from its superproject '{name}'"),
+    ("submodule.title.one", "🧩 1 submodule:"),
+    ("submodule.title.other", "🧩 {count} submodules:"),
+    ("submodule.entry", "  {path}: {state}"),
+    ("submodule.state.uninitialized", "not initialized"),
+    ("submodule.state.conflict", "merge conflict"),
+    ("submodule.state.ahead", "new commits"),
+    ("submodule.state.behind", "behind the recorded commit"),
+    ("submodule.state.diverged", "diverged from the recorded commit"),
+    ("submodule.state.modified", "modified content"),
+    ("submodule.state.unpushed.one", "1 unpushed commit"),
+    ("submodule.state.unpushed.other", "{count} unpushed commits"),
+    ("submodule.state.detached", "detached HEAD"),
+    ("submodule.state.clean", "up to date"),
+    ("submodule.update_hint", "💡 Run 'git submodule update --init --recursive' to check out the recorded commits"),
+    ("submodule.has_changes", "🧩 Submodule '{path}' has uncommitted changes"),
+    ("submodule.detached_commit", "⚠️  It is on a detached HEAD; check out a branch in it to commit from syncgit"),
+    ("submodule.commit_first", "Commit the changes in '{path}' first?"),
+    ("submodule.needs_push.one", "🧩 Submodule '{path}' has 1 commit the superproject points at that isn't pushed"),
+    ("submodule.needs_push.other", "🧩 Submodule '{path}' has {count} commits not pushed yet, including the one the superproject points at"),
+    ("submodule.commit_on_no_branch", "⚠️  '{path}' points at {commit}, which is on no branch in the submodule; create a branch for it there and push it"),
+    ("submodule.push_first", "Push '{path}' before the superproject?"),
+    ("submodule.push_commit_first", "Push {commit} of '{path}' to {remote}/{branch} before the superproject?"),
+    ("submodule.pushed", "✅ Pushed submodule '{path}'"),
+    ("submodule.push_failed", "❌ Could not push submodule '{path}': {error}"),
+    ("submodule.dangling_warning", "⚠️  These submodule pointers refer to commits that aren't on any remote. Others won't be able to check them out:"),
+    ("submodule.dangling_entry", "  {path} → {commit}"),
+    ("submodule.push_anyway", "Push the superproject anyway?"),
+    ("submodule.unpushed_queued", "Submodule commits not on a remote yet: {paths}; push them first"),
+
+    // Worktrees
+    ("worktree.broken_link", "{path}/.git points at a git directory that no longer exists. If the main repository moved, run 'git worktree repair' from it."),
+    ("worktree.linked_note", "🌳 Linked worktree of {main}"),
+    ("worktree.title.one", "🌳 1 worktree"),
+    ("worktree.title.other", "🌳 {count} worktrees"),
+    ("worktree.entry", "{marker} {branch}  {path} · {state}"),
+    ("worktree.detached", "(detached at {commit})"),
+    ("worktree.state.main", "main"),
+    ("worktree.state.bare", "bare"),
+    ("worktree.state.missing", "directory missing"),
+    ("worktree.state.clean", "clean"),
+    ("worktree.state.changed.one", "1 changed file"),
+    ("worktree.state.changed.other", "{count} changed files"),
+    ("worktree.state.ahead.one", "1 commit to push"),
+    ("worktree.state.ahead.other", "{count} commits to push"),
+    ("worktree.state.behind.one", "1 commit to pull"),
+    ("worktree.state.behind.other", "{count} commits to pull"),
+    ("worktree.state.locked", "🔒 locked"),
+    ("worktree.state.locked_reason", "🔒 locked: {reason}"),
+    ("worktree.prune_hint", "💡 Run 'git worktree prune' to forget worktrees whose directory is gone"),
+    ("worktree.add_hint", "Add one with 'syncgit worktree add <branch> [path]'"),
+    ("worktree.usage", "Usage: syncgit worktree [list | add <branch> [path] | remove <branch|path>]"),
+    ("worktree.invalid_branch", "'{branch}' is not a valid branch name"),
+    ("worktree.already_checked_out", "Branch '{branch}' is already checked out in {path}"),
+    ("worktree.path_exists", "{path} already exists. Pass another path: syncgit worktree add <branch> <path>"),
+    ("worktree.tracking", "🔗 Tracking {upstream}"),
+    ("worktree.create_branch", "Branch '{branch}' doesn't exist. Create it from {from}?"),
+    ("worktree.created", "✅ '{branch}' is checked out in {path}"),
+    ("worktree.cd_hint", "cd {path} to work on it"),
+    ("worktree.not_found", "No worktree for '{target}'. Run 'syncgit worktree' to see them."),
+    ("worktree.cannot_remove_main", "The main worktree holds the repository itself and can't be removed"),
+    ("worktree.cannot_remove_current", "You are inside that worktree. Run the command from another one."),
+    ("worktree.remove_dirty.one", "⚠️  That worktree has 1 uncommitted change"),
+    ("worktree.remove_dirty.other", "⚠️  That worktree has {count} uncommitted changes"),
+    ("worktree.remove_force", "Remove it anyway and lose them?"),
+    ("worktree.removed", "🗑️  Removed the worktree at {path}"),
+    ("worktree.branch_kept", "Branch '{branch}' is kept; delete it with 'git branch -d {branch}'"),
+
+    // Git LFS
+    ("lfs.tracking", "📦 Git LFS stores: {patterns}"),
+    ("lfs.not_installed", "⚠️  This repository uses Git LFS but 'git lfs' isn't installed. Large files would be committed as-is and checkouts show pointer files."),
+    ("lfs.install_hint", "💡 Install it from https://git-lfs.com, then run 'git lfs install'"),
+    ("lfs.hooks_missing", "⚠️  Git LFS isn't set up for this clone: without its filters and pre-push hook, LFS files won't be converted or uploaded"),
+    ("lfs.install_prompt", "Run 'git lfs install --local' now?"),
+    ("lfs.installed", "✅ Git LFS is set up for this repository"),
+    ("lfs.install_failed", "❌ 'git lfs install' failed: {error}"),
+    ("lfs.large_files.one", "⚠️  1 file is larger than {size} and isn't stored with Git LFS:"),
+    ("lfs.large_files.other", "⚠️  {count} files are larger than {size} and aren't stored with Git LFS:"),
+    ("lfs.large_entry", "  {path} ({size})"),
+    ("lfs.more.one", "  …and 1 more"),
+    ("lfs.more.other", "  …and {count} more"),
+    ("lfs.commit_anyway", "Commit them without LFS anyway? Once pushed they stay in the history for good."),
+    ("lfs.track_prompt.one", "Track {pattern} with Git LFS (1 file)?"),
+    ("lfs.track_prompt.other", "Track {pattern} with Git LFS ({count} files)?"),
+    ("lfs.tracked", "✅ {pattern} is now stored with Git LFS (.gitattributes staged)"),
+    ("lfs.pending_upload.one", "📦 1 Git LFS object will be uploaded:"),
+    ("lfs.pending_upload.other", "📦 {count} Git LFS objects will be uploaded:"),
+    ("lfs.pending_entry", "  {path}"),
+    ("lfs.uploading", "⬆️  No Git LFS pre-push hook here; uploading the objects first..."),
+
+    // Project tasks
+    ("config.read_failed", "Could not read {file}"),
+    ("config.invalid", "Invalid {file}: {error}"),
+    ("tasks.expected_array", "'task' must be a list of [[task]] tables"),
+    ("tasks.expected_table", "task #{index} must be a table"),
+    ("tasks.missing_run", "task #{index} has no 'run' command"),
+    ("tasks.expected_strings", "'{key}' of task '{task}' must be a string or a list of strings"),
+    ("tasks.unknown_hook", "task '{task}' has an unknown 'when' value '{hook}' (use pre-commit or pre-push)"),
+    ("tasks.header.one", "🧪 Running 1 {hook} check from .syncgit.toml"),
+    ("tasks.header.other", "🧪 Running {count} {hook} checks from .syncgit.toml"),
+    ("tasks.not_applicable", "⏭️  {name}: no matching changes"),
+    ("tasks.running", "▶️  {name}: {command}"),
+    ("tasks.passed", "✅ {name} passed ({time})"),
+    ("tasks.failed", "❌ {name} failed with exit code {code} ({time})"),
+    ("tasks.spawn_failed", "❌ {name} could not start: {error}"),
+    ("tasks.choice", "[r]etry after fixing, [s]kip this check, or Enter to abort"),
+    ("tasks.choice_fix", "[f]ix with '{fix}', [r]etry after fixing, [s]kip this check, or Enter to abort"),
+    ("tasks.answers.fix", "f"),
+    ("tasks.answers.retry", "r"),
+    ("tasks.answers.skip", "s"),
+    ("tasks.fix_name", "fix {name}"),
+    ("tasks.overridden", "⚠️  Skipping {name} at your request"),
+    ("tasks.aborted", "Aborted after {name} failed"),
+    ("tasks.failed_unattended", "{name} failed; the queued push waits until it passes"),
+    ("tasks.approval_header", "🔐 .syncgit.toml wants to run these commands on your machine:"),
+    ("tasks.approval_command", "   {name}: {command}"),
+    ("tasks.approval_confirm", "Allow them in this repository? You'll be asked again if they change"),
+    ("tasks.not_approved", "⏭️  Project checks skipped; they'll be offered again next time"),
+    ("tasks.unapproved_unattended", "the checks in .syncgit.toml haven't been approved yet; run syncgit in the repository to review them"),
+    ("tasks.summary", "✅ Checks done: {passed} passed, {skipped} not applicable ({time})"),
+    ("tasks.summary_overridden", "⚠️  Checks done: {passed} passed, {overridden} skipped by you, {skipped} not applicable ({time})"),
+
+    // Release
+    ("release.dirty", "Commit or stash your changes before releasing"),
+    ("release.nothing_new", "No commits since {tag}, nothing to release"),
+    ("release.no_commits", "This repository has no commits to release"),
+    ("release.since_tag.one", "📦 {count} commit since {tag}"),
+    ("release.since_tag.other", "📦 {count} commits since {tag}"),
+    ("release.first.one", "📦 First release: {count} commit"),
+    ("release.first.other", "📦 First release: {count} commits"),
+    ("release.breakdown", "💥 {breaking} breaking · ✨ {features} features · 🐛 {fixes} fixes"),
+    ("release.suggested", "Suggested version: {version} (current: {current})"),
+    ("release.version_prompt", "Version to release (Enter for {version}, or major/minor/patch/X.Y.Z)"),
+    ("release.invalid_version", "'{version}' is not a version: use major, minor, patch or X.Y.Z"),
+    ("release.not_newer", "{version} is not newer than the current version {current}"),
+    ("release.tag_exists", "Tag {tag} already exists"),
+    ("release.plan_title", "🏷️  Releasing {tag}"),
+    ("release.plan_file", "  ✏️  {file}: {from} → {to}"),
+    ("release.plan_tag", "  🏷️  Annotated tag {tag} with release notes"),
+    ("release.plan_tag_signed", "  🔏 Signed tag {tag} with release notes"),
+    ("release.plan_push", "  🚀 Push the branch and the tag"),
+    ("release.confirm", "Go ahead?"),
+    ("release.version_not_found", "Could not find version {version} in {file}"),
+    ("release.extra_without_version", "[release] lists {file}, but no Cargo.toml, package.json or pyproject.toml states the current version"),
+    ("release.tagged", "✅ Tagged {tag}"),
+    ("release.offline", "📴 Offline: run 'git push --follow-tags' to publish {tag} later"),
+    ("release.pushed", "🚀 Pushed {tag} to {remote}"),
+    ("release.github_prompt", "Create a GitHub release with these notes?"),
+    ("release.github_no_token", "No GitHub token found; create the release for {tag} on the website"),
+    ("release.github_created", "🎉 GitHub release created: {url}"),
+    ("release.github_failed", "⚠️  Could not create the GitHub release: {error}"),
+    ("release.notes.breaking", "Breaking changes"),
+    ("release.notes.features", "Features"),
+    ("release.notes.fixes", "Bug fixes"),
+    ("release.notes.misc", "Other changes"),
+
+    // Changelog
+    ("changelog.created.one", "📝 {file} created with {count} release"),
+    ("changelog.created.other", "📝 {file} created with {count} releases"),
+    ("changelog.updated.one", "📝 {file} updated: {count} new release, Unreleased refreshed"),
+    ("changelog.updated.other", "📝 {file} updated: {count} new releases, Unreleased refreshed"),
+    ("changelog.up_to_date", "✅ {file} is up to date"),
+    ("changelog.no_forge", "ℹ️  The push remote isn't on GitHub or GitLab, so issues and versions aren't linked"),
+    ("changelog.review_hint", "Review it with 'syncgit diff' and commit it with your next sync"),
+
+    // Identity
+    ("identity.usage", "Usage: syncgit identity [status | use <profile> | add <profile>]"),
+    ("identity.title", "👤 Commit identity"),
+    ("identity.current", "  Committing as {ident} (from {scope} config)"),
+    ("identity.current_missing", "  ⚠️  No user.name / user.email set: commits would fail"),
+    ("identity.no_profiles", "No identity profiles yet"),
+    ("identity.add_hint", "Create one with 'syncgit identity add <profile>'"),
+    ("identity.profile_entry", "{marker} {profile}  {ident} · {rules}"),
+    ("identity.team_title.one", "🤝 {count} teammate in {file}"),
+    ("identity.team_title.other", "🤝 {count} teammates in {file}"),
+    ("identity.team_entry", "  {handle}  {ident}"),
+    ("identity.mismatch", "⚠️  This repository matches profile '{profile}' ({expected}), but commits would be made as {current}"),
+    ("identity.use_profile", "Use profile '{profile}' for this repository?"),
+    ("identity.missing", "⚠️  No commit identity (user.name / user.email) is set"),
+    ("identity.profile_hint", "Profiles set it automatically: see 'syncgit identity add'"),
+    ("identity.name_prompt", "Your name for commits"),
+    ("identity.email_prompt", "Your email for commits"),
+    ("identity.invalid_email", "'{email}' is not an email address"),
+    ("identity.applied", "👤 This repository now commits as {ident} (profile {profile})"),
+    ("identity.profile_incomplete", "Profile '{profile}' needs both a name and an email"),
+    ("identity.committing_as", "👤 Committing as {ident}"),
+    ("identity.committing_as_profile", "👤 Committing as {ident} (profile {profile})"),
+    ("identity.unknown_profile", "No identity profile named '{profile}'"),
+    ("identity.profile_exists", "Profile '{profile}' already exists; edit it with 'git config --global --edit'"),
+    ("identity.hosts_prompt", "Remote hosts for this profile, e.g. {example} (space separated, Enter for none)"),
+    ("identity.dirs_prompt", "Directories for this profile, e.g. ~/work (comma separated, Enter for none)"),
+    ("identity.profile_saved", "✅ Profile '{profile}' saved in your global git config"),
+    ("identity.use_hint", "Repositories matching it pick it up at the next commit, or run 'syncgit identity use {profile}'"),
+    ("identity.team_not_table", "[team] must be a table of handle = \"Name <email>\""),
+    ("identity.team_invalid", "team member '{handle}' must be \"Name <email>\""),
+    ("identity.team", "🤝 Team: {handles}"),
+    ("identity.co_authors_prompt", "Co-authors (handles separated by spaces, Enter for none)"),
+    ("identity.unknown_handles", "Not in the team roster: {handles}"),
+    ("identity.co_authors_added.one", "🤝 {count} co-author credited"),
+    ("identity.co_authors_added.other", "🤝 {count} co-authors credited"),
+];
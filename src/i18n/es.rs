@@ -0,0 +1,754 @@
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Prompts and terminal I/O
+    ("prompt.yes_no_suffix", "(s/n)"),
+    ("prompt.yes_answers", "s,si,sí,y,yes"),
+    ("prompt.continue_anyway", "¿Quieres continuar de todos modos?"),
+    ("io.flush_failed", "Error al vaciar stdout: {error}"),
+    ("io.read_failed", "Error al leer la entrada: {error}"),
+
+    // Repository helpers
+    ("repo.branch_error", "Error al obtener la rama: {error}"),
+    ("repo.status_check_failed", "Error al verificar cambios: {error}"),
+    ("repo.status_run_failed", "Error al ejecutar git status: {error}"),
+    ("repo.git_stderr", "stderr de git: {stderr}"),
+
+    // Errors
+    ("error.no_changes", "No hay cambios para confirmar"),
+    ("error.no_commit_message", "No se proporcionó un mensaje de commit"),
+    ("error.no_token", "No se encontró un token de GitHub"),
+    ("error.no_internet", "Sin conexión a internet"),
+    ("error.auth", "Falló la autenticación: {details}"),
+    ("error.network", "Error de red: {details}"),
+    ("error.conflict", "Conflicto: {details}"),
+    ("error.push_rejected", "Push rechazado ({reason}): {details}"),
+    ("error.missing_upstream", "No hay rama upstream configurada: {details}"),
+    ("error.command_failed", "Falló el comando: {details}"),
+    ("error.exit_status", "código de salida {code}"),
+    ("error.exec_git", "No se pudo ejecutar el comando git"),
+    ("error.parse_output", "No se pudo interpretar la salida del comando: {error}"),
+    ("error.root_missing", "El directorio raíz del repositorio no existe: {path}"),
+    ("error.root_not_dir", "La raíz del repositorio no es un directorio: {path}"),
+    ("error.root_access", "No se puede acceder al directorio del repositorio {path}"),
+    ("error.root_readonly", "Permisos insuficientes para leer el repositorio: {path}"),
+    ("error.spawn_git", "No se pudo iniciar el comando git"),
+    ("error.wait_git", "No se pudo esperar al comando git"),
+    ("error.no_remote_url", "No se pudo obtener la URL del remoto"),
+    ("error.credential_spawn", "No se pudo iniciar git credential"),
+    ("error.credential_write", "No se pudo escribir en la entrada de git credential"),
+    ("error.credential_wait", "No se pudo esperar a git credential"),
+    ("error.credential_store", "No se pudieron guardar las credenciales"),
+    ("error.check_conflicts", "No se pudo comprobar si hay conflictos de merge"),
+    ("error.git_init", "No se pudo ejecutar git init"),
+    ("error.rename_branch", "No se pudo renombrar la rama a main: {error}"),
+    ("error.create_gitignore", "No se pudo crear .gitignore"),
+    ("error.current_dir", "No se pudo obtener el directorio actual"),
+    ("error.detached_head", "No se puede hacer push desde un HEAD desacoplado"),
+    ("error.git_push", "No se pudo ejecutar git push"),
+
+    // Cancellations
+    ("cancel.operation", "El usuario canceló la operación"),
+    ("cancel.commit", "El usuario canceló el commit"),
+    ("cancel.by_user", "Operación cancelada por el usuario"),
+    ("cancel.repo_creation", "Creación del repositorio cancelada por el usuario"),
+
+    // Authentication
+    ("auth.token_found", "🔑 Token de GitHub encontrado"),
+    ("auth.no_token", "ℹ️  No se encontró un token de GitHub"),
+    ("auth.helper_configured", "✅ Gestor de credenciales seguro configurado"),
+    ("auth.existing_login", "🔑 Usando tu sesión existente ({source})"),
+    ("auth.gh_setup_git_hint", "💡 Si git pide una contraseña, ejecuta 'gh auth setup-git' una vez."),
+    ("auth.using_ssh", "ℹ️  Usando autenticación SSH (no hace falta token)"),
+    ("auth.setup.title", "❌ No se encontró un token de GitHub en el entorno"),
+    ("auth.setup.intro", "   Configúralo antes de continuar:"),
+    ("auth.setup.step1", "   1. Crea un Personal Access Token en GitHub con el permiso 'repo'"),
+    ("auth.setup.step2", "   2. Añádelo a la configuración de tu shell (p. ej., ~/.zshrc):"),
+    ("auth.setup.step3", "   3. Recarga tu shell: source ~/.zshrc"),
+    ("auth.setup.ssh", "   Como alternativa, puedes autenticarte con SSH."),
+
+    // Status display
+    ("status.no_changes_subpath", "🟢 No hay cambios en la subruta actual"),
+    ("status.root_group", "(raíz)"),
+
+    // Staging and committing
+    ("stage.header", "📄 Cambios que se van a preparar:"),
+    ("stage.no_changes", "🟢 No hay cambios para añadir en la carpeta actual"),
+    ("stage.confirm", "Pulsa Enter para preparar estos cambios, d + Enter para revisar el diff, o Ctrl+C para cancelar..."),
+    ("stage.cancelled", "❌ Operación cancelada"),
+    ("stage.staging", "⏳ Preparando cambios..."),
+    ("stage.added", "✅ Cambios añadidos"),
+    ("stage.nothing_to_commit", "ℹ️  No hay nada que confirmar"),
+    ("stage.all_committed", "   Todos los cambios ya están confirmados"),
+    ("commit.staged_header", "📝 Cambios preparados que se van a confirmar:"),
+    ("commit.confirm", "Pulsa Enter para confirmar estos cambios, d + Enter para revisar el diff, o cualquier otra cosa para cancelar"),
+    ("commit.cancelled", "❌ Commit cancelado"),
+    ("commit.message_prompt", "Escribe el mensaje del commit (o déjalo vacío para cancelar)"),
+    ("commit.no_message", "❌ Commit cancelado: no se proporcionó mensaje"),
+
+    // Conflict checks
+    ("conflicts.unresolved", "Tienes conflictos sin resolver. Resuélvelos antes de continuar."),
+    ("conflicts.merge_in_progress", "Hay un merge en progreso. Por favor, completa o aborta el merge antes de continuar."),
+
+    // Pending pushes
+    ("pending.verification_error", "⚠️  Error de verificación:"),
+    ("pending.none", "✅ No hay commits pendientes de push"),
+    ("pending.warning", "⚠️  AVISO: tienes commits pendientes de push"),
+    ("pending.ahead.one", "   {count} commit por delante del repositorio remoto"),
+    ("pending.ahead.other", "   {count} commits por delante del repositorio remoto"),
+    ("pending.risk", "   Esto podría causar conflictos o commits duplicados."),
+    ("pending.push_first", "¿Quieres hacer push de los commits existentes primero?"),
+    ("pending.continue_without", "⚠️  Continuando con el nuevo commit sin hacer push..."),
+    ("pending.pushing", "⬆️  Haciendo push de los commits existentes..."),
+    ("pending.no_token", "❌ No se puede hacer push: no se encontró el token de GitHub"),
+    ("pending.configure_token", "   Configura tu token de GitHub"),
+    ("pending.no_internet", "⚠️  Sin conexión a internet. No se pueden subir los commits existentes."),
+    ("pending.pushed", "✅ ¡Commits existentes subidos correctamente!"),
+
+    // Pushing
+    ("push.success", "✅ ¡Cambios subidos correctamente!"),
+    ("push.no_internet", "⚠️  Sin conexión a internet. Los cambios se guardaron localmente pero no se subieron."),
+    ("push.run_manually", "    Ejecuta 'git push' manualmente cuando tengas conexión."),
+    ("push.rejected", "❌ Push rechazado: {reason}"),
+    ("push.reason.non_fast_forward", "la rama ha divergido (non-fast-forward)"),
+    ("push.reason.protected", "rama protegida"),
+    ("push.reason.hook", "rechazado por un hook del servidor"),
+    ("push.reason.auth", "falló la autenticación"),
+    ("push.reason.too_large", "push demasiado grande"),
+    ("push.reason.unknown", "motivo desconocido"),
+    ("push.explain.non_fast_forward", "La rama remota tiene commits que tu rama local no tiene.\nHacer push ahora los descartaría, así que git lo rechazó."),
+    ("push.explain.protected", "La rama remota está protegida y no acepta push directos.\nHaz push a otra rama y abre un pull request."),
+    ("push.explain.hook", "Un hook del servidor rechazó el push.\nRevisa las líneas 'remote:' de arriba para ver el motivo."),
+    ("push.explain.auth", "El remoto rechazó tus credenciales.\nRevisa tu token o clave SSH y que tengas permiso de escritura en el repositorio."),
+    ("push.explain.too_large", "El push contiene archivos o un pack más grandes de lo que acepta el servidor.\nElimina los archivos grandes de los commits sin subir o gestiónalos con Git LFS."),
+    ("push.explain.unknown", "git no pudo subir tus cambios; revisa la salida de error de arriba."),
+    ("push.fetching_compare", "🔄 Descargando {remote}/{branch} para comparar historiales..."),
+    ("push.fetch_failed", "⚠️  No se pudo descargar la rama remota: {error}"),
+    ("push.local_only.one", "⬆️  {count} commit local que no está en {remote}/{branch}:"),
+    ("push.local_only.other", "⬆️  {count} commits locales que no están en {remote}/{branch}:"),
+    ("push.remote_only.one", "⬇️  {count} commit remoto que no está en tu rama:"),
+    ("push.remote_only.other", "⬇️  {count} commits remotos que no están en tu rama:"),
+    ("push.recommend_pull", "Recomendado: trae los commits remotos y vuelve a ejecutar syncgit."),
+    ("push.overwrite_warning", "Sobrescribir eliminará definitivamente los commits remotos listados arriba."),
+    ("push.overwrite_prompt", "¿Quieres sobrescribir la rama remota con tu historial local?"),
+    ("push.cancelled_local_kept", "ℹ️  Push cancelado. Tus commits locales no se han tocado."),
+    ("push.type_to_confirm", "Escribe '{branch}' para confirmar la sobrescritura"),
+    ("push.confirm_mismatch", "❌ La confirmación no coincide. Push cancelado."),
+    ("push.force_pushing", "⬆️  Forzando el push con lease..."),
+    ("push.overwritten", "✅ Rama remota sobrescrita con tu historial local"),
+    ("push.stale_lease", "❌ La rama remota cambió de nuevo desde que se descargó."),
+    ("push.stale_lease_hint", "   No se sobrescribió nada. Vuelve a ejecutar syncgit para revisar los nuevos commits."),
+
+    // Repository initialization
+    ("init.no_repo_found", "No se encontró un repositorio Git en el directorio actual ni en sus padres."),
+    ("init.ask_init", "¿Quieres inicializar un nuevo repositorio Git aquí?"),
+    ("init.created_commit", "✅ Commit inicial creado"),
+    ("init.nothing_to_commit", "ℹ️  No hay archivos que confirmar en el repositorio inicial"),
+    ("init.exiting", "Saliendo..."),
+
+    // GitHub repository creation
+    ("github.create_prompt", "¿Quieres crear un repositorio en GitHub y hacer push?"),
+    ("github.create_later", "Puedes crear el repositorio manualmente más tarde."),
+    ("github.created", "✅ ¡Repositorio creado y subido a GitHub!"),
+    ("github.name_prompt", "Nombre del repositorio en GitHub [{default}]"),
+    ("github.name_empty", "❌ El nombre del repositorio no puede estar vacío. Inténtalo de nuevo."),
+    ("github.name_invalid", "❌ El nombre del repositorio solo puede contener caracteres alfanuméricos, guiones, guiones bajos y puntos. Inténtalo de nuevo."),
+    ("github.description_prompt", "Descripción del repositorio (opcional)"),
+    ("github.private_prompt", "¿El repositorio debe ser privado?"),
+    ("github.creating", "🔄 Creando el repositorio en GitHub..."),
+    ("github.request_failed", "No se pudo enviar la petición a la API de GitHub: {error}"),
+    ("github.unknown_error", "Error desconocido"),
+    ("github.already_exists", "⚠️  El repositorio '{name}' ya existe en GitHub"),
+    ("github.use_existing", "¿Quieres usar el repositorio existente y hacer push?"),
+    ("github.pushing_existing", "🚀 Haciendo push al repositorio existente en GitHub..."),
+    ("github.pushed_to", "✅ Push realizado correctamente al repositorio de GitHub: {url}"),
+    ("github.error_401", "Revisa tu token de GitHub. Error: {error}"),
+    ("github.error_403", "Permiso denegado. Puede que tu token no tenga el permiso 'repo'. Error: {error}"),
+    ("github.error_422", "Nombre de repositorio no válido o el repositorio ya existe. Error: {error}"),
+    ("github.error_status", "Error de la API de GitHub (estado {status}): {error}"),
+    ("github.parse_failed", "No se pudo interpretar la respuesta de GitHub: {error}"),
+    ("github.no_html_url", "La respuesta de GitHub no incluye la URL del repositorio"),
+    ("github.no_clone_url", "La respuesta de GitHub no incluye la URL de clonado"),
+    ("github.origin_exists", "ℹ️  El remoto 'origin' ya existe: {url}"),
+    ("github.update_remote", "¿Quieres actualizar la URL del remoto existente?"),
+    ("github.using_existing_remote", "⚠️  Usando el remoto existente. Puede que tengas que configurar el seguimiento manualmente."),
+    ("github.initial_commit_prompt", "Mensaje del commit inicial (o pulsa Enter para 'Initial commit')"),
+    ("github.initial_commit_created", "✅ Commit inicial creado con el mensaje: {message}"),
+    ("github.pushing", "🚀 Haciendo push al repositorio de GitHub..."),
+    ("github.push_failed", "⚠️  No se pudo hacer push al repositorio remoto: {error}"),
+    ("github.fetching", "🔄 Descargando del remoto..."),
+    ("github.fetch_failed", "⚠️  No se pudo descargar del remoto: {error}"),
+    ("github.setting_tracking", "🔗 Configurando el seguimiento..."),
+    ("github.command_failed", "⚠️  Falló el comando: git {command}"),
+    ("github.error_detail", "   Error: {error}"),
+    ("github.retry_push", "¿Quieres intentar el push de nuevo?"),
+    ("github.final_push_failed", "❌ Falló el último intento de push: {error}"),
+    ("github.manual_tracking", "Puede que tengas que configurar el seguimiento manualmente con estos comandos:"),
+    ("github.push_incomplete_failed", "No se pudo hacer push al repositorio remoto"),
+    ("github.pushed", "✅ ¡Push realizado correctamente al repositorio de GitHub!"),
+    ("github.push_not_completed", "El push al repositorio remoto no se completó"),
+
+    // Sync status
+    ("sync.unpushed", "⚠️  Tienes cambios sin subir:"),
+    ("sync.commits_ahead.one", "{count} commit por delante del remoto"),
+    ("sync.commits_ahead.other", "{count} commits por delante del remoto"),
+    ("sync.push_confirm", "Pulsa Enter para subir los cambios, o Ctrl+C para cancelar"),
+    ("sync.offline_local", "ℹ️  Sin conexión a internet. Los cambios quedarán en local por ahora."),
+    ("sync.behind.one", "⚠️  Tu rama local va por detrás: {count} commit por detrás del remoto"),
+    ("sync.behind.other", "⚠️  Tu rama local va por detrás: {count} commits por detrás del remoto"),
+    ("sync.to_sync.one", "Tienes {count} commit para sincronizar desde el remoto"),
+    ("sync.to_sync.other", "Tienes {count} commits para sincronizar desde el remoto"),
+    ("sync.view_confirm", "Pulsa Enter para ver y sincronizar estos cambios, o Ctrl+C para cancelar"),
+    ("sync.cancelled", "❌ Sincronización cancelada"),
+    ("sync.confirm", "Pulsa Enter para confirmar la sincronización, o Ctrl+C para cancelar"),
+    ("sync.syncing", "🔄 Sincronizando cambios..."),
+    ("sync.done", "✅ ¡Sincronizado correctamente con el remoto!"),
+    ("sync.offline_working", "ℹ️  Sin conexión a internet. Trabajando con la versión local por ahora."),
+    ("sync.in_sync", "✅ Tu repositorio está sincronizado con el remoto"),
+
+    // Main workflow
+    ("main.warning", "⚠️  Aviso"),
+    ("main.repo_root", "📁 Raíz del repositorio: {name}"),
+    ("main.subpath_root", ". (raíz del repositorio)"),
+    ("main.subpath", "🧭 Subruta: {path}"),
+    ("main.status_header", "🔍 Estado del repositorio:"),
+    ("main.checking_pending", "🔍 Comprobando push pendientes..."),
+    ("main.pulling", "⬇️  Descargando cambios..."),
+    ("main.no_remote_skip_pull", "ℹ️  No hay remoto configurado. Se omite el pull."),
+    ("main.checking_changes", "📦 Comprobando cambios locales..."),
+    ("main.no_changes_here", "ℹ️  No se detectaron cambios en la carpeta actual"),
+    ("main.changes_elsewhere", "   Sin embargo, hay cambios pendientes en otras partes del repositorio."),
+    ("main.tip_root", "   Consejo: ejecuta esta herramienta desde la raíz del repositorio o ve a la carpeta con cambios."),
+    ("main.push_about", "⚠️  Estás a punto de subir tus cambios al repositorio remoto."),
+    ("main.push_confirm", "   Pulsa Enter para confirmar el push, o Ctrl+C para cancelar"),
+    ("main.push_cancelled", "❌ Push cancelado"),
+    ("main.pushing", "⬆️  Subiendo cambios..."),
+    ("main.committed_locally", "ℹ️  No hay remoto configurado. Cambios confirmados localmente."),
+
+    // Command line and .gitignore generation
+    ("error.read_gitignore", "No se pudo leer .gitignore"),
+    ("gitignore.detected", "🔎 Plantillas de .gitignore detectadas: {templates}"),
+    ("gitignore.available", "   Disponibles: {templates}"),
+    ("gitignore.choose_prompt", "Plantillas a usar, separadas por comas ('-' para ninguna) [{default}]"),
+    ("gitignore.unknown_template", "Plantilla de .gitignore desconocida '{name}'. Disponibles: {available}"),
+    ("gitignore.created", "✅ .gitignore creado a partir de las plantillas: {templates}"),
+    ("gitignore.merged", "✅ Reglas nuevas añadidas al .gitignore existente desde: {templates}"),
+    ("gitignore.up_to_date", "ℹ️  .gitignore ya contiene todas las reglas de las plantillas seleccionadas"),
+    ("cli.missing_value", "Falta el valor de {flag}"),
+    ("cli.unknown_argument", "Argumento desconocido '{arg}'. Ejecuta 'syncgit help' para ver el uso."),
+    ("cli.unknown_command", "Comando desconocido '{command}'. Ejecuta 'syncgit help' para ver el uso."),
+    ("cli.help", "Uso: syncgit [comando]\n\nComandos:\n  (ninguno)              Revisar, confirmar y sincronizar el repositorio actual\n  init [opciones]        Inicializar un repositorio aquí\n  auth status            Comprobar el token de GitHub: propietario, permisos y caducidad\n  auth ssh [remoto]      Diagnosticar claves, agente y configuración SSH de un remoto\n  bundle export [archivo] Escribir los commits sin sincronizar en un bundle (--all ramas, --full historial)\n  bundle import <archivo> Verificar un bundle e integrarlo como un pull\n  flush                  Reintentar los push guardados sin conexión\n  queue                  Listar los push que esperan conexión, en todos los repositorios\n  diff [--staged]        Revisar los cambios sin preparar (o preparados) en el visor de diffs\n  stash [acción] [n]     Listar, ver, aplicar, recuperar o descartar stashes (list, show, apply, pop, drop)\n  worktree [acción]      Lista worktrees, o add <rama> [ruta] / remove <rama|ruta>\n  release [versión]      Sube la versión, crea la etiqueta, la publica y crea la release (major, minor, patch o X.Y.Z; --sign)\n  changelog [--stdout]   Crea o actualiza CHANGELOG.md a partir de los commits entre etiquetas de versión\n  identity [acción]      Muestra con qué identidad se confirma, o use <perfil> / add <perfil> perfiles de identidad\n  tui                    Panel a pantalla completa: preparar, confirmar, pull, push y stash\n  help                   Mostrar esta ayuda\n\nOpciones de init:\n  --gitignore <lista>    Plantillas de .gitignore separadas por comas\n  --no-gitignore         No crear ni modificar .gitignore\n  --scaffold             Generar README, LICENSE y configuración del editor sin preguntar\n  --no-scaffold          No ofrecer la generación de README/LICENSE\n  --license <id>         Licencia para el archivo LICENSE generado (o 'none')\n\nPlantillas: {templates}\nLicencias: {licenses}"),
+
+    // Project scaffolding
+    ("error.read_file", "No se pudo leer {path}"),
+    ("error.write_file", "No se pudo escribir {path}"),
+    ("scaffold.ask", "¿Generar README, LICENSE, .editorconfig y .gitattributes?"),
+    ("scaffold.licenses_available", "📜 Licencias disponibles: {licenses}"),
+    ("scaffold.license_prompt", "Licencia ('none' para no crear LICENSE) [{default}]"),
+    ("scaffold.unknown_license", "Licencia desconocida '{license}'. Disponibles: {available}"),
+    ("scaffold.author_prompt", "Titular del copyright para LICENSE"),
+    ("scaffold.readme_getting_started", "## Primeros pasos\n\nDescribe aquí cómo instalar, compilar y usar el proyecto."),
+    ("scaffold.readme_license", "## Licencia\n\nDistribuido bajo la licencia {license}. Consulta [LICENSE](LICENSE) para más detalles."),
+    ("scaffold.preview_title", "👀 Archivos que se crearán:"),
+    ("scaffold.preview_lines.one", "{count} línea"),
+    ("scaffold.preview_lines.other", "{count} líneas"),
+    ("scaffold.confirm", "¿Escribir estos archivos?"),
+    ("scaffold.skipped", "ℹ️  Plantillas omitidas, no se escribió nada"),
+    ("scaffold.nothing_to_do", "ℹ️  README, LICENSE, .editorconfig y .gitattributes ya existen"),
+    ("scaffold.created", "✅ Creados y confirmados: {files}"),
+
+    // GitHub repository options
+    ("github.no_login", "No se pudo obtener tu usuario de GitHub desde la API"),
+    ("github.owner_title", "👥 ¿Dónde se debe crear el repositorio?"),
+    ("github.owner_personal", "{login} (cuenta personal)"),
+    ("github.owner_prompt", "Número del propietario [1]"),
+    ("github.owner_invalid", "❌ Introduce uno de los números de la lista."),
+    ("github.visibility_prompt", "Visibilidad (public, private, internal) [public]"),
+    ("github.visibility_invalid", "❌ La visibilidad debe ser public, private o internal."),
+    ("github.advanced_prompt", "¿Configurar plantilla, temas, página web, rama por defecto y funciones?"),
+    ("github.template_prompt", "Repositorio plantilla como propietario/nombre (vacío para ninguno)"),
+    ("github.template_invalid", "❌ Usa el formato propietario/nombre, p. ej. octo-org/rust-template."),
+    ("github.topics_prompt", "Temas separados por comas (vacío para ninguno)"),
+    ("github.topic_invalid", "❌ Tema no válido '{topic}': usa minúsculas, dígitos y guiones (máx. 50)."),
+    ("github.homepage_prompt", "URL de la página web (vacío para ninguna)"),
+    ("github.default_branch_prompt", "Rama por defecto [{default}]"),
+    ("github.enable_issues", "¿Activar issues?"),
+    ("github.enable_wiki", "¿Activar la wiki?"),
+    ("github.enable_projects", "¿Activar proyectos?"),
+    ("github.settings_failed", "⚠️  Repositorio creado, pero algunos ajustes no se pudieron aplicar: {error}"),
+    ("github.default_branch_failed", "⚠️  No se pudo establecer la rama por defecto '{branch}': {error}"),
+    ("github.waiting_template", "⏳ Esperando a que GitHub copie el contenido de la plantilla..."),
+    ("github.template_merged", "✅ Contenido de la plantilla fusionado con tu historial local"),
+    ("github.template_not_ready", "El repositorio se creó desde la plantilla, pero su contenido aún no está disponible. Ejecuta 'git pull --allow-unrelated-histories origin' más tarde y vuelve a hacer push."),
+
+    // Existing repository fallback
+    ("github.no_push_permission", "Tu cuenta no puede hacer push a {repo}. Pide acceso de escritura a un propietario o elige otro nombre."),
+    ("github.comparing_history", "🔍 Comparando tu historial con {repo}..."),
+    ("github.ssh_fallback", "⚠️  Falló la autenticación SSH; se usará HTTPS con tu token"),
+    ("github.history.empty", "   El repositorio remoto está vacío; tu rama se subirá tal cual."),
+    ("github.history.up_to_date", "   La rama remota '{branch}' ya coincide con tu historial local."),
+    ("github.history.fast_forward", "   La rama remota '{branch}' está por detrás de la tuya; el push será un avance rápido."),
+    ("github.history.behind", "   La rama remota '{branch}' tiene commits más recientes; primero se incorporarán a tu rama con avance rápido."),
+    ("github.history.diverged", "   Tu historial y '{branch}' han divergido; hay que fusionar los commits remotos antes del push."),
+    ("github.history.unrelated", "   '{branch}' tiene un historial sin relación con el tuyo; el push necesitaría un commit de fusión que combine ambos."),
+    ("github.confirm_existing", "¿Continuar y apuntar 'origin' a {url}?"),
+    ("github.confirm_merge.one", "¿Fusionar ahora {count} commit remoto de '{branch}' en tu rama '{local}'?"),
+    ("github.confirm_merge.other", "¿Fusionar ahora {count} commits remotos de '{branch}' en tu rama '{local}'?"),
+    ("github.confirm_unrelated_merge.one", "'{branch}' no comparte historial con '{local}'. ¿Fusionar igualmente su {count} commit en tu rama, combinando dos proyectos sin relación?"),
+    ("github.confirm_unrelated_merge.other", "'{branch}' no comparte historial con '{local}'. ¿Fusionar igualmente sus {count} commits en tu rama, combinando dos proyectos sin relación?"),
+    ("github.merge_declined", "No se fusionó nada y 'origin' no cambió. Para combinar los historiales tú mismo, ejecuta: {command}"),
+
+    // GitHub API client
+    ("github.error_404", "No encontrado en GitHub (o tu token no tiene acceso): {error}"),
+    ("github.rate_limited", "Se superó el límite de peticiones de la API de GitHub; se restablece en unos {minutes} min"),
+    ("github.retrying", "⏳ GitHub respondió {status}; reintentando en {seconds}s..."),
+
+    // Auth status
+    ("auth.source.env", "variable de entorno {var}"),
+    ("auth.kind.fine_grained", "token de acceso personal de grano fino"),
+    ("auth.kind.classic", "token de acceso personal clásico"),
+    ("auth.kind.oauth", "token OAuth"),
+    ("auth.kind.app", "token de GitHub App"),
+    ("auth.kind.unknown", "formato de token no reconocido"),
+    ("auth.yes", "sí"),
+    ("auth.no", "no"),
+    ("auth.status.title", "🔐 Autenticación de GitHub"),
+    ("auth.status.source", "   Token de:     {source}"),
+    ("auth.status.kind", "   Tipo:         {kind}"),
+    ("auth.status.api", "   API:          {url}"),
+    ("auth.status.login", "   Usuario:      {login}"),
+    ("auth.status.login_named", "   Usuario:      {login} ({name})"),
+    ("auth.status.scopes", "   Permisos:     {scopes}"),
+    ("auth.status.none", "(ninguno)"),
+    ("auth.status.fine_grained", "   Permisos:     no informados; los tokens de grano fino necesitan Contents (lectura/escritura) para push y Administration (lectura/escritura) para crear repositorios"),
+    ("auth.status.expires", "   Caduca:       {expiry}"),
+    ("auth.status.never", "nunca"),
+    ("auth.status.push_access", "   Acceso de push a {repo}: {answer}"),
+    ("auth.status.repo_permissions", "   Permisos en {repo}: {permissions}"),
+    ("auth.status.repo_unavailable", "   No se pudo comprobar el acceso a {repo}: {error}"),
+    ("auth.status.ok", "✅ El token es válido y puede crear repositorios y hacer push"),
+    ("auth.warn.public_only", "El token solo tiene 'public_repo': no puede crear repositorios privados ni hacer push a ellos. Añade el permiso 'repo'."),
+    ("auth.warn.no_repo_scope", "Al token le falta el permiso 'repo': crear repositorios y hacer push por HTTPS fallará."),
+    ("auth.warn.no_org_scope", "'{org}' es propietaria de este repositorio, pero sin 'read:org' el token no ve tus organizaciones: no se ofrecerán como propietarias de nuevos repositorios."),
+    ("auth.warn.no_push", "No tienes acceso de push a {repo}."),
+
+    // Token discovery
+    ("auth.source.credential_helper", "asistente de credenciales de git"),
+    ("auth.source.gh", "GitHub CLI ({origin})"),
+    ("auth.source.netrc", "archivo netrc {path}"),
+    ("auth.searched", "   Se probó: {vars}, asistentes de credenciales de git, GitHub CLI (gh) y ~/.netrc"),
+
+    // Per-remote authentication
+    ("auth.other_host", "ℹ️  Remoto en {host}: se usarán tus credenciales de git (no hace falta token de GitHub)"),
+    ("auth.local_remote", "ℹ️  Remoto local: no hace falta autenticación"),
+
+    // SSH diagnostics
+    ("error.ssh_run", "Error al ejecutar ssh"),
+    ("ssh.no_remote", "No hay ningún remoto que comprobar: ejecútalo dentro de un repositorio con remoto, o indica un nombre de remoto o una URL"),
+    ("ssh.not_ssh", "{url} no es un remoto SSH"),
+    ("ssh.report.title", "🔑 Diagnóstico SSH"),
+    ("ssh.report.remote", "   Remoto:       {destination}"),
+    ("ssh.report.connects", "   Conecta a:    {user}@{host}, puerto {port}"),
+    ("ssh.report.config", "   Bloques de ~/.ssh/config que se aplican:"),
+    ("ssh.report.config_none", "   ~/.ssh/config: ningún bloque Host coincide"),
+    ("ssh.report.agent_off", "   Agente:       no está en ejecución (SSH_AUTH_SOCK no está definido)"),
+    ("ssh.report.agent_unreachable", "   Agente:       no responde en {socket}"),
+    ("ssh.report.agent_unknown", "   Agente:       desconocido (no se pudo ejecutar ssh-add)"),
+    ("ssh.report.agent_keys.one", "   Agente:       {count} clave cargada"),
+    ("ssh.report.agent_keys.other", "   Agente:       {count} claves cargadas"),
+    ("ssh.report.keys", "   Claves que ofrecerá ssh:"),
+    ("ssh.report.keys_none", "   Claves que ofrecerá ssh: ninguna aparte de las del agente"),
+    ("ssh.report.unused", "   Otras claves en ~/.ssh que no se ofrecen a este host: {keys}"),
+    ("ssh.key.missing", "no existe"),
+    ("ssh.key.too_open", "ignorada, permisos demasiado abiertos"),
+    ("ssh.key.loaded", "cargada en el agente"),
+    ("ssh.key.locked", "protegida con frase de contraseña, no está en el agente"),
+    ("ssh.key.ready", "lista, sin frase de contraseña"),
+    ("ssh.report.testing", "⏳ Probando la conexión con {host}..."),
+    ("ssh.report.connected", "✅ Autenticado: {greeting}"),
+    ("ssh.report.connected_plain", "✅ Autenticado"),
+    ("ssh.report.ok", "✅ No se encontraron problemas de SSH"),
+    ("ssh.report.hint", "   Ejecuta 'syncgit auth ssh' para ver un informe SSH completo"),
+    ("ssh.diagnosis", "🔎 Por qué falló la conexión SSH:"),
+    ("ssh.problem.agent_unreachable", "SSH_AUTH_SOCK apunta a {socket}, pero ningún agente responde ahí. Inicia uno con 'eval \"$(ssh-agent -s)\"' y carga tu clave con 'ssh-add'."),
+    ("ssh.problem.missing_identity", "~/.ssh/config define IdentityFile {path} para {host}, pero ese archivo no existe."),
+    ("ssh.problem.permissions", "ssh ignora {path} porque otros usuarios pueden leerla. Corrígelo con 'chmod 600 {path}'."),
+    ("ssh.problem.locked", "{path} está protegida con frase de contraseña y no está cargada en el agente, así que los push sin terminal no pueden usarla. Cárgala una vez con 'ssh-add {path}'."),
+    ("ssh.problem.locked_no_agent", "{path} está protegida con frase de contraseña y no hay ningún agente SSH en ejecución. Inicia uno con 'eval \"$(ssh-agent -s)\"' y ejecuta 'ssh-add {path}'."),
+    ("ssh.problem.identities_only", "IdentitiesOnly está activado para {host}, así que las claves del agente no se ofrecen salvo que también figuren como IdentityFile."),
+    ("ssh.problem.unused_key", "{path} existe pero ssh no la ofrece a {host}. Añade 'IdentityFile {path}' a un bloque 'Host {host}' en ~/.ssh/config, o cárgala con 'ssh-add {path}'."),
+    ("ssh.problem.no_keys", "No hay ninguna clave SSH disponible para {host}. Crea una con 'ssh-keygen -t ed25519' y añade el archivo .pub a tu cuenta."),
+    ("ssh.fail.host_key_changed", "La clave de host de {host} cambió desde tu última conexión. Puede que el servidor se haya reinstalado o que alguien esté interceptando la conexión. Comprueba la nueva huella con el proveedor antes de borrar la antigua con 'ssh-keygen -R {host}'."),
+    ("ssh.fail.host_key_unknown", "{host} no está en ~/.ssh/known_hosts y ssh no pudo preguntarte si confiar en él. Conéctate una vez con 'ssh -T {destination}' y confirma su huella."),
+    ("ssh.fail.resolve", "No se pudo resolver {host}. Revisa el host de la URL del remoto, o el HostName de su bloque en ~/.ssh/config."),
+    ("ssh.fail.connect", "No se pudo conectar con {host} en el puerto {port}. Puede que un cortafuegos o proxy esté bloqueando SSH."),
+    ("ssh.fail.github_443", "GitHub también acepta SSH por el puerto 443: añade un bloque 'Host github.com' con 'Hostname ssh.github.com' y 'Port 443' a ~/.ssh/config."),
+    ("ssh.fail.passphrase", "La frase de contraseña de la clave era incorrecta o no se pudo pedir."),
+    ("ssh.fail.agent_refused", "El agente SSH se negó a firmar con la clave. Puede estar bloqueado, o una llave física puede estar esperando que la toques."),
+    ("ssh.fail.wrong_account", "La clave inició sesión como {account}, que no puede hacer push a este repositorio. Si tienes varias cuentas, elige la clave correcta con un alias Host en ~/.ssh/config."),
+    ("ssh.fail.publickey", "{host} rechazó todas las claves que ofreció ssh."),
+    ("ssh.fail.register_key", "Estas claves parecen correctas en local; asegúrate de que una de ellas esté añadida a tu cuenta en {host}:"),
+
+    // Offline push queue
+    ("error.create_dir", "Error al crear {path}"),
+    ("queue.no_state_dir", "No se encontró un directorio para la cola de push (define HOME o XDG_STATE_HOME)"),
+    ("queue.queued", "📥 En cola: syncgit hará push cuando vuelvas a tener conexión (o ejecuta 'syncgit flush')"),
+    ("queue.queue_failed", "⚠️  No se pudo poner el push en cola: {error}"),
+    ("queue.retrying.one", "📤 Reintentando {count} push en cola"),
+    ("queue.retrying.other", "📤 Reintentando {count} push en cola"),
+    ("queue.entry", "   {repo}: {branch} → {remote}/{remote_branch}"),
+    ("queue.pushed", "      ✅ Enviado"),
+    ("queue.gone", "      🗑️  Eliminado: el repositorio, la rama o el remoto ya no existe"),
+    ("queue.offline", "      ⏸️  Sigue sin conexión, se mantiene en la cola"),
+    ("queue.failed", "      ❌ {error}"),
+    ("queue.failed_hint", "      Se mantiene en la cola; ejecuta syncgit en {path} para resolverlo"),
+    ("queue.flushed", "✅ Se enviaron todos los push en cola"),
+    ("queue.still_waiting.one", "⏳ {count} push sigue esperando"),
+    ("queue.still_waiting.other", "⏳ {count} push siguen esperando"),
+    ("queue.empty", "✅ No hay nada pendiente de push"),
+    ("queue.title.one", "📥 {count} push esperando conexión"),
+    ("queue.title.other", "📥 {count} push esperando conexión"),
+    ("queue.details", "      {path} · {commits} · en cola {age}"),
+    ("queue.commits.one", "{count} commit"),
+    ("queue.commits.other", "{count} commits"),
+    ("queue.new_branch", "rama nueva"),
+    ("queue.missing_repo", "repositorio no encontrado"),
+    ("queue.age.now", "ahora mismo"),
+    ("queue.age.minutes.one", "hace {count} minuto"),
+    ("queue.age.minutes.other", "hace {count} minutos"),
+    ("queue.age.hours.one", "hace {count} hora"),
+    ("queue.age.hours.other", "hace {count} horas"),
+    ("queue.age.days.one", "hace {count} día"),
+    ("queue.age.days.other", "hace {count} días"),
+    ("queue.flush_hint", "Ejecuta 'syncgit flush' para reintentarlos ahora"),
+    ("queue.startup_confirm", "¿Enviarlos ahora? Cada envío ejecuta las comprobaciones previas al push de ese repositorio y configura sus credenciales"),
+
+    // Bundle sync
+    ("bundle.usage", "Uso: syncgit bundle export [archivo] [--all] [--full] | syncgit bundle import <archivo>"),
+    ("bundle.nothing_to_export", "✅ Nada que exportar: la otra máquina ya tiene todos los commits"),
+    ("bundle.full_hint", "Usa --full para exportar todo el historial de todos modos, p. ej. si se perdió un bundle"),
+    ("bundle.overwrite", "{path} ya existe. ¿Sobrescribirlo?"),
+    ("bundle.export_title", "📦 Escribiendo {path}"),
+    ("bundle.export_branch.one", "   {branch}: {count} commit (todo el historial)"),
+    ("bundle.export_branch.other", "   {branch}: {count} commits (todo el historial)"),
+    ("bundle.export_branch_since.one", "   {branch}: {count} commit nuevo desde {known}"),
+    ("bundle.export_branch_since.other", "   {branch}: {count} commits nuevos desde {known}"),
+    ("bundle.exported", "✅ Exportado a {path} ({size})"),
+    ("bundle.import_hint", "En la otra máquina, ejecuta: syncgit bundle import {file}"),
+    ("bundle.not_found", "No se encontró el archivo bundle: {path}"),
+    ("bundle.missing_prerequisites.one", "❌ El bundle se basa en {count} commit que este repositorio no tiene:"),
+    ("bundle.missing_prerequisites.other", "❌ El bundle se basa en {count} commits que este repositorio no tiene:"),
+    ("bundle.export_full_hint", "Un bundle anterior nunca llegó aquí. Vuelve a exportar con 'syncgit bundle export --full' en la otra máquina."),
+    ("bundle.no_branches", "El bundle no contiene ninguna rama"),
+    ("bundle.import_title", "📦 Importando {path}"),
+    ("bundle.branch_up_to_date", "   {branch}: ya está al día"),
+    ("bundle.branch_incoming.one", "   {branch}: {count} commit entrante"),
+    ("bundle.branch_incoming.other", "   {branch}: {count} commits entrantes"),
+    ("bundle.nothing_new", "✅ No hay nada nuevo en el bundle"),
+    ("bundle.import_confirm", "¿Integrar estos commits en tus ramas?"),
+    ("bundle.merging", "⬇️  Haciendo pull de {branch} desde el bundle..."),
+    ("bundle.created_branch", "✅ Rama {branch} creada"),
+    ("bundle.fast_forwarded", "✅ {branch} avanzada (fast-forward)"),
+    ("bundle.diverged", "⚠️  {branch} ha divergido; la versión importada se guarda como {imported}"),
+    ("bundle.imported", "✅ Bundle importado"),
+    ("bundle.diverged_hint", "Cambia a cada rama divergente y vuelve a ejecutar 'syncgit bundle import' para integrarla"),
+
+    // Incoming changes
+    ("incoming.commits_title.one", "⬇️  {count} commit entrante"),
+    ("incoming.commits_title.other", "⬇️  {count} commits entrantes"),
+    ("incoming.commit", "   {hash} {date} {author}: {subject}"),
+    ("incoming.files_title.one", "📄 {count} archivo modificado"),
+    ("incoming.files_title.other", "📄 {count} archivos modificados"),
+    ("incoming.binary", "binario"),
+    ("incoming.totals", "   Total: +{added} -{removed}"),
+    ("incoming.conflict_title.one", "⚠️  {count} archivo también está modificado en local y puede dar conflicto:"),
+    ("incoming.conflict_title.other", "⚠️  {count} archivos también están modificados en local y pueden dar conflicto:"),
+    ("incoming.conflict_file", "   ⚠️  {path}"),
+    ("incoming.conflict_hint", "Haz commit o una copia de tus cambios en estos archivos antes de sincronizar"),
+
+    // TUI dashboard
+    ("tui.terminal_error", "No se pudo preparar el terminal"),
+    ("tui.too_small", "El terminal es demasiado pequeño para el panel: necesita al menos {width}x{height}. Agrándalo o pulsa q para salir."),
+    ("tui.pane.changes.one", "Cambios ({count} archivo)"),
+    ("tui.pane.changes.other", "Cambios ({count} archivos)"),
+    ("tui.pane.staged_diff", "Diff preparado"),
+    ("tui.pane.log", "Commits recientes"),
+    ("tui.pane.remote", "Remoto"),
+    ("tui.detached", "HEAD separado"),
+    ("tui.in_sync", "sincronizado"),
+    ("tui.no_upstream", "sin upstream"),
+    ("tui.stashes.one", "{count} stash"),
+    ("tui.stashes.other", "{count} stashes"),
+    ("tui.nothing_staged", "Nada preparado: selecciona un archivo y pulsa espacio, o pulsa a para prepararlo todo"),
+    ("tui.no_commits", "Todavía no hay commits"),
+    ("tui.keys", "↑↓ seleccionar · espacio preparar/quitar · a preparar todo · c commit · d diff · p pull · P push · s stash · S recuperar stash · f fetch · RePág/AvPág desplazar diff · q salir"),
+    ("tui.commit_prompt", "Mensaje del commit (Enter para confirmar, Esc para cancelar):"),
+    ("tui.committed", "✅ Commit creado"),
+    ("tui.nothing_to_stash", "No hay cambios que guardar en el stash"),
+    ("tui.stashed", "Cambios guardados en el stash: pulsa S para recuperarlos"),
+    ("tui.no_stash", "No hay ningún stash que recuperar"),
+    ("tui.stash_popped", "Stash recuperado"),
+    ("tui.fetched", "Cambios remotos descargados"),
+    ("tui.press_enter", "Pulsa Enter para volver al panel"),
+    ("tui.no_upstream_to_pull", "Esta rama no tiene upstream del que hacer pull"),
+    ("tui.pull_confirm", "¿Hacer pull de estos cambios?"),
+    ("tui.remote.detached", "No estás en ninguna rama"),
+    ("tui.remote.none", "No hay remoto configurado: los commits se quedan en local"),
+    ("tui.remote.name", "{remote}: {url}"),
+    ("tui.remote.kind_github", "GitHub por HTTPS ({host}), push con el token"),
+    ("tui.remote.kind_ssh", "SSH ({host}), push con tus claves"),
+    ("tui.remote.kind_other", "HTTPS ({host}), push con los credential helpers de git"),
+    ("tui.remote.kind_local", "Ruta local, no necesita red"),
+    ("tui.remote.tracking", "Sigue a {upstream}: {ahead} por subir, {behind} por traer"),
+    ("tui.remote.no_upstream", "'{branch}' aún no tiene upstream: P hace push y lo configura"),
+    ("tui.remote.fetched", "Última descarga {age}"),
+    ("tui.remote.never_fetched", "Nunca descargado: pulsa f para hacer fetch"),
+    ("tui.remote.queued", "⏳ Push en cola {age}, esperando conexión"),
+
+    // Diff viewer
+    ("diff.unstaged", "Cambios sin preparar"),
+    ("diff.staged", "Cambios preparados"),
+    ("diff.no_unstaged", "No hay cambios sin preparar. Pulsa Tab para ver los preparados."),
+    ("diff.no_staged", "No hay cambios preparados. Pulsa Tab para ver los que están sin preparar."),
+    ("diff.file_position", "archivo {index}/{total}"),
+    ("diff.unified", "unificado"),
+    ("diff.side_by_side", "lado a lado"),
+    ("diff.new_file", "archivo nuevo"),
+    ("diff.deleted_file", "eliminado"),
+    ("diff.renamed", "renombrado desde {from}"),
+    ("diff.mode_changed", "modo {mode}"),
+    ("diff.binary", "binario"),
+    ("diff.keys", "↑↓ desplazar · RePág/AvPág página · n/N archivo siguiente/anterior · v lado a lado/unificado · Tab preparados/sin preparar · q volver"),
+
+    // Stash manager
+    ("stash.auto_message", "sincronización de {branch} con su upstream"),
+    ("stash.auto_kept", "⚠️  Tus cambios locales no se aplicaron limpiamente sobre la rama sincronizada. Se conservan en el stash como auto-stash de syncgit."),
+    ("stash.manage_hint", "Ejecuta 'syncgit stash' para revisarlos, restaurarlos o descartarlos"),
+    ("stash.leftover_warning.one", "⚠️  Aviso: una sincronización de syncgit interrumpida dejó 1 stash"),
+    ("stash.leftover_warning.other", "⚠️  Aviso: sincronizaciones de syncgit interrumpidas dejaron {count} stashes"),
+    ("stash.existing_note.one", "ℹ️  Tienes 1 stash ('syncgit stash' para revisarlo)"),
+    ("stash.existing_note.other", "ℹ️  Tienes {count} stashes ('syncgit stash' para revisarlos)"),
+    ("stash.empty", "✅ No hay stashes"),
+    ("stash.title.one", "📦 1 stash"),
+    ("stash.title.other", "📦 {count} stashes"),
+    ("stash.entry", "  {stash}  en {branch} · {age} · {files}"),
+    ("stash.files.one", "1 archivo (+{added} -{removed})"),
+    ("stash.files.other", "{count} archivos (+{added} -{removed})"),
+    ("stash.entry_message", "      {message}"),
+    ("stash.entry_auto", "      🤖 Dejado por syncgit: {message}"),
+    ("stash.restore_hint", "🤖 marca cambios que syncgit guardó durante una sincronización que no terminó. Restáuralos con 'syncgit stash pop {index}'"),
+    ("stash.not_found", "No existe el stash '{stash}'. Ejecuta 'syncgit stash list' para verlos."),
+    ("stash.choose", "Número de stash a gestionar (Enter para salir)"),
+    ("stash.action_prompt", "{stash}: [v]er, [a]plicar, [s]acar (aplicar y borrar), [d]escartar, o Enter para volver"),
+    ("stash.answers.show", "v"),
+    ("stash.answers.apply", "a"),
+    ("stash.answers.pop", "s"),
+    ("stash.answers.drop", "d"),
+    ("stash.applied", "✅ {stash} aplicado; sigue en la lista"),
+    ("stash.popped", "✅ {stash} aplicado y eliminado"),
+    ("stash.drop_confirm", "¿Descartar {stash} definitivamente?"),
+    ("stash.dropped", "🗑️  {stash} descartado"),
+    ("stash.usage", "Uso: syncgit stash [list | show [n] | apply [n] | pop [n] | drop [n]]"),
+    ("diff.stash", "Stash {stash}"),
+    ("diff.no_stash_changes", "Este stash no tiene cambios"),
+
+    // Submodules
+    ("submodule.using_superproject", "📂 Esta carpeta es un submódulo; sincronizando desde su superproyecto '{name}'"),
+    ("submodule.title.one", "🧩 1 submódulo:"),
+    ("submodule.title.other", "🧩 {count} submódulos:"),
+    ("submodule.entry", "  {path}: {state}"),
+    ("submodule.state.uninitialized", "sin inicializar"),
+    ("submodule.state.conflict", "conflicto de fusión"),
+    ("submodule.state.ahead", "commits nuevos"),
+    ("submodule.state.behind", "por detrás del commit registrado"),
+    ("submodule.state.diverged", "divergente del commit registrado"),
+    ("submodule.state.modified", "contenido modificado"),
+    ("submodule.state.unpushed.one", "1 commit sin subir"),
+    ("submodule.state.unpushed.other", "{count} commits sin subir"),
+    ("submodule.state.detached", "HEAD separado"),
+    ("submodule.state.clean", "al día"),
+    ("submodule.update_hint", "💡 Ejecuta 'git submodule update --init --recursive' para obtener los commits registrados"),
+    ("submodule.has_changes", "🧩 El submódulo '{path}' tiene cambios sin confirmar"),
+    ("submodule.detached_commit", "⚠️  Está en un HEAD separado; cambia a una rama en él para confirmar desde syncgit"),
+    ("submodule.commit_first", "¿Confirmar primero los cambios en '{path}'?"),
+    ("submodule.needs_push.one", "🧩 El submódulo '{path}' tiene 1 commit al que apunta el superproyecto y que no se ha subido"),
+    ("submodule.needs_push.other", "🧩 El submódulo '{path}' tiene {count} commits sin subir, incluido al que apunta el superproyecto"),
+    ("submodule.commit_on_no_branch", "⚠️  '{path}' apunta a {commit}, que no está en ninguna rama del submódulo; crea una rama para él allí y súbela"),
+    ("submodule.push_first", "¿Subir '{path}' antes que el superproyecto?"),
+    ("submodule.push_commit_first", "¿Subir {commit} de '{path}' a {remote}/{branch} antes que el superproyecto?"),
+    ("submodule.pushed", "✅ Submódulo '{path}' subido"),
+    ("submodule.push_failed", "❌ No se pudo subir el submódulo '{path}': {error}"),
+    ("submodule.dangling_warning", "⚠️  Estos punteros de submódulo apuntan a commits que no están en ningún remoto. Otros no podrán obtenerlos:"),
+    ("submodule.dangling_entry", "  {path} → {commit}"),
+    ("submodule.push_anyway", "¿Subir el superproyecto de todos modos?"),
+    ("submodule.unpushed_queued", "Commits de submódulos que aún no están en un remoto: {paths}; súbelos primero"),
+
+    // Worktrees
+    ("worktree.broken_link", "{path}/.git apunta a un directorio git que ya no existe. Si el repositorio principal se movió, ejecuta 'git worktree repair' desde él."),
+    ("worktree.linked_note", "🌳 Worktree vinculado de {main}"),
+    ("worktree.title.one", "🌳 1 worktree"),
+    ("worktree.title.other", "🌳 {count} worktrees"),
+    ("worktree.entry", "{marker} {branch}  {path} · {state}"),
+    ("worktree.detached", "(separado en {commit})"),
+    ("worktree.state.main", "principal"),
+    ("worktree.state.bare", "bare"),
+    ("worktree.state.missing", "directorio inexistente"),
+    ("worktree.state.clean", "limpio"),
+    ("worktree.state.changed.one", "1 archivo modificado"),
+    ("worktree.state.changed.other", "{count} archivos modificados"),
+    ("worktree.state.ahead.one", "1 commit por subir"),
+    ("worktree.state.ahead.other", "{count} commits por subir"),
+    ("worktree.state.behind.one", "1 commit por bajar"),
+    ("worktree.state.behind.other", "{count} commits por bajar"),
+    ("worktree.state.locked", "🔒 bloqueado"),
+    ("worktree.state.locked_reason", "🔒 bloqueado: {reason}"),
+    ("worktree.prune_hint", "💡 Ejecuta 'git worktree prune' para olvidar los worktrees cuyo directorio ya no existe"),
+    ("worktree.add_hint", "Añade uno con 'syncgit worktree add <rama> [ruta]'"),
+    ("worktree.usage", "Uso: syncgit worktree [list | add <rama> [ruta] | remove <rama|ruta>]"),
+    ("worktree.invalid_branch", "'{branch}' no es un nombre de rama válido"),
+    ("worktree.already_checked_out", "La rama '{branch}' ya está en uso en {path}"),
+    ("worktree.path_exists", "{path} ya existe. Indica otra ruta: syncgit worktree add <rama> <ruta>"),
+    ("worktree.tracking", "🔗 Siguiendo {upstream}"),
+    ("worktree.create_branch", "La rama '{branch}' no existe. ¿Crearla a partir de {from}?"),
+    ("worktree.created", "✅ '{branch}' está disponible en {path}"),
+    ("worktree.cd_hint", "cd {path} para trabajar en ella"),
+    ("worktree.not_found", "No hay ningún worktree para '{target}'. Ejecuta 'syncgit worktree' para verlos."),
+    ("worktree.cannot_remove_main", "El worktree principal contiene el propio repositorio y no se puede eliminar"),
+    ("worktree.cannot_remove_current", "Estás dentro de ese worktree. Ejecuta el comando desde otro."),
+    ("worktree.remove_dirty.one", "⚠️  Ese worktree tiene 1 cambio sin confirmar"),
+    ("worktree.remove_dirty.other", "⚠️  Ese worktree tiene {count} cambios sin confirmar"),
+    ("worktree.remove_force", "¿Eliminarlo de todos modos y perderlos?"),
+    ("worktree.removed", "🗑️  Worktree en {path} eliminado"),
+    ("worktree.branch_kept", "La rama '{branch}' se conserva; bórrala con 'git branch -d {branch}'"),
+
+    // Git LFS
+    ("lfs.tracking", "📦 Git LFS almacena: {patterns}"),
+    ("lfs.not_installed", "⚠️  Este repositorio usa Git LFS pero 'git lfs' no está instalado. Los archivos grandes se confirmarían tal cual y verás archivos puntero al hacer checkout."),
+    ("lfs.install_hint", "💡 Instálalo desde https://git-lfs.com y ejecuta 'git lfs install'"),
+    ("lfs.hooks_missing", "⚠️  Git LFS no está configurado en este clon: sin sus filtros y su hook pre-push, los archivos LFS no se convertirán ni se subirán"),
+    ("lfs.install_prompt", "¿Ejecutar 'git lfs install --local' ahora?"),
+    ("lfs.installed", "✅ Git LFS está configurado en este repositorio"),
+    ("lfs.install_failed", "❌ 'git lfs install' falló: {error}"),
+    ("lfs.large_files.one", "⚠️  1 archivo supera {size} y no se almacena con Git LFS:"),
+    ("lfs.large_files.other", "⚠️  {count} archivos superan {size} y no se almacenan con Git LFS:"),
+    ("lfs.large_entry", "  {path} ({size})"),
+    ("lfs.more.one", "  …y 1 más"),
+    ("lfs.more.other", "  …y {count} más"),
+    ("lfs.commit_anyway", "¿Confirmarlos sin LFS de todos modos? Una vez subidos quedarán en el historial para siempre."),
+    ("lfs.track_prompt.one", "¿Almacenar {pattern} con Git LFS (1 archivo)?"),
+    ("lfs.track_prompt.other", "¿Almacenar {pattern} con Git LFS ({count} archivos)?"),
+    ("lfs.tracked", "✅ {pattern} se almacena ahora con Git LFS (.gitattributes preparado)"),
+    ("lfs.pending_upload.one", "📦 Se subirá 1 objeto de Git LFS:"),
+    ("lfs.pending_upload.other", "📦 Se subirán {count} objetos de Git LFS:"),
+    ("lfs.pending_entry", "  {path}"),
+    ("lfs.uploading", "⬆️  No hay hook pre-push de Git LFS; subiendo primero los objetos..."),
+
+    // Project tasks
+    ("config.read_failed", "No se pudo leer {file}"),
+    ("config.invalid", "{file} no es válido: {error}"),
+    ("tasks.expected_array", "'task' debe ser una lista de tablas [[task]]"),
+    ("tasks.expected_table", "la tarea n.º {index} debe ser una tabla"),
+    ("tasks.missing_run", "la tarea n.º {index} no tiene comando 'run'"),
+    ("tasks.expected_strings", "'{key}' de la tarea '{task}' debe ser un texto o una lista de textos"),
+    ("tasks.unknown_hook", "la tarea '{task}' tiene un valor 'when' desconocido '{hook}' (usa pre-commit o pre-push)"),
+    ("tasks.header.one", "🧪 Ejecutando 1 comprobación {hook} de .syncgit.toml"),
+    ("tasks.header.other", "🧪 Ejecutando {count} comprobaciones {hook} de .syncgit.toml"),
+    ("tasks.not_applicable", "⏭️  {name}: ningún cambio coincide"),
+    ("tasks.running", "▶️  {name}: {command}"),
+    ("tasks.passed", "✅ {name} correcto ({time})"),
+    ("tasks.failed", "❌ {name} falló con código de salida {code} ({time})"),
+    ("tasks.spawn_failed", "❌ {name} no pudo iniciarse: {error}"),
+    ("tasks.choice", "[r]eintentar tras corregir, [s]altar esta comprobación, o Enter para abortar"),
+    ("tasks.choice_fix", "[c]orregir con '{fix}', [r]eintentar tras corregir, [s]altar esta comprobación, o Enter para abortar"),
+    ("tasks.answers.fix", "c"),
+    ("tasks.answers.retry", "r"),
+    ("tasks.answers.skip", "s"),
+    ("tasks.fix_name", "corregir {name}"),
+    ("tasks.overridden", "⚠️  Saltando {name} a petición tuya"),
+    ("tasks.aborted", "Abortado tras fallar {name}"),
+    ("tasks.failed_unattended", "{name} falló; el push en cola espera hasta que pase"),
+    ("tasks.approval_header", "🔐 .syncgit.toml quiere ejecutar estos comandos en tu equipo:"),
+    ("tasks.approval_command", "   {name}: {command}"),
+    ("tasks.approval_confirm", "¿Permitirlos en este repositorio? Se volverá a preguntar si cambian"),
+    ("tasks.not_approved", "⏭️  Comprobaciones del proyecto omitidas; se volverán a ofrecer la próxima vez"),
+    ("tasks.unapproved_unattended", "las comprobaciones de .syncgit.toml aún no se han aprobado; ejecuta syncgit en el repositorio para revisarlas"),
+    ("tasks.summary", "✅ Comprobaciones terminadas: {passed} correctas, {skipped} no aplicables ({time})"),
+    ("tasks.summary_overridden", "⚠️  Comprobaciones terminadas: {passed} correctas, {overridden} saltadas por ti, {skipped} no aplicables ({time})"),
+
+    // Release
+    ("release.dirty", "Confirma o guarda en el stash tus cambios antes de publicar una versión"),
+    ("release.nothing_new", "No hay commits desde {tag}, no hay nada que publicar"),
+    ("release.no_commits", "Este repositorio no tiene commits que publicar"),
+    ("release.since_tag.one", "📦 {count} commit desde {tag}"),
+    ("release.since_tag.other", "📦 {count} commits desde {tag}"),
+    ("release.first.one", "📦 Primera versión: {count} commit"),
+    ("release.first.other", "📦 Primera versión: {count} commits"),
+    ("release.breakdown", "💥 {breaking} incompatibles · ✨ {features} funcionalidades · 🐛 {fixes} correcciones"),
+    ("release.suggested", "Versión sugerida: {version} (actual: {current})"),
+    ("release.version_prompt", "Versión a publicar (Enter para {version}, o major/minor/patch/X.Y.Z)"),
+    ("release.invalid_version", "'{version}' no es una versión: usa major, minor, patch o X.Y.Z"),
+    ("release.not_newer", "{version} no es posterior a la versión actual {current}"),
+    ("release.tag_exists", "La etiqueta {tag} ya existe"),
+    ("release.plan_title", "🏷️  Publicando {tag}"),
+    ("release.plan_file", "  ✏️  {file}: {from} → {to}"),
+    ("release.plan_tag", "  🏷️  Etiqueta anotada {tag} con las notas de la versión"),
+    ("release.plan_tag_signed", "  🔏 Etiqueta firmada {tag} con las notas de la versión"),
+    ("release.plan_push", "  🚀 Subir la rama y la etiqueta"),
+    ("release.confirm", "¿Continuar?"),
+    ("release.version_not_found", "No se encontró la versión {version} en {file}"),
+    ("release.extra_without_version", "[release] incluye {file}, pero ningún Cargo.toml, package.json o pyproject.toml indica la versión actual"),
+    ("release.tagged", "✅ Etiqueta {tag} creada"),
+    ("release.offline", "📴 Sin conexión: ejecuta 'git push --follow-tags' para publicar {tag} más tarde"),
+    ("release.pushed", "🚀 {tag} subida a {remote}"),
+    ("release.github_prompt", "¿Crear una release en GitHub con estas notas?"),
+    ("release.github_no_token", "No se encontró token de GitHub; crea la release de {tag} desde la web"),
+    ("release.github_created", "🎉 Release de GitHub creada: {url}"),
+    ("release.github_failed", "⚠️  No se pudo crear la release de GitHub: {error}"),
+    ("release.notes.breaking", "Cambios incompatibles"),
+    ("release.notes.features", "Funcionalidades"),
+    ("release.notes.fixes", "Correcciones"),
+    ("release.notes.misc", "Otros cambios"),
+
+    // Changelog
+    ("changelog.created.one", "📝 {file} creado con {count} versión"),
+    ("changelog.created.other", "📝 {file} creado con {count} versiones"),
+    ("changelog.updated.one", "📝 {file} actualizado: {count} versión nueva, Unreleased regenerado"),
+    ("changelog.updated.other", "📝 {file} actualizado: {count} versiones nuevas, Unreleased regenerado"),
+    ("changelog.up_to_date", "✅ {file} está al día"),
+    ("changelog.no_forge", "ℹ️  El remoto de push no está en GitHub ni en GitLab, así que no se enlazan issues ni versiones"),
+    ("changelog.review_hint", "Revísalo con 'syncgit diff' y confírmalo en tu próxima sincronización"),
+
+    // Identity
+    ("identity.usage", "Uso: syncgit identity [status | use <perfil> | add <perfil>]"),
+    ("identity.title", "👤 Identidad de los commits"),
+    ("identity.current", "  Confirmando como {ident} (de la configuración {scope})"),
+    ("identity.current_missing", "  ⚠️  No hay user.name / user.email: los commits fallarían"),
+    ("identity.no_profiles", "Aún no hay perfiles de identidad"),
+    ("identity.add_hint", "Crea uno con 'syncgit identity add <perfil>'"),
+    ("identity.profile_entry", "{marker} {profile}  {ident} · {rules}"),
+    ("identity.team_title.one", "🤝 {count} compañero en {file}"),
+    ("identity.team_title.other", "🤝 {count} compañeros en {file}"),
+    ("identity.team_entry", "  {handle}  {ident}"),
+    ("identity.mismatch", "⚠️  Este repositorio corresponde al perfil '{profile}' ({expected}), pero los commits se harían como {current}"),
+    ("identity.use_profile", "¿Usar el perfil '{profile}' en este repositorio?"),
+    ("identity.missing", "⚠️  No hay identidad para los commits (user.name / user.email)"),
+    ("identity.profile_hint", "Los perfiles la configuran automáticamente: consulta 'syncgit identity add'"),
+    ("identity.name_prompt", "Tu nombre para los commits"),
+    ("identity.email_prompt", "Tu email para los commits"),
+    ("identity.invalid_email", "'{email}' no es una dirección de email"),
+    ("identity.applied", "👤 Este repositorio ahora confirma como {ident} (perfil {profile})"),
+    ("identity.profile_incomplete", "El perfil '{profile}' necesita nombre y email"),
+    ("identity.committing_as", "👤 Confirmando como {ident}"),
+    ("identity.committing_as_profile", "👤 Confirmando como {ident} (perfil {profile})"),
+    ("identity.unknown_profile", "No existe el perfil de identidad '{profile}'"),
+    ("identity.profile_exists", "El perfil '{profile}' ya existe; edítalo con 'git config --global --edit'"),
+    ("identity.hosts_prompt", "Hosts remotos de este perfil, p. ej. {example} (separados por espacios, Enter para ninguno)"),
+    ("identity.dirs_prompt", "Directorios de este perfil, p. ej. ~/work (separados por comas, Enter para ninguno)"),
+    ("identity.profile_saved", "✅ Perfil '{profile}' guardado en tu configuración global de git"),
+    ("identity.use_hint", "Los repositorios que coincidan lo aplicarán en el próximo commit, o ejecuta 'syncgit identity use {profile}'"),
+    ("identity.team_not_table", "[team] debe ser una tabla de alias = \"Nombre <email>\""),
+    ("identity.team_invalid", "el miembro del equipo '{handle}' debe ser \"Nombre <email>\""),
+    ("identity.team", "🤝 Equipo: {handles}"),
+    ("identity.co_authors_prompt", "Coautores (alias separados por espacios, Enter para ninguno)"),
+    ("identity.unknown_handles", "No están en el equipo: {handles}"),
+    ("identity.co_authors_added.one", "🤝 {count} coautor acreditado"),
+    ("identity.co_authors_added.other", "🤝 {count} coautores acreditados"),
+];
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::env;
+use std::process::Command;
+use std::sync::OnceLock;
+
+mod en;
+mod es;
+
+// ============================================================================
+// MESSAGE CATALOG
+// ============================================================================
+
+// Every user-facing string lives in the bundles under this module, keyed by
+// a dotted identifier. Templates use `{name}` placeholders; plural messages
+// have `.one` and `.other` variants and always receive `{count}`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    Es,
+}
+
+impl Lang {
+    fn from_locale(value: &str) -> Option<Self> {
+        let value = value.trim().to_lowercase();
+        if value.is_empty() || value == "c" || value == "posix" {
+            None
+        } else if value.starts_with("es") {
+            Some(Lang::Es)
+        } else {
+            Some(Lang::En)
+        }
+    }
+
+    fn bundle(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Lang::En => en::MESSAGES,
+            Lang::Es => es::MESSAGES,
+        }
+    }
+
+    fn plural_category(&self, count: u64) -> &'static str {
+        // English and Spanish share the same one/other rule
+        if count == 1 { "one" } else { "other" }
+    }
+}
+
+/// Picks the language from `SYNCGIT_LANG`, then the `syncgit.language` git
+/// config, then the usual POSIX locale variables.
+fn detect_lang() -> Lang {
+    if let Some(lang) = env::var("SYNCGIT_LANG").ok().and_then(|v| Lang::from_locale(&v)) {
+        return lang;
+    }
+
+    let configured = Command::new("git")
+        .args(["config", "--get", "syncgit.language"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok());
+    if let Some(lang) = configured.and_then(|v| Lang::from_locale(&v)) {
+        return lang;
+    }
+
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find_map(|v| Lang::from_locale(&v))
+        .unwrap_or(Lang::En)
+}
+
+pub fn lang() -> Lang {
+    static LANG: OnceLock<Lang> = OnceLock::new();
+    *LANG.get_or_init(detect_lang)
+}
+
+fn catalog(lang: Lang) -> &'static HashMap<&'static str, &'static str> {
+    static EN: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    static ES: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    let cell = match lang {
+        Lang::En => &EN,
+        Lang::Es => &ES,
+    };
+    cell.get_or_init(|| lang.bundle().iter().copied().collect())
+}
+
+fn find(key: &str) -> Option<&'static str> {
+    catalog(lang()).get(key)
+        .or_else(|| catalog(Lang::En).get(key))
+        .copied()
+}
+
+/// Looks up a message, falling back to English and then to the key itself.
+pub fn lookup(key: &'static str) -> &'static str {
+    find(key).unwrap_or(key)
+}
+
+pub fn lookup_plural(key: &'static str, count: u64) -> &'static str {
+    find(&format!("{}.{}", key, lang().plural_category(count))).unwrap_or(key)
+}
+
+pub fn format(template: &str, args: &[(&str, String)]) -> String {
+    let mut out = template.to_string();
+    for (name, value) in args {
+        out = out.replace(&format!("{{{}}}", name), value);
+    }
+    out
+}
+
+/// Translates a message key, filling `{name}` placeholders.
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::lookup($key).to_string()
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::format($crate::i18n::lookup($key), &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+
+/// Translates a plural message key for `count`, which is also available to
+/// the template as `{count}`.
+macro_rules! tn {
+    ($key:expr, $count:expr $(, $name:ident = $value:expr)* $(,)?) => {{
+        let count = $count as u64;
+        $crate::i18n::format(
+            $crate::i18n::lookup_plural($key, count),
+            &[("count", count.to_string()) $(, (stringify!($name), $value.to_string()))*],
+        )
+    }};
+}
+
+pub(crate) use {t, tn};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use std::fs;
+    use std::path::Path;
+
+    fn keys(bundle: &[(&str, &str)]) -> BTreeSet<String> {
+        bundle.iter().map(|(k, _)| k.to_string()).collect()
+    }
+
+    fn placeholders(template: &str) -> BTreeSet<String> {
+        template.split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name.to_string()))
+            .filter(|name| !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+            .collect()
+    }
+
+    #[test]
+    fn every_key_exists_in_every_bundle() {
+        let all = [Lang::En, Lang::Es];
+        for a in all {
+            for b in all {
+                let missing: Vec<_> = keys(a.bundle()).difference(&keys(b.bundle())).cloned().collect();
+                assert!(missing.is_empty(), "keys in {:?} missing from {:?}: {:?}", a, b, missing);
+            }
+        }
+    }
+
+    #[test]
+    fn bundles_have_no_duplicate_keys() {
+        for lang in [Lang::En, Lang::Es] {
+            assert_eq!(keys(lang.bundle()).len(), lang.bundle().len(), "duplicate key in {:?}", lang);
+        }
+    }
+
+    #[test]
+    fn translations_keep_placeholders() {
+        let es = catalog(Lang::Es);
+        for (key, english) in Lang::En.bundle() {
+            assert_eq!(placeholders(english), placeholders(es[key]), "placeholder mismatch for {}", key);
+        }
+    }
+
+    #[test]
+    fn every_key_used_in_source_is_defined() {
+        let defined = keys(Lang::En.bundle());
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut pending = vec![src];
+        while let Some(dir) = pending.pop() {
+            for entry in fs::read_dir(&dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    pending.push(path);
+                    continue;
+                }
+                if path.extension().and_then(|e| e.to_str()) != Some("rs") {
+                    continue;
+                }
+                let source = fs::read_to_string(&path).unwrap();
+                for (macro_name, plural) in [("t!(\"", false), ("tn!(\"", true)] {
+                    for (idx, _) in source.match_indices(macro_name) {
+                        // Skip other macros ending in `t!`, such as `format!`
+                        let preceding = source[..idx].chars().next_back();
+                        if preceding.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                            continue;
+                        }
+                        let key = source[idx + macro_name.len()..].split('"').next().unwrap();
+                        if plural {
+                            for form in ["one", "other"] {
+                                let full = format!("{}.{}", key, form);
+                                assert!(defined.contains(&full), "{} uses undefined key {}", path.display(), full);
+                            }
+                        } else {
+                            assert!(defined.contains(key), "{} uses undefined key {}", path.display(), key);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn plural_forms_follow_count() {
+        let one = format(catalog(Lang::En)["sync.commits_ahead.one"], &[("count", "1".into())]);
+        let many = format(catalog(Lang::En)["sync.commits_ahead.other"], &[("count", "3".into())]);
+        assert_eq!(one, "1 commit ahead of remote");
+        assert_eq!(many, "3 commits ahead of remote");
+    }
+}
@@ -0,0 +1,390 @@
+use std::path::{Path, PathBuf};
+
+use crate::auth::{self, home_dir};
+use crate::config;
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::push;
+use crate::{GitRepo, UI};
+
+// ============================================================================
+// IDENTITY PROFILES
+// ============================================================================
+
+// Profiles live in the global git config, one subsection each:
+//
+//     [syncgit "profile.work"]
+//         name = Ada Lovelace
+//         email = ada@acme.example
+//         signingKey = ~/.ssh/id_work.pub
+//         host = github.com/acme
+//         dir = ~/work
+//
+// `host` matches the push remote by host, or host and leading path; `dir`
+// matches repositories below a directory. Both may be repeated, and the
+// profile with the longest matching rule applies.
+
+const PREFIX: &str = "syncgit.profile.";
+
+pub struct Profile {
+    pub name: String,
+    pub user_name: Option<String>,
+    pub email: Option<String>,
+    pub signing_key: Option<String>,
+    pub hosts: Vec<String>,
+    pub dirs: Vec<String>,
+}
+
+impl Profile {
+    fn new(name: &str) -> Self {
+        Profile { name: name.to_string(), user_name: None, email: None, signing_key: None, hosts: Vec::new(), dirs: Vec::new() }
+    }
+
+    fn ident(&self) -> String {
+        format_ident(self.user_name.as_deref().unwrap_or("?"), self.email.as_deref().unwrap_or("?"))
+    }
+
+    /// The length of the longest rule matching the repository, if any does.
+    fn match_length(&self, remote: Option<&str>, root: &Path) -> Option<usize> {
+        let hosts = self.hosts.iter().filter(|rule| {
+            let rule = rule.trim_end_matches('/').to_lowercase();
+            remote.is_some_and(|remote| remote == rule || remote.starts_with(&format!("{}/", rule)))
+        });
+        let dirs = self.dirs.iter().filter(|rule| root.starts_with(expand_dir(rule)));
+        hosts.chain(dirs).map(String::len).max()
+    }
+}
+
+fn format_ident(name: &str, email: &str) -> String {
+    format!("{} <{}>", name, email)
+}
+
+fn expand_dir(dir: &str) -> PathBuf {
+    let path = match (dir.strip_prefix("~/"), home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(dir),
+    };
+    // The repository root has symlinks resolved, so the rule needs them too
+    path.canonicalize().unwrap_or(path)
+}
+
+/// Every profile in the git config, in the order first defined.
+pub fn profiles(repo: &GitRepo) -> Vec<Profile> {
+    let output = repo.run_command_with_output(&["config", "--get-regexp", r"^syncgit\.profile\."]).unwrap_or_default();
+    let mut profiles: Vec<Profile> = Vec::new();
+    for line in output.lines() {
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        // The subsection keeps its case and may hold dots; the key is lowercased
+        let Some((name, field)) = key.strip_prefix(PREFIX).and_then(|rest| rest.rsplit_once('.')) else { continue };
+        let index = match profiles.iter().position(|p| p.name == name) {
+            Some(index) => index,
+            None => {
+                profiles.push(Profile::new(name));
+                profiles.len() - 1
+            }
+        };
+        let profile = &mut profiles[index];
+        let value = value.to_string();
+        match field {
+            "name" => profile.user_name = Some(value),
+            "email" => profile.email = Some(value),
+            "signingkey" => profile.signing_key = Some(value),
+            "host" => profile.hosts.push(value),
+            "dir" => profile.dirs.push(value),
+            _ => {}
+        }
+    }
+    profiles
+}
+
+/// `host/owner/repo` of the push remote, lowercased.
+fn remote_location(repo: &GitRepo) -> Option<String> {
+    let (host, path) = push::push_url(repo).and_then(|url| auth::split_remote(&url))?;
+    Some(format!("{}/{}", host, path.to_lowercase()))
+}
+
+/// The profile whose rules match the repository most specifically.
+fn matching(repo: &GitRepo, profiles: &[Profile]) -> Option<usize> {
+    let remote = remote_location(repo);
+    let root = repo.root.canonicalize().unwrap_or_else(|_| repo.root.clone());
+    profiles.iter()
+        .enumerate()
+        .filter_map(|(index, profile)| Some((index, profile.match_length(remote.as_deref(), &root)?)))
+        .max_by_key(|(_, length)| *length)
+        .map(|(index, _)| index)
+}
+
+fn config_value(repo: &GitRepo, key: &str) -> Option<String> {
+    repo.run_command_with_output(&["config", "--get", key]).ok().filter(|v| !v.is_empty())
+}
+
+/// Writes a profile's identity into the repository's own config.
+fn apply(repo: &GitRepo, profile: &Profile) -> Result<()> {
+    let (Some(name), Some(email)) = (&profile.user_name, &profile.email) else {
+        return Err(GitError::Usage(t!("identity.profile_incomplete", profile = profile.name)));
+    };
+    repo.run_command_with_output(&["config", "--local", "user.name", name])?;
+    repo.run_command_with_output(&["config", "--local", "user.email", email])?;
+    if let Some(key) = &profile.signing_key {
+        repo.run_command_with_output(&["config", "--local", "user.signingkey", key])?;
+    }
+    println!("{}", UI::center_text(&t!("identity.applied", profile = profile.name, ident = profile.ident())));
+    Ok(())
+}
+
+/// Asks for a name and email when there's no profile to take them from,
+/// and saves them for this repository.
+fn ask_identity(repo: &GitRepo) -> Result<()> {
+    let name = UI::prompt_input(&t!("identity.name_prompt")).trim().to_string();
+    if name.is_empty() {
+        return Err(GitError::Cancelled(t!("cancel.commit")));
+    }
+    let email = UI::prompt_input(&t!("identity.email_prompt")).trim().to_string();
+    if !email.contains('@') {
+        return Err(GitError::Usage(t!("identity.invalid_email", email = email)));
+    }
+    repo.run_command_with_output(&["config", "--local", "user.name", &name])?;
+    repo.run_command_with_output(&["config", "--local", "user.email", &email])?;
+    Ok(())
+}
+
+/// Whether `name` and `email` are what `profile` expects. Emails compare
+/// case-insensitively, as mail servers treat them.
+fn fits(profile: &Profile, name: &str, email: &str) -> bool {
+    profile.email.as_deref().is_none_or(|e| e.eq_ignore_ascii_case(email))
+        && profile.user_name.as_deref().is_none_or(|n| n == name)
+}
+
+/// Whether committing would ask anything: no identity yet, one that isn't
+/// the matching profile's, or teammates to credit. A broken roster counts
+/// as yes, so `co_author_trailers` gets to report it.
+pub fn needs_prompt(repo: &GitRepo) -> bool {
+    let profiles = profiles(repo);
+    let matched = matching(repo, &profiles).map(|index| &profiles[index]);
+    let settled = match (config_value(repo, "user.name"), config_value(repo, "user.email")) {
+        (Some(name), Some(email)) => matched.is_none_or(|profile| fits(profile, &name, &email)),
+        _ => false,
+    };
+    !settled || teammates(repo).map_or(true, |team| !team.is_empty())
+}
+
+/// Runs before committing: makes sure an identity is set, and that it's the
+/// one the matching profile expects. Then says who the commit is from.
+pub fn check(repo: &GitRepo) -> Result<()> {
+    let profiles = profiles(repo);
+    let matched = matching(repo, &profiles).map(|index| &profiles[index]);
+    let name = config_value(repo, "user.name");
+    let email = config_value(repo, "user.email");
+
+    match (&name, &email, matched) {
+        (Some(name), Some(email), Some(profile)) => {
+            if !fits(profile, name, email) {
+                println!(
+                    "{}",
+                    UI::center_text(&t!(
+                        "identity.mismatch",
+                        profile = profile.name,
+                        expected = profile.ident(),
+                        current = format_ident(name, email)
+                    ))
+                );
+                if UI::prompt_yes_no(&t!("identity.use_profile", profile = profile.name)) {
+                    apply(repo, profile)?;
+                }
+            }
+        }
+        (Some(_), Some(_), None) => {}
+        (_, _, Some(profile)) => {
+            println!("{}", UI::center_text(&t!("identity.missing")));
+            if !UI::prompt_yes_no(&t!("identity.use_profile", profile = profile.name)) {
+                return Err(GitError::Cancelled(t!("cancel.commit")));
+            }
+            apply(repo, profile)?;
+        }
+        (_, _, None) => {
+            println!("{}", UI::center_text(&t!("identity.missing")));
+            println!("{}", UI::center_text(&t!("identity.profile_hint")));
+            ask_identity(repo)?;
+        }
+    }
+
+    let email = config_value(repo, "user.email").unwrap_or_default();
+    let ident = format_ident(&config_value(repo, "user.name").unwrap_or_default(), &email);
+    let line = match matched {
+        Some(profile) if profile.email.as_deref().is_some_and(|e| e.eq_ignore_ascii_case(&email)) => {
+            t!("identity.committing_as_profile", ident = ident, profile = profile.name)
+        }
+        _ => t!("identity.committing_as", ident = ident),
+    };
+    println!("{}", UI::center_text(&line));
+    Ok(())
+}
+
+// ============================================================================
+// CO-AUTHORS
+// ============================================================================
+
+// The team roster is a table in `.syncgit.toml`, handle to identity:
+//
+//     [team]
+//     ada = "Ada Lovelace <ada@acme.example>"
+//     grace = "Grace Hopper <grace@acme.example>"
+
+/// The roster's handles and identities, sorted by handle.
+pub fn roster(repo: &GitRepo) -> Result<Vec<(String, String)>> {
+    let Some(config) = config::load(repo)? else {
+        return Ok(Vec::new());
+    };
+    let Some(team) = config.get("team") else {
+        return Ok(Vec::new());
+    };
+    let team = team.as_table().ok_or_else(|| config::invalid(t!("identity.team_not_table")))?;
+    let mut members = team.iter()
+        .map(|(handle, ident)| match ident.as_str() {
+            Some(ident) if ident.contains('<') && ident.ends_with('>') => Ok((handle.clone(), ident.to_string())),
+            _ => Err(config::invalid(t!("identity.team_invalid", handle = handle))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    members.sort();
+    Ok(members)
+}
+
+/// The roster without the committer.
+fn teammates(repo: &GitRepo) -> Result<Vec<(String, String)>> {
+    let own_email = config_value(repo, "user.email").unwrap_or_default().to_lowercase();
+    Ok(roster(repo)?
+        .into_iter()
+        .filter(|(_, ident)| !ident.to_lowercase().contains(&format!("<{}>", own_email)))
+        .collect())
+}
+
+/// Asks which teammates worked on the commit, when the project has a
+/// roster. Returns the `Co-authored-by` trailers to add.
+pub fn co_author_trailers(repo: &GitRepo) -> Result<Vec<String>> {
+    let team = teammates(repo)?;
+    if team.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let handles: Vec<&str> = team.iter().map(|(handle, _)| handle.as_str()).collect();
+    println!("{}", UI::center_text(&t!("identity.team", handles = handles.join(", "))));
+    loop {
+        let input = UI::prompt_input(&t!("identity.co_authors_prompt"));
+        let wanted: Vec<&str> = input.split([',', ' ']).filter(|h| !h.is_empty()).collect();
+        let unknown: Vec<&str> = wanted.iter().copied().filter(|h| !handles.contains(h)).collect();
+        if !unknown.is_empty() {
+            println!("{}", UI::center_text(&t!("identity.unknown_handles", handles = unknown.join(", "))));
+            continue;
+        }
+        let trailers: Vec<String> = team.iter()
+            .filter(|(handle, _)| wanted.contains(&handle.as_str()))
+            .map(|(_, ident)| format!("Co-authored-by: {}", ident))
+            .collect();
+        if !trailers.is_empty() {
+            println!("{}", UI::center_text(&tn!("identity.co_authors_added", trailers.len())));
+        }
+        return Ok(trailers);
+    }
+}
+
+// ============================================================================
+// COMMANDS
+// ============================================================================
+
+/// `syncgit identity`: who commits here, from which config, and the
+/// profiles and teammates to choose from.
+pub fn print_status() -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    let profiles = profiles(&repo);
+    let matched = matching(&repo, &profiles);
+
+    println!("{}", UI::center_text(&t!("identity.title")));
+    UI::print_separator();
+    match (config_value(&repo, "user.name"), config_value(&repo, "user.email")) {
+        (Some(name), Some(email)) => {
+            // "local", "global", "system", ...
+            let scope = repo.run_command_with_output(&["config", "--show-scope", "--get", "user.email"])
+                .ok()
+                .and_then(|s| s.split_whitespace().next().map(str::to_string))
+                .unwrap_or_default();
+            println!("{}", t!("identity.current", ident = format_ident(&name, &email), scope = scope));
+        }
+        _ => println!("{}", t!("identity.current_missing")),
+    }
+
+    UI::print_separator();
+    if profiles.is_empty() {
+        println!("{}", UI::center_text(&t!("identity.no_profiles")));
+        println!("{}", UI::center_text(&t!("identity.add_hint")));
+    }
+    for (index, profile) in profiles.iter().enumerate() {
+        let rules: Vec<String> = profile.hosts.iter().map(|h| format!("host {}", h))
+            .chain(profile.dirs.iter().map(|d| format!("dir {}", d)))
+            .collect();
+        println!(
+            "{}",
+            t!(
+                "identity.profile_entry",
+                marker = if matched == Some(index) { "▶" } else { " " },
+                profile = profile.name,
+                ident = profile.ident(),
+                rules = if rules.is_empty() { String::from("-") } else { rules.join(", ") }
+            )
+        );
+    }
+
+    let team = roster(&repo)?;
+    if !team.is_empty() {
+        UI::print_separator();
+        println!("{}", UI::center_text(&tn!("identity.team_title", team.len(), file = config::FILE_NAME)));
+        for (handle, ident) in &team {
+            println!("{}", t!("identity.team_entry", handle = handle, ident = ident));
+        }
+    }
+    Ok(())
+}
+
+/// `syncgit identity use <profile>`
+pub fn use_profile(name: &str) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    let profiles = profiles(&repo);
+    let profile = profiles.iter()
+        .find(|p| p.name == name)
+        .ok_or_else(|| GitError::Usage(t!("identity.unknown_profile", profile = name)))?;
+    apply(&repo, profile)
+}
+
+/// `syncgit identity add <profile>`: asks for the identity and the rules
+/// that select it, and saves them in the global git config.
+pub fn add_profile(name: &str) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    if profiles(&repo).iter().any(|p| p.name == name) {
+        return Err(GitError::Usage(t!("identity.profile_exists", profile = name)));
+    }
+    let user_name = UI::prompt_input(&t!("identity.name_prompt")).trim().to_string();
+    let email = UI::prompt_input(&t!("identity.email_prompt")).trim().to_string();
+    if user_name.is_empty() {
+        return Err(GitError::Cancelled(t!("cancel.operation")));
+    }
+    if !email.contains('@') {
+        return Err(GitError::Usage(t!("identity.invalid_email", email = email)));
+    }
+    let remote = remote_location(&repo)
+        .and_then(|location| location.rsplit_once('/').map(|(owner, _)| owner.to_string()))
+        .unwrap_or_else(|| String::from("github.com/your-org"));
+    let hosts = UI::prompt_input(&t!("identity.hosts_prompt", example = remote));
+    let dirs = UI::prompt_input(&t!("identity.dirs_prompt"));
+
+    let key = |field: &str| format!("{}{}.{}", PREFIX, name, field);
+    repo.run_command_with_output(&["config", "--global", &key("name"), &user_name])?;
+    repo.run_command_with_output(&["config", "--global", &key("email"), &email])?;
+    for host in hosts.split([',', ' ']).filter(|h| !h.is_empty()) {
+        repo.run_command_with_output(&["config", "--global", "--add", &key("host"), host])?;
+    }
+    for dir in dirs.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+        repo.run_command_with_output(&["config", "--global", "--add", &key("dir"), dir])?;
+    }
+    println!("{}", UI::center_text(&t!("identity.profile_saved", profile = name)));
+    println!("{}", UI::center_text(&t!("identity.use_hint", profile = name)));
+    Ok(())
+}
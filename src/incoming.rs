@@ -0,0 +1,153 @@
+use std::collections::BTreeSet;
+
+use crate::error::Result;
+use crate::i18n::{t, tn};
+use crate::{GitRepo, UI};
+
+// ============================================================================
+// INCOMING CHANGES
+// ============================================================================
+
+const UPSTREAM: &str = "@{u}";
+
+/// A file changed upstream, from `git diff --numstat`. Binary files have no
+/// line counts.
+struct FileChange {
+    path: String,
+    added: Option<usize>,
+    removed: Option<usize>,
+}
+
+fn incoming_commits(repo: &GitRepo) -> Result<Vec<String>> {
+    let log = repo.run_command_with_output(&[
+        "log",
+        "--format=%h%x1f%ad%x1f%an%x1f%s",
+        "--date=short",
+        &format!("HEAD..{}", UPSTREAM),
+        "--",
+    ])?;
+    Ok(log.lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\x1f');
+            let (hash, date, author, subject) = (fields.next()?, fields.next()?, fields.next()?, fields.next()?);
+            Some(t!("incoming.commit", hash = hash, date = date, author = author, subject = subject))
+        })
+        .collect())
+}
+
+/// What upstream changed since the common ancestor, file by file.
+fn incoming_files(repo: &GitRepo) -> Result<Vec<FileChange>> {
+    let numstat = repo.run_command_with_output(&["diff", "--numstat", "-M", &format!("HEAD...{}", UPSTREAM), "--"])?;
+    Ok(numstat.lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let (added, removed, path) = (fields.next()?, fields.next()?, fields.next()?);
+            Some(FileChange {
+                path: path.to_string(),
+                added: added.parse().ok(),
+                removed: removed.parse().ok(),
+            })
+        })
+        .collect())
+}
+
+/// Files changed on this side: uncommitted, untracked, or in local commits
+/// upstream doesn't have yet.
+fn locally_modified(repo: &GitRepo) -> BTreeSet<String> {
+    let mut files = BTreeSet::new();
+    let listings = [
+        repo.run_command_with_output(&["diff", "--name-only", "HEAD", "--"]),
+        repo.run_command_with_output(&["ls-files", "--others", "--exclude-standard"]),
+        repo.run_command_with_output(&["diff", "--name-only", &format!("{}...HEAD", UPSTREAM), "--"]),
+    ];
+    for listing in listings.into_iter().flatten() {
+        files.extend(listing.lines().map(str::to_string));
+    }
+    files
+}
+
+/// Both sides of a change: numstat shows renames as `old => new` or
+/// `dir/{old => new}/file`.
+fn change_paths(path: &str) -> Vec<String> {
+    let (Some(arrow), open, close) = (path.find(" => "), path.find('{'), path.find('}')) else {
+        return vec![path.to_string()];
+    };
+    match (open, close) {
+        (Some(open), Some(close)) if open < arrow && arrow < close => {
+            let (prefix, suffix) = (&path[..open], &path[close + 1..]);
+            [&path[open + 1..arrow], &path[arrow + 4..close]]
+                .iter()
+                .map(|middle| format!("{}{}{}", prefix, middle, suffix).replace("//", "/"))
+                .collect()
+        }
+        _ => vec![path[..arrow].to_string(), path[arrow + 4..].to_string()],
+    }
+}
+
+/// Lists every commit in `HEAD..@{u}`, a per-file diffstat of what they
+/// change, and the files also modified here that are likely to conflict.
+pub fn show(repo: &GitRepo) -> Result<()> {
+    let commits = incoming_commits(repo)?;
+    let files = incoming_files(repo)?;
+
+    UI::print_separator();
+    println!("{}", UI::center_text(&tn!("incoming.commits_title", commits.len())));
+    for commit in &commits {
+        println!("{}", commit);
+    }
+
+    println!();
+    println!("{}", UI::center_text(&tn!("incoming.files_title", files.len())));
+    let width = files.iter().map(|f| f.added.map_or(1, |n| n.to_string().len())).max().unwrap_or(1);
+    for file in &files {
+        match (file.added, file.removed) {
+            (Some(added), Some(removed)) => println!(
+                "   {:>width$} {:<6} {}",
+                format!("+{}", added),
+                format!("-{}", removed),
+                file.path,
+                width = width + 1
+            ),
+            _ => println!("   {:>width$} {:<6} {}", "", t!("incoming.binary"), file.path, width = width + 1),
+        }
+    }
+    let added: usize = files.iter().filter_map(|f| f.added).sum();
+    let removed: usize = files.iter().filter_map(|f| f.removed).sum();
+    println!("{}", t!("incoming.totals", added = added, removed = removed));
+
+    let local = locally_modified(repo);
+    let overlapping: Vec<&FileChange> = files.iter()
+        .filter(|f| change_paths(&f.path).iter().any(|p| local.contains(p)))
+        .collect();
+    if !overlapping.is_empty() {
+        println!();
+        println!("{}", UI::center_text(&tn!("incoming.conflict_title", overlapping.len())));
+        for file in overlapping {
+            println!("{}", t!("incoming.conflict_file", path = file.path));
+        }
+        println!("{}", UI::center_text(&t!("incoming.conflict_hint")));
+    }
+    UI::print_separator();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_renames_into_both_paths() {
+        let cases: [(&str, &[&str]); 7] = [
+            ("src/main.rs", &["src/main.rs"]),
+            ("src/{weird}.rs", &["src/{weird}.rs"]),
+            ("old.txt => new.txt", &["old.txt", "new.txt"]),
+            ("docs/{intro.md => start.md}", &["docs/intro.md", "docs/start.md"]),
+            ("src/{old => new}/mod.rs", &["src/old/mod.rs", "src/new/mod.rs"]),
+            ("src/{ => nested}/lib.rs", &["src/lib.rs", "src/nested/lib.rs"]),
+            ("{lib => crates/core/src}/parse.rs", &["lib/parse.rs", "crates/core/src/parse.rs"]),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(change_paths(path), expected, "{}", path);
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::push::PushTarget;
+use crate::{GitRepo, UI};
+
+// ============================================================================
+// GIT LFS
+// ============================================================================
+
+/// Files above this size that aren't stored with LFS get a warning when
+/// staged. `git config syncgit.lfsThreshold 50m` changes it.
+const DEFAULT_THRESHOLD: u64 = 10 * 1024 * 1024;
+
+/// How many files to name before summarizing the rest.
+const MAX_LISTED: usize = 10;
+
+fn threshold(repo: &GitRepo) -> u64 {
+    // --type=int understands k/m/g suffixes
+    repo.run_command_with_output(&["config", "--type=int", "--get", "syncgit.lfsThreshold"])
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_THRESHOLD)
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1024 * 1024 * 1024 => format!("{:.1} GB", b as f64 / (1024.0 * 1024.0 * 1024.0)),
+        b if b >= 1024 * 1024 => format!("{:.1} MB", b as f64 / (1024.0 * 1024.0)),
+        b => format!("{} KB", b / 1024),
+    }
+}
+
+/// The patterns the root `.gitattributes` stores with LFS, as written by
+/// `git lfs track`.
+pub fn patterns(repo: &GitRepo) -> Vec<String> {
+    fs::read_to_string(repo.root.join(".gitattributes"))
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter(|line| line.split_whitespace().skip(1).any(|attr| attr == "filter=lfs"))
+        .filter_map(|line| line.split_whitespace().next().map(str::to_string))
+        .collect()
+}
+
+fn installed(repo: &GitRepo) -> bool {
+    repo.run_command_with_output(&["lfs", "version"]).is_ok()
+}
+
+fn pre_push_hook(repo: &GitRepo) -> PathBuf {
+    match repo.run_command_with_output(&["config", "--get", "core.hooksPath"]) {
+        Ok(dir) if !dir.is_empty() => repo.root.join(dir).join("pre-push"),
+        _ => repo.git_path("hooks/pre-push"),
+    }
+}
+
+/// Whether `git lfs install` has set this repository up: the filter that
+/// turns files into pointers, and the pre-push hook that uploads them.
+fn hooks_installed(repo: &GitRepo) -> bool {
+    let filter = repo.run_command_with_output(&["config", "--get", "filter.lfs.process"])
+        .or_else(|_| repo.run_command_with_output(&["config", "--get", "filter.lfs.clean"]))
+        .is_ok();
+    let hook = fs::read_to_string(pre_push_hook(repo))
+        .map(|script| script.contains("git lfs") || script.contains("git-lfs"))
+        .unwrap_or(false);
+    filter && hook
+}
+
+/// Startup check for repositories that use LFS: is `git lfs` there, and
+/// has it been installed for this clone?
+pub fn check_setup(repo: &GitRepo) {
+    let patterns = patterns(repo);
+    if patterns.is_empty() {
+        return;
+    }
+    println!("{}", UI::center_text(&t!("lfs.tracking", patterns = patterns.join(", "))));
+    if !installed(repo) {
+        println!("{}", UI::center_text(&t!("lfs.not_installed")));
+        println!("{}", UI::center_text(&t!("lfs.install_hint")));
+    } else if !hooks_installed(repo) {
+        println!("{}", UI::center_text(&t!("lfs.hooks_missing")));
+        if UI::prompt_yes_no(&t!("lfs.install_prompt")) {
+            match repo.run_command_with_output(&["lfs", "install", "--local"]) {
+                Ok(_) => println!("{}", UI::center_text(&t!("lfs.installed"))),
+                Err(e) => println!("{}", UI::center_text(&t!("lfs.install_failed", error = e))),
+            }
+        }
+    }
+    UI::print_separator();
+}
+
+/// Which of `paths` the attributes send through the LFS filter.
+fn lfs_tracked(repo: &GitRepo, paths: &[String]) -> Vec<String> {
+    let mut args = vec!["check-attr", "-z", "filter", "--"];
+    args.extend(paths.iter().map(String::as_str));
+    let output = repo.run_command_with_output(&args).unwrap_or_default();
+    // "<path>\0filter\0<value>\0" per path
+    let fields: Vec<&str> = output.split('\0').collect();
+    fields.chunks(3)
+        .filter(|entry| entry.len() == 3 && entry[2] == "lfs")
+        .map(|entry| entry[0].to_string())
+        .collect()
+}
+
+/// `*.ext` for files with an extension, the path itself otherwise.
+fn track_pattern(path: &str) -> String {
+    match Path::new(path).extension() {
+        Some(ext) => format!("*.{}", ext.to_string_lossy()),
+        None => path.to_string(),
+    }
+}
+
+/// New or modified files under `pathspecs` above the size threshold that
+/// LFS won't store.
+fn unprotected_large_files(repo: &GitRepo, pathspecs: &[&str], limit: u64) -> Vec<(String, u64)> {
+    let mut args = vec!["ls-files", "-z", "--modified", "--others", "--exclude-standard", "--"];
+    args.extend_from_slice(pathspecs);
+    let listed = repo.run_command_with_output(&args).unwrap_or_default();
+    let mut large: Vec<(String, u64)> = listed.split('\0')
+        .filter(|path| !path.is_empty())
+        .filter_map(|path| {
+            let meta = fs::metadata(repo.root.join(path)).ok()?;
+            (meta.is_file() && meta.len() > limit).then(|| (path.to_string(), meta.len()))
+        })
+        .collect();
+    large.dedup_by(|a, b| a.0 == b.0);
+    if large.is_empty() {
+        return large;
+    }
+    let paths: Vec<String> = large.iter().map(|(path, _)| path.clone()).collect();
+    let tracked = lfs_tracked(repo, &paths);
+    large.retain(|(path, _)| !tracked.contains(path));
+    large
+}
+
+/// Whether staging `pathspecs` would make `check_large_files` ask anything.
+pub fn has_large_files(repo: &GitRepo, pathspecs: &[&str]) -> bool {
+    !unprotected_large_files(repo, pathspecs, threshold(repo)).is_empty()
+}
+
+/// Runs before staging: warns about new or modified files under `pathspecs`
+/// above the size threshold that LFS won't store, and offers to track
+/// their extensions.
+pub fn check_large_files(repo: &GitRepo, pathspecs: &[&str]) -> Result<()> {
+    let limit = threshold(repo);
+    let large = unprotected_large_files(repo, pathspecs, limit);
+    if large.is_empty() {
+        return Ok(());
+    }
+
+    UI::print_separator();
+    println!("{}", UI::center_text(&tn!("lfs.large_files", large.len(), size = format_size(limit))));
+    for (path, size) in large.iter().take(MAX_LISTED) {
+        println!("{}", t!("lfs.large_entry", path = path, size = format_size(*size)));
+    }
+    if large.len() > MAX_LISTED {
+        println!("{}", tn!("lfs.more", large.len() - MAX_LISTED));
+    }
+
+    if !installed(repo) {
+        println!("{}", UI::center_text(&t!("lfs.not_installed")));
+        if !UI::prompt_yes_no(&t!("lfs.commit_anyway")) {
+            return Err(GitError::Cancelled(t!("cancel.operation")));
+        }
+        return Ok(());
+    }
+
+    let mut by_pattern: BTreeMap<String, usize> = BTreeMap::new();
+    for (path, _) in &large {
+        *by_pattern.entry(track_pattern(path)).or_default() += 1;
+    }
+    let mut declined = false;
+    let mut changed_attributes = false;
+    for (pattern, count) in &by_pattern {
+        if !UI::prompt_yes_no(&tn!("lfs.track_prompt", *count, pattern = pattern)) {
+            declined = true;
+            continue;
+        }
+        repo.run_command_with_output(&["lfs", "track", pattern])?;
+        println!("{}", UI::center_text(&t!("lfs.tracked", pattern = pattern)));
+        changed_attributes = true;
+    }
+    if changed_attributes {
+        // The pathspec may not cover the root, where `git lfs track` writes
+        repo.run_command(&["add", "--", ".gitattributes"])?;
+    }
+    if declined && !UI::prompt_yes_no(&t!("lfs.commit_anyway")) {
+        return Err(GitError::Cancelled(t!("cancel.operation")));
+    }
+    Ok(())
+}
+
+/// Runs before pushing: lists the LFS objects this push has to upload. If
+/// the pre-push hook that would upload them isn't installed, uploads them
+/// here, since a pushed pointer without its object can't be checked out.
+pub fn before_push(repo: &GitRepo, target: &PushTarget) -> Result<()> {
+    if patterns(repo).is_empty() || !installed(repo) {
+        return Ok(());
+    }
+    let output = repo.run_command_with_output(&["lfs", "push", "--dry-run", &target.remote, &target.local_branch])
+        .unwrap_or_default();
+    // "push <oid> => <path>"
+    let pending: Vec<&str> = output.lines()
+        .filter_map(|line| line.strip_prefix("push ")?.split_once(" => ").map(|(_, path)| path))
+        .collect();
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    println!("{}", UI::center_text(&tn!("lfs.pending_upload", pending.len())));
+    for path in pending.iter().take(MAX_LISTED) {
+        println!("{}", t!("lfs.pending_entry", path = path));
+    }
+    if pending.len() > MAX_LISTED {
+        println!("{}", tn!("lfs.more", pending.len() - MAX_LISTED));
+    }
+    if !hooks_installed(repo) {
+        println!("{}", UI::center_text(&t!("lfs.uploading")));
+        repo.run_command(&["lfs", "push", &target.remote, &target.local_branch])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    /// An empty repository in a fresh temporary directory.
+    fn temp_repo(name: &str) -> GitRepo {
+        let root = env::temp_dir().join(format!("syncgit-lfs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let repo = GitRepo { root, name: name.to_string() };
+        repo.run_command_with_output(&["init", "-q"]).unwrap();
+        repo
+    }
+
+    #[test]
+    fn reads_the_threshold_from_git_config() {
+        let repo = temp_repo("threshold");
+        let cases = [
+            (None, DEFAULT_THRESHOLD),
+            (Some("50m"), 50 * 1024 * 1024),
+            (Some("512k"), 512 * 1024),
+            (Some("1000"), 1000),
+            (Some("lots"), DEFAULT_THRESHOLD),
+        ];
+        for (value, expected) in cases {
+            if let Some(value) = value {
+                repo.run_command_with_output(&["config", "syncgit.lfsThreshold", value]).unwrap();
+            }
+            assert_eq!(threshold(&repo), expected, "{:?}", value);
+        }
+        let _ = fs::remove_dir_all(&repo.root);
+    }
+
+    #[test]
+    fn lists_lfs_patterns_from_gitattributes() {
+        let repo = temp_repo("patterns");
+        assert!(patterns(&repo).is_empty());
+
+        let attributes = "\
+*.psd filter=lfs diff=lfs merge=lfs -text
+*.txt text eol=lf
+# *.zip filter=lfs diff=lfs merge=lfs -text
+assets/video/** filter=lfs diff=lfs merge=lfs -text
+*.lfs-like -filter=lfs
+";
+        fs::write(repo.root.join(".gitattributes"), attributes).unwrap();
+        assert_eq!(patterns(&repo), ["*.psd", "assets/video/**"]);
+        let _ = fs::remove_dir_all(&repo.root);
+    }
+
+    #[test]
+    fn tracks_by_extension() {
+        let cases = [
+            ("art/cover.psd", "*.psd"),
+            ("data.tar.gz", "*.gz"),
+            ("bin/tool", "bin/tool"),
+            ("Makefile", "Makefile"),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(track_pattern(path), expected, "{}", path);
+        }
+    }
+
+    #[test]
+    fn formats_sizes() {
+        let cases = [
+            (512, "0 KB"),
+            (10 * 1024, "10 KB"),
+            (15 * 1024 * 1024 / 10, "1.5 MB"),
+            (3 * 1024 * 1024 * 1024, "3.0 GB"),
+        ];
+        for (bytes, expected) in cases {
+            assert_eq!(format_size(bytes), expected, "{}", bytes);
+        }
+    }
+
+    #[test]
+    fn finds_large_files_lfs_wont_store() {
+        let repo = temp_repo("large");
+        fs::write(repo.root.join(".gitattributes"), "*.psd filter=lfs diff=lfs merge=lfs -text\n").unwrap();
+        fs::create_dir_all(repo.root.join("data")).unwrap();
+        let big = vec![0u8; 4096];
+        fs::write(repo.root.join("data/dump.bin"), &big).unwrap();
+        fs::write(repo.root.join("cover.psd"), &big).unwrap();
+        fs::write(repo.root.join("notes.txt"), "small").unwrap();
+        fs::write(repo.root.join(".gitignore"), "ignored.bin\n").unwrap();
+        fs::write(repo.root.join("ignored.bin"), &big).unwrap();
+
+        assert_eq!(unprotected_large_files(&repo, &["."], 1024), [("data/dump.bin".to_string(), 4096)]);
+        assert!(unprotected_large_files(&repo, &["notes.txt"], 1024).is_empty());
+        assert!(unprotected_large_files(&repo, &["."], 8192).is_empty());
+        let _ = fs::remove_dir_all(&repo.root);
+    }
+}
@@ -1,21 +1,48 @@
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use std::process::{Command, Stdio};
 use std::env;
 use std::path::{Path, PathBuf};
 use std::net::TcpStream;
 use std::collections::BTreeMap;
 use std::time::Duration;
-use std::fs;
 
 use crossterm::terminal;
 
+mod i18n;
+mod incoming;
+mod api;
+mod auth;
+mod bundle;
+mod changelog;
+mod cli;
+mod config;
+mod diff;
+mod error;
+mod gitignore;
+mod github;
+mod identity;
+mod lfs;
+mod push;
+mod queue;
+mod release;
+mod scaffold;
+mod ssh;
+mod stash;
+mod submodule;
+mod tasks;
+mod tui;
+mod worktree;
+
+use cli::InitOptions;
+
+use i18n::{t, tn};
+
+use error::{Failure, GitError, Result, EXIT_OK};
+
 // ============================================================================
 // CONSTANTS
 // ============================================================================
 
-const MSG_NO_INTERNET_PUSH: &str = "⚠️  No internet connection. Changes have been saved locally but not pushed.";
-const MSG_RUN_PUSH_MANUALLY: &str = "    Please run 'git push' manually when you have connection.";
-
 const TOKEN_ENV_VARS: &[&str] = &["GITHUB_TOKEN", "GH_TOKEN", "GIT_TOKEN"];
 const INTERNET_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
 
@@ -24,15 +51,7 @@ const INTERNET_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
 // ============================================================================
 
 fn get_github_token() -> Option<String> {
-    // Check environment variables for token
-    for var in TOKEN_ENV_VARS {
-        if let Ok(token) = std::env::var(var) {
-            if !token.trim().is_empty() {
-                return Some(token.trim().to_string());
-            }
-        }
-    }
-    None
+    auth::find_token().map(|token| token.value)
 }
 
 fn check_internet_connection() -> bool {
@@ -43,28 +62,6 @@ fn check_internet_connection() -> bool {
     ).is_ok()
 }
 
-// ============================================================================
-// ERROR HANDLING
-// ============================================================================
-
-use std::fmt;
-use std::error::Error;
-
-#[derive(Debug)]
-enum GitError {
-    NoChanges,
-    NoCommitMessage,
-    CommandFailed(String),
-    NoToken,
-    NoInternet,
-    #[allow(dead_code)]
-    Other(String),
-}
-
-impl Error for GitError {}
-
-type Result<T = ()> = std::result::Result<T, GitError>;
-
 // ============================================================================
 // GIT OPERATIONS
 // ============================================================================
@@ -74,21 +71,76 @@ struct GitRepo {
     name: String,
 }
 
+/// What `GitRepo::lookup` found at or above a path.
+enum Lookup {
+    Found(GitRepo),
+    /// A `.git` file at this directory points at a git dir that's gone,
+    /// e.g. a worktree whose main repository was moved or deleted.
+    BrokenLink(PathBuf),
+    NotFound,
+}
+
 impl GitRepo {
-    fn find_from_path(path: &Path) -> Option<Self> {
+    /// The working tree containing `path`. `.git` is a directory in a
+    /// regular clone but a file pointing at the real git dir in linked
+    /// worktrees and submodules, so git is asked to resolve it.
+    fn lookup(path: &Path) -> Lookup {
         let mut current = path.to_path_buf();
         loop {
             if current.join(".git").exists() {
+                if Self::resolve_git_dir(&current).is_none() {
+                    return Lookup::BrokenLink(current);
+                }
                 let name = Self::extract_repo_name(&current);
-                return Some(GitRepo { root: current, name });
+                return Lookup::Found(GitRepo { root: current, name });
             }
 
             if !current.pop() {
-                return None;
+                return Lookup::NotFound;
             }
         }
     }
 
+    /// Like `lookup`, for callers that treat a broken link as no repository.
+    fn find_from_path(path: &Path) -> Option<Self> {
+        match Self::lookup(path) {
+            Lookup::Found(repo) => Some(repo),
+            Lookup::BrokenLink(_) | Lookup::NotFound => None,
+        }
+    }
+
+    /// The working tree the current directory is in.
+    fn from_current_dir() -> Result<Self> {
+        let dir = env::current_dir().map_err(|e| GitError::io(t!("error.current_dir"), e))?;
+        match Self::lookup(&dir) {
+            Lookup::Found(repo) => Ok(repo),
+            Lookup::BrokenLink(path) => Err(GitError::Usage(t!("worktree.broken_link", path = path.display()))),
+            Lookup::NotFound => Err(GitError::Usage(t!("init.no_repo_found"))),
+        }
+    }
+
+    fn resolve_git_dir(root: &Path) -> Option<PathBuf> {
+        Command::new("git")
+            .arg("-C")
+            .arg(root)
+            .args(["rev-parse", "--absolute-git-dir"])
+            .stderr(Stdio::null())
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| String::from_utf8(o.stdout).ok())
+            .map(|s| PathBuf::from(s.trim()))
+    }
+
+    /// Where git keeps `name` for this working tree, e.g. `MERGE_HEAD`.
+    /// Per-worktree files of a linked worktree live under
+    /// `<common dir>/worktrees/<id>`, not under `<root>/.git`.
+    fn git_path(&self, name: &str) -> PathBuf {
+        self.run_command_with_output(&["rev-parse", "--git-path", name])
+            .map(|path| self.root.join(path))
+            .unwrap_or_else(|_| self.root.join(".git").join(name))
+    }
+
     fn extract_repo_name(path: &Path) -> String {
         // Try remote URL first
         if let Some(url) = Self::get_remote_url(path) {
@@ -139,10 +191,9 @@ impl GitRepo {
     }
 
     fn get_branch(&self) -> String {
-        self.run_command(&["symbolic-ref", "--short", "HEAD"])
-            .map(|_| String::new())
+        self.run_command_with_output(&["symbolic-ref", "--short", "HEAD"])
             .unwrap_or_else(|e| {
-                eprintln!("Error getting branch: {}", e);
+                eprintln!("{}", t!("repo.branch_error", error = e));
                 "unknown".to_string()
             })
     }
@@ -170,15 +221,17 @@ impl GitRepo {
 
         Command::new("git")
             .arg("-C").arg(&self.root)
-            .args(&["rev-list", "--left-right", "--count", &format!("{}...{}", branch, upstream)])
+            .args(["rev-list", "--left-right", "--count", &format!("{}...{}", branch, upstream)])
             .output()
             .ok()
             .and_then(|o| String::from_utf8(o.stdout).ok())
             .and_then(|s| {
-                let parts: Vec<&str> = s.trim().split_whitespace().collect();
+                // `branch...upstream` counts the left side first: commits
+                // only the branch has (ahead), then only upstream (behind)
+                let parts: Vec<&str> = s.split_whitespace().collect();
                 if parts.len() == 2 {
-                    let behind = parts[0].parse().ok()?;
-                    let ahead = parts[1].parse().ok()?;
+                    let ahead = parts[0].parse().ok()?;
+                    let behind = parts[1].parse().ok()?;
                     Some((ahead, behind))
                 } else {
                     None
@@ -207,7 +260,7 @@ impl GitRepo {
         let mut args = vec!["status", "--porcelain=v1", "-z"];
         
         // Procesar el pathspec si existe
-        let normalized = pathspec.map(|p| Self::normalize_pathspec(p));
+        let normalized = pathspec.map(Self::normalize_pathspec);
         
         if let Some(ref norm_path) = normalized {
             if !norm_path.is_empty() {
@@ -226,73 +279,63 @@ impl GitRepo {
         {
             Ok(output) => {
                 if !output.status.success() {
-                    eprintln!("Error al verificar cambios: {}", 
-                        String::from_utf8_lossy(&output.stderr));
+                    eprintln!("{}", t!("repo.status_check_failed",
+                        error = String::from_utf8_lossy(&output.stderr)));
                     return false;
                 }
                 // Verificar si hay salida (cambios)
                 !output.stdout.is_empty()
             },
             Err(e) => {
-                eprintln!("Error al ejecutar git status: {}", e);
+                eprintln!("{}", t!("repo.status_run_failed", error = e));
                 false
             }
         }
     }
 
     fn run_command_with_output(&self, args: &[&str]) -> Result<String> {
+        self.run_command_with_env(args, &[])
+    }
+
+    /// `run_command_with_output` with extra environment variables for this
+    /// one command.
+    fn run_command_with_env(&self, args: &[&str], vars: &[(&str, &str)]) -> Result<String> {
         // Same as run_command but returns the command's output
         let output = self.create_command(args)
+            .envs(vars.iter().copied())
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .output()
-            .map_err(|e| GitError::CommandFailed(format!("Failed to execute git command: {}", e)))?;
+            .map_err(|e| GitError::io(t!("error.exec_git"), e))?;
 
         if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-            return Err(GitError::CommandFailed(format!(
-                "git command failed with status {}: {}\nError: {}",
-                output.status,
-                args.join(" "),
-                stderr
-            )));
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::from_command(args, output.status, &stderr));
         }
 
         String::from_utf8(output.stdout)
-            .map_err(|e| GitError::CommandFailed(format!("Failed to parse command output: {}", e)))
+            .map_err(|e| GitError::Other(t!("error.parse_output", error = e)))
             .map(|s| s.trim().to_string())
     }
 
     fn run_command(&self, args: &[&str]) -> Result<()> {
         // Verify that the root directory exists
         if !self.root.exists() {
-            return Err(GitError::CommandFailed(format!(
-                "Repository root directory does not exist: {}",
-                self.root.display()
-            )));
+            return Err(GitError::Other(t!("error.root_missing", path = self.root.display())));
         }
 
         // Verificar que es un directorio
         if !self.root.is_dir() {
-            return Err(GitError::CommandFailed(format!(
-                "Repository root is not a directory: {}",
-                self.root.display()
-            )));
+            return Err(GitError::Other(t!("error.root_not_dir", path = self.root.display())));
         }
 
         // Verificar permisos de lectura
         if std::fs::metadata(&self.root)
-            .map_err(|e| GitError::CommandFailed(format!(
-                "Cannot access repository directory {}: {}",
-                self.root.display(), e
-            )))?
+            .map_err(|e| GitError::io(t!("error.root_access", path = self.root.display()), e))?
             .permissions().readonly()
         {
-            return Err(GitError::CommandFailed(format!(
-                "Insufficient permissions to read repository: {}",
-                self.root.display()
-            )));
+            return Err(GitError::Other(t!("error.root_readonly", path = self.root.display())));
         }
 
         // Configure the command with piped I/O
@@ -301,21 +344,17 @@ impl GitRepo {
             .stdout(Stdio::piped())  // Capture stdout
             .stderr(Stdio::piped())
             .spawn()
-            .map_err(|e| GitError::CommandFailed(format!(
-                "Failed to spawn git command: {}", e
-            )))?;
+            .map_err(|e| GitError::io(t!("error.spawn_git"), e))?;
             
         // Wait for the command to complete and capture output
         let output = child.wait_with_output()
-            .map_err(|e| GitError::CommandFailed(format!(
-                "Failed to wait for git command: {}", e
-            )))?;
+            .map_err(|e| GitError::io(t!("error.wait_git"), e))?;
 
         // Log stderr if there was an error or if there's any output
         if !output.stderr.is_empty() {
             let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
             if !stderr.is_empty() {
-                eprintln!("git stderr: {}", stderr);
+                eprintln!("{}", t!("repo.git_stderr", stderr = stderr));
             }
         }
 
@@ -334,14 +373,11 @@ impl GitRepo {
             Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            Err(GitError::CommandFailed(format!(
-                "git command failed with status {}: git {}\nError: {}",
-                output.status, args.join(" "), stderr.trim()
-            )))
+            Err(GitError::from_command(args, output.status, &stderr))
         }
     }
 
-    fn create_command<'a, I, S>(&self, args: I) -> Command
+    fn create_command<I, S>(&self, args: I) -> Command
     where
         I: IntoIterator<Item = S>,
         S: AsRef<std::ffi::OsStr>,
@@ -354,89 +390,96 @@ impl GitRepo {
             cmd.arg(arg);
         }
         
-        if let Some(token) = get_github_token() {
-            cmd.env("GITHUB_TOKEN", token);
+        // No token is added here: a token from the environment is inherited
+        // anyway, and one found in a helper, gh or .netrc stays there
+        cmd
+    }
+
+    /// Stores `token` for `url` in the repository's temporary credential
+    /// file so git can authenticate HTTPS requests to it.
+    fn store_credentials(&self, url: &str, token: &str) -> Result<()> {
+        // Configurar el helper de credenciales para almacenamiento temporal
+        // Absolute, so git finds it from any directory and in linked worktrees
+        let file = self.git_path("credentials");
+        let helper = format!("store --file={}", tasks::shell_quote(&file.to_string_lossy()));
+        self.run_command(&["config", "--local", "credential.helper", &helper])?;
+        
+        // Guardar las credenciales temporalmente
+        let mut cmd = self.create_command(["credential", "approve"]);
+        let mut child = cmd
+            .stdin(Stdio::piped())
+            .spawn()
+            .map_err(|e| GitError::io(t!("error.credential_spawn"), e))?;
+        
+        if let Some(stdin) = child.stdin.as_mut() {
+            writeln!(stdin, "url={}", url)
+                .map_err(|e| GitError::io(t!("error.credential_write"), e))?;
+            writeln!(stdin, "username={}", token)
+                .map_err(|e| GitError::io(t!("error.credential_write"), e))?;
+            writeln!(stdin, "password=x-oauth-basic")
+                .map_err(|e| GitError::io(t!("error.credential_write"), e))?;
         }
         
-        cmd
+        let status = child.wait()
+            .map_err(|e| GitError::io(t!("error.credential_wait"), e))?;
+        
+        if !status.success() {
+            return Err(GitError::Auth(Failure::new(t!("error.credential_store"))));
+        }
+        Ok(())
     }
 
     fn configure_auth_remote(&self) -> Result<()> {
-        let token = match get_github_token() {
-            Some(t) => {
-                println!("🔑 Found GitHub token");
-                t
-            }
-            None => {
-                println!("ℹ️  No GitHub token found");
-                println!("   Tried: {}", TOKEN_ENV_VARS.join(", "));
-                return Ok(());
-            }
-        };
+        let remote_url = push::push_url(self)
+            .ok_or_else(|| GitError::Other(t!("error.no_remote_url")))?;
+        self.configure_auth(&remote_url)
+    }
 
-        let remote_url = Self::get_remote_url(&self.root)
-            .ok_or_else(|| GitError::CommandFailed("Failed to get remote URL".to_string()))?;
+    /// Prepares authentication for pushing to `remote_url`: the GitHub token
+    /// for GitHub over HTTPS, nothing for anything else. Only a token from
+    /// the environment is handed to git; a login found in a credential
+    /// helper, gh or .netrc stays where it is.
+    fn configure_auth(&self, remote_url: &str) -> Result<()> {
+        match auth::RemoteKind::classify(remote_url) {
+            auth::RemoteKind::GitHub { .. } => {
+                let token = match auth::find_token() {
+                    Some(t) => {
+                        println!("{}", t!("auth.token_found"));
+                        t
+                    }
+                    None => {
+                        println!("{}", t!("auth.no_token"));
+                        println!("{}", auth::searched_sources());
+                        return Ok(());
+                    }
+                };
 
-        if remote_url.starts_with("https://") {
-            // Configure the credentials helper to store in memory (cache)
-            self.run_command(&["config", "--local", "credential.helper", "cache"])?;
-            
-            // Configure cache timeout (default 15 minutes)
-            self.run_command(&["config", "--local", "credential.helper", "cache --timeout=3600"])?;
-            
-            // Configurar la URL remota sin credenciales
-            self.run_command(&["remote", "set-url", "origin", &remote_url])?;
-            
-            // Configurar el helper de credenciales para almacenamiento temporal
-            self.run_command(&["config", "--local", "credential.helper", "store --file=.git/credentials"])?;
-            
-            // Guardar las credenciales temporalmente
-            let mut cmd = self.create_command(&["credential", "approve"]);
-            let mut child = cmd
-                .stdin(Stdio::piped())
-                .spawn()
-                .map_err(|e| GitError::CommandFailed(format!("Failed to spawn git credential command: {}", e)))?;
-            
-            if let Some(stdin) = child.stdin.as_mut() {
-                writeln!(stdin, "url={}", remote_url)
-                    .map_err(|e| GitError::CommandFailed(format!("Failed to write to git credential stdin: {}", e)))?;
-                writeln!(stdin, "username={}", token)
-                    .map_err(|e| GitError::CommandFailed(format!("Failed to write to git credential stdin: {}", e)))?;
-                writeln!(stdin, "password=x-oauth-basic")
-                    .map_err(|e| GitError::CommandFailed(format!("Failed to write to git credential stdin: {}", e)))?;
-            }
-            
-            let status = child.wait()
-                .map_err(|e| GitError::CommandFailed(format!("Failed to wait for git credential command: {}", e)))?;
-            
-            if !status.success() {
-                return Err(GitError::CommandFailed("Failed to store credentials".to_string()));
+                if !matches!(token.source, auth::TokenSource::EnvVar(_)) {
+                    println!("{}", t!("auth.existing_login", source = token.source.describe()));
+                    if let auth::TokenSource::GhCli(_) = token.source {
+                        println!("{}", t!("auth.gh_setup_git_hint"));
+                    }
+                    return Ok(());
+                }
+
+                // Configure the credentials helper to store in memory (cache)
+                self.run_command(&["config", "--local", "credential.helper", "cache"])?;
+                
+                // Configure cache timeout (default 15 minutes)
+                self.run_command(&["config", "--local", "credential.helper", "cache --timeout=3600"])?;
+                
+                self.store_credentials(remote_url, &token.value)?;
+                println!("{}", t!("auth.helper_configured"));
             }
-            
-            println!("✅ Configured secure credential helper");
-        } else if remote_url.starts_with("git@") {
-            println!("ℹ️  Using SSH authentication (no token needed)");
-        } else {
-            println!("ℹ️  Remote already configured or using non-HTTPS protocol");
+            auth::RemoteKind::Ssh { .. } => ssh::preflight(remote_url),
+            auth::RemoteKind::OtherHost { host } => println!("{}", t!("auth.other_host", host = host)),
+            auth::RemoteKind::Local => println!("{}", t!("auth.local_remote")),
         }
 
         Ok(())
     }
 }
 
-impl fmt::Display for GitError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            GitError::NoChanges => write!(f, "No changes to commit"),
-            GitError::NoCommitMessage => write!(f, "No commit message provided"),
-            GitError::CommandFailed(msg) => write!(f, "Command failed: {}", msg),
-            GitError::NoToken => write!(f, "No GitHub token found"),
-            GitError::NoInternet => write!(f, "No internet connection"),
-            GitError::Other(msg) => write!(f, "{}", msg),
-        }
-    }
-}
-
 // ============================================================================
 // UI HELPERS
 // ============================================================================
@@ -460,31 +503,38 @@ impl UI {
     }
 
     fn prompt_yes_no(question: &str) -> bool {
-        print!("❓ {} (y/n): ", question);
+        print!("❓ {} {}: ", question, t!("prompt.yes_no_suffix"));
         if let Err(e) = io::stdout().flush() {
-            eprintln!("Error flushing stdout: {}", e);
+            eprintln!("{}", t!("io.flush_failed", error = e));
             return false;
         }
         
         let mut response = String::new();
         if let Err(e) = io::stdin().read_line(&mut response) {
-            eprintln!("Error reading input: {}", e);
+            eprintln!("{}", t!("io.read_failed", error = e));
             return false;
         }
         
-        matches!(response.trim().to_lowercase().as_str(), "y" | "yes")
+        UI::is_answer(&response, &t!("prompt.yes_answers"))
+    }
+
+    /// Whether `input` is one of the comma-separated `answers` from the
+    /// catalog, so each language accepts its own letters.
+    fn is_answer(input: &str, answers: &str) -> bool {
+        let input = input.trim().to_lowercase();
+        answers.split(',').any(|answer| answer == input)
     }
 
     fn prompt_input(prompt: &str) -> String {
         print!("✏️  {}: ", prompt);
         if let Err(e) = io::stdout().flush() {
-            eprintln!("Error flushing stdout: {}", e);
+            eprintln!("{}", t!("io.flush_failed", error = e));
             return String::new();
         }
         
         let mut input = String::new();
         if let Err(e) = io::stdin().read_line(&mut input) {
-            eprintln!("Error reading input: {}", e);
+            eprintln!("{}", t!("io.read_failed", error = e));
             return String::new();
         }
         
@@ -493,10 +543,7 @@ impl UI {
 
     fn wait_for_enter() -> bool {
         let mut input = String::new();
-        match io::stdin().read_line(&mut input) {
-            Ok(_) => true,
-            Err(_) => false
-        }
+        io::stdin().read_line(&mut input).is_ok()
     }
 }
 
@@ -504,15 +551,17 @@ impl UI {
 // STATUS DISPLAY
 // ============================================================================
 
-fn print_grouped_status(repo: &GitRepo, pathspec: &str) {
-    let output = match repo.create_command(&["status", "--porcelain=v1", "--", pathspec]).output() {
+/// `git status --porcelain` lines for `pathspec`, grouped by top-level
+/// folder (`.` for files at the root). Lines are kept verbatim, status
+/// columns included.
+fn group_status(repo: &GitRepo, pathspec: &str) -> BTreeMap<String, Vec<String>> {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let output = match repo.create_command(["status", "--porcelain=v1", "--", pathspec]).output() {
         Ok(o) => o,
-        Err(_) => return,
+        Err(_) => return groups,
     };
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
-
     for line in stdout.lines() {
         let trimmed = line.trim_start();
         if trimmed.is_empty() { continue; }
@@ -533,19 +582,23 @@ fn print_grouped_status(repo: &GitRepo, pathspec: &str) {
             .map(|idx| path_part[..idx].to_string())
             .unwrap_or_else(|| ".".to_string());
 
-        groups.entry(key).or_default().push(trimmed.to_string());
+        groups.entry(key).or_default().push(line.to_string());
     }
+    groups
+}
 
+fn print_grouped_status(repo: &GitRepo, pathspec: &str) {
+    let groups = group_status(repo, pathspec);
     if groups.is_empty() {
-        println!("{}", UI::center_text("🟢 No changes in current subpath"));
+        println!("{}", UI::center_text(&t!("status.no_changes_subpath")));
         return;
     }
 
     for (group, items) in groups {
-        let display_name = if group == "." { "(root)" } else { &group };
+        let display_name = if group == "." { t!("status.root_group") } else { group };
         println!("{}", UI::center_text(&format!("📁 {}", display_name)));
         for item in items {
-            println!("{}", item);
+            println!("{}", item.trim_start());
         }
         UI::print_separator();
     }
@@ -568,27 +621,36 @@ fn compute_pathspec(repo_root: &Path, current: &Path) -> String {
 
 fn stage_and_commit(repo: &GitRepo, pathspec: &str) -> Result<()> {
     UI::print_separator();
-    println!("{}", UI::center_text("📄 Changes to be staged:"));
+    println!("{}", UI::center_text(&t!("stage.header")));
     print_grouped_status(repo, pathspec);
 
     if !repo.has_changes(Some(pathspec)) {
-        println!("{}", UI::center_text("🟢 No changes to add in the current folder"));
+        println!("{}", UI::center_text(&t!("stage.no_changes")));
         return Err(GitError::NoChanges);
     }
 
-    // Ask for confirmation before staging
-    println!("\n{}", UI::center_text("Press Enter to stage these changes, or Ctrl+C to cancel..."));
-    let mut input = String::new();
-    if std::io::stdin().read_line(&mut input).is_err() {
-        println!("\n{}", UI::center_text("❌ Operation cancelled"));
-        return Err(GitError::CommandFailed("User cancelled the operation".into()));
+    // Ask for confirmation before staging; 'd' reviews the diff first
+    loop {
+        println!("\n{}", UI::center_text(&t!("stage.confirm")));
+        let mut input = String::new();
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("\n{}", UI::center_text(&t!("stage.cancelled")));
+            return Err(GitError::Cancelled(t!("cancel.operation")));
+        }
+        if !input.trim().eq_ignore_ascii_case("d") {
+            break;
+        }
+        diff::view(repo, pathspec, diff::Source::Unstaged)?;
     }
 
+    // Large binaries belong in LFS; catch them before they're in history
+    lfs::check_large_files(repo, &[pathspec])?;
+
     // Stage changes
     // Use -- to prevent pathspec from being interpreted as an option
-    println!("\n{}", UI::center_text("⏳ Staging changes..."));
+    println!("\n{}", UI::center_text(&t!("stage.staging")));
     repo.run_command(&["add", "--", pathspec])?;
-    println!("{}", UI::center_text("✅ Changes added"));
+    println!("{}", UI::center_text(&t!("stage.added")));
 
     // Verify staged changes exist
     let has_staged = Command::new("git")
@@ -604,32 +666,50 @@ fn stage_and_commit(repo: &GitRepo, pathspec: &str) -> Result<()> {
         .unwrap_or(false);
 
     if !has_staged {
-        println!("{}", UI::center_text("ℹ️  There's nothing to commit"));
-        println!("{}", UI::center_text("   All changes are already committed"));
+        println!("{}", UI::center_text(&t!("stage.nothing_to_commit")));
+        println!("{}", UI::center_text(&t!("stage.all_committed")));
         return Err(GitError::NoChanges);
     }
 
+    // Project checks from .syncgit.toml
+    tasks::before_commit(repo, pathspec)?;
+
     // Show staged changes
     UI::print_separator();
-    println!("{}", UI::center_text("📝 Staged changes to be committed:"));
+    println!("{}", UI::center_text(&t!("commit.staged_header")));
     repo.run_command(&["diff", "--cached", "--stat"])?;
     
-    // Ask for confirmation before committing
-    println!("\n{}", UI::center_text("Press Enter to commit these changes, or any other key to cancel"));
-    let mut input = String::new();
-    if std::io::stdin().read_line(&mut input).is_err() || 
-       !input.trim().is_empty() {
-        println!("\n{}", UI::center_text("❌ Commit cancelled"));
-        return Err(GitError::CommandFailed("User cancelled the commit".into()));
+    // Ask for confirmation before committing; 'd' reviews the diff first
+    loop {
+        println!("\n{}", UI::center_text(&t!("commit.confirm")));
+        let mut input = String::new();
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("\n{}", UI::center_text(&t!("commit.cancelled")));
+            return Err(GitError::Cancelled(t!("cancel.commit")));
+        }
+        match input.trim() {
+            "" => break,
+            d if d.eq_ignore_ascii_case("d") => diff::view(repo, ".", diff::Source::Staged)?,
+            _ => {
+                println!("\n{}", UI::center_text(&t!("commit.cancelled")));
+                return Err(GitError::Cancelled(t!("cancel.commit")));
+            }
+        }
     }
 
     UI::print_separator();
-    let message = UI::prompt_input("Enter commit message (or leave empty to cancel)");
+    // Right author, and anyone from the team roster who worked on it
+    identity::check(repo)?;
+    let mut message = UI::prompt_input(&t!("commit.message_prompt"));
 
     if message.trim().is_empty() {
-        println!("\n{}", UI::center_text("❌ Commit cancelled - no message provided"));
+        println!("\n{}", UI::center_text(&t!("commit.no_message")));
         return Err(GitError::NoCommitMessage);
     }
+    let trailers = identity::co_author_trailers(repo)?;
+    if !trailers.is_empty() {
+        message = format!("{}\n\n{}", message.trim_end(), trailers.join("\n"));
+    }
 
     // Use -- to prevent the message from being interpreted as an option
     repo.run_command(&["commit", "-m", &message, "--"])?;
@@ -642,39 +722,23 @@ fn check_git_conflicts(repo: &GitRepo) -> Result<()> {
     // Check for merge conflicts
     let has_conflicts = Command::new("git")
         .arg("-C").arg(&repo.root)
-        .args(&["diff", "--name-only", "--diff-filter=U"])
+        .args(["diff", "--name-only", "--diff-filter=U"])
         .output()
         .map(|o| !o.stdout.is_empty())
-        .map_err(|e| GitError::CommandFailed(format!("Failed to check for merge conflicts: {}", e)))?;
+        .map_err(|e| GitError::io(t!("error.check_conflicts"), e))?;
 
     if has_conflicts {
-        return Err(GitError::CommandFailed(
-"You have unresolved conflicts. Please resolve them before continuing.".into()
-        ));
+        return Err(GitError::Conflict(Failure::new(t!("conflicts.unresolved"))));
     }
 
     // Check if there's a merge in progress
-    let merge_head_exists = repo.root.join(".git/MERGE_HEAD").exists();
+    let merge_head_exists = repo.git_path("MERGE_HEAD").exists();
     if merge_head_exists {
-        return Err(GitError::CommandFailed(
-            "Hay un merge en progreso. Por favor, completa o aborta el merge antes de continuar.".into()
-        ));
+        return Err(GitError::Conflict(Failure::new(t!("conflicts.merge_in_progress"))));
     }
 
-    // Verificar si hay stash pendiente
-    let has_stash = Command::new("git")
-        .arg("-C").arg(&repo.root)
-        .args(&["stash", "list"])
-        .output()
-        .map(|o| !o.stdout.is_empty())
-        .map_err(|e| GitError::CommandFailed(format!("Failed to check for stashed changes: {}", e)))?;
-
-    if has_stash {
-        println!("{}", UI::center_text("⚠️  Warning: You have stashed changes"));
-        if !UI::prompt_yes_no("Do you want to continue anyway?") {
-            return Err(GitError::CommandFailed("Operation cancelled by user".into()));
-        }
-    }
+    // Stashes left behind by an interrupted sync hold work that isn't anywhere else
+    stash::check_leftovers(repo)?;
 
     Ok(())
 }
@@ -682,7 +746,7 @@ fn check_git_conflicts(repo: &GitRepo) -> Result<()> {
 fn handle_pending_pushes(repo: &GitRepo) -> Result<()> {
     // First check for any conflicts or problematic states
     if let Err(e) = check_git_conflicts(repo) {
-        println!("\n{}", UI::center_text(" Verification error:"));
+        println!("\n{}", UI::center_text(&t!("pending.verification_error")));
         println!("{}\n", UI::center_text(&e.to_string()));
         return Err(e);
     }
@@ -690,41 +754,44 @@ fn handle_pending_pushes(repo: &GitRepo) -> Result<()> {
     let (ahead, _) = repo.get_ahead_behind_count();
     
     if ahead == 0 {
-        println!("{}", UI::center_text(" No pending commits to push"));
+        println!("{}", UI::center_text(&t!("pending.none")));
         UI::print_separator();
         return Ok(());
     }
 
-    println!("{}", UI::center_text(" WARNING: You have commits that need to be pushed"));
-    println!("{}", UI::center_text(&format!("   {} commits ahead of remote repository", ahead)));
-    println!("{}", UI::center_text("   This could cause conflicts or duplicate commits."));
+    println!("{}", UI::center_text(&t!("pending.warning")));
+    println!("{}", UI::center_text(&tn!("pending.ahead", ahead)));
+    println!("{}", UI::center_text(&t!("pending.risk")));
     UI::print_separator();
 
-    if !UI::prompt_yes_no("Do you want to push the existing commits first?") {
-        println!("{}", UI::center_text("⚠️  Continuing with the new commit without pushing changes..."));
+    if !UI::prompt_yes_no(&t!("pending.push_first")) {
+        println!("{}", UI::center_text(&t!("pending.continue_without")));
         UI::print_separator();
         return Ok(());
     }
 
-    println!("{}", UI::center_text("⬆️  Pushing existing commits..."));
+    println!("{}", UI::center_text(&t!("pending.pushing")));
     
-    if get_github_token().is_none() {
-        println!("{}", UI::center_text("❌ Cannot push: GitHub token not found"));
-        println!("{}", UI::center_text("   Please configure your GitHub token"));
-        return Err(GitError::NoToken);
-    }
-    
-    if !check_internet_connection() {
-        println!("{}", UI::center_text("⚠️  No internet connection. Cannot push existing commits."));
-        println!("{}", UI::center_text("    Please resolve this before making new commits."));
-        return Err(GitError::NoInternet);
+    match auth::ensure_can_push(repo) {
+        Ok(()) => {}
+        Err(GitError::NoToken) => {
+            println!("{}", UI::center_text(&t!("pending.no_token")));
+            println!("{}", UI::center_text(&t!("pending.configure_token")));
+            return Err(GitError::NoToken);
+        }
+        Err(GitError::NoInternet) => {
+            println!("{}", UI::center_text(&t!("pending.no_internet")));
+            queue::queue_for_later(repo);
+            UI::print_separator();
+            return Ok(());
+        }
+        Err(e) => return Err(e),
     }
 
     repo.configure_auth_remote()?;
-    // Ensure push doesn't receive any unwanted parameters
-    repo.run_command(&["push", "--"])?;
+    push::push_current_branch(repo, false)?;
     
-    println!("{}", UI::center_text("✅ Existing commits pushed successfully!"));
+    println!("{}", UI::center_text(&t!("pending.pushed")));
     UI::print_separator();
     
     Ok(())
@@ -734,7 +801,7 @@ fn handle_pending_pushes(repo: &GitRepo) -> Result<()> {
 // REPOSITORY INITIALIZATION
 // ============================================================================
 
-fn initialize_git_repo(path: &Path) -> Result<GitRepo> {
+fn initialize_git_repo(path: &Path, options: &InitOptions) -> Result<GitRepo> {
     // Initialize git repository with 'main' as default branch
     let output = Command::new("git")
         .arg("init")
@@ -742,10 +809,11 @@ fn initialize_git_repo(path: &Path) -> Result<GitRepo> {
         .arg("main")
         .current_dir(path)
         .output()
-        .map_err(|e| GitError::Other(format!("Failed to run git init: {}", e)))?;
+        .map_err(|e| GitError::io(t!("error.git_init"), e))?;
 
     if !output.status.success() {
-        return Err(GitError::Other("Failed to initialize Git repository".to_string()));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GitError::from_command(&["init", "-b", "main"], output.status, &stderr));
     }
 
     // Create initial commit
@@ -765,20 +833,13 @@ fn initialize_git_repo(path: &Path) -> Result<GitRepo> {
         if branch_name == "master" {
             // Rename master to main only if it exists
             repo.run_command(&["branch", "-m", "master", "main"])
-                .map_err(|e| GitError::Other(format!("Failed to rename branch to main: {}", e)))?;
+                .map_err(|e| GitError::Other(t!("error.rename_branch", error = e)))?;
         }
     }
     // If we can't determine the branch, that's okay - git init -b main should have created main
 
-    // Create .gitignore if it doesn't exist
-    let gitignore_path = path.join(".gitignore");
-    if !gitignore_path.exists() {
-        let default_gitignore = "# Default .gitignore for new repositories\n\
-# OS generated files\n.DS_Store\n.DS_Store?\n._*\n.Spotlight-V100\n.Trashes\nehthumbs.db\nThumbs.db\n\n# Build artifacts\ntarget/\n**/*.rs.bk\nCargo.lock\n\n# Editor directories and files\n.idea\n.vscode\n*.swp\n*.swo\n*~";
-        
-        fs::write(&gitignore_path, default_gitignore)
-            .map_err(|e| GitError::Other(format!("Failed to create .gitignore: {}", e)))?;
-    }
+    // Create or extend .gitignore from the project's language templates
+    gitignore::write_gitignore(path, &options.gitignore)?;
     
     // Add all files and create initial commit
     repo.run_command(&["add", "--all"])?;
@@ -786,255 +847,28 @@ fn initialize_git_repo(path: &Path) -> Result<GitRepo> {
     // Check if there are any changes to commit
     if repo.has_changes(None) {
         repo.run_command(&["commit", "-m", "Initial commit"])?;
-        println!("\n✅ Created initial commit");
+        println!("\n{}", t!("init.created_commit"));
     } else {
-        println!("\nℹ️  No files to commit in the initial repository");
+        println!("\n{}", t!("init.nothing_to_commit"));
     }
 
     Ok(repo)
 }
 
-fn create_github_repo(repo: &GitRepo) -> Result<()> {
-    if !check_internet_connection() {
-        return Err(GitError::NoInternet);
-    }
-
-    let token = get_github_token().ok_or(GitError::NoToken)?;
-    let default_repo_name = repo.root.file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("new-repo")
-        .to_string();
-    
-    // Ask for repository name with default
-    let repo_name = loop {
-        let input_name = UI::prompt_input(&format!("Enter GitHub repository name [{}]: ", default_repo_name));
-        let repo_name = if input_name.trim().is_empty() {
-            default_repo_name.clone()
-        } else {
-            input_name.trim().to_string()
-        };
-        
-        // Validate repository name (GitHub requirements: alphanumeric, -, _, and . only)
-        if repo_name.is_empty() {
-            println!("{}", UI::center_text("❌ Repository name cannot be empty. Please try again."));
-            continue;
-        }
-        if !repo_name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.') {
-            println!("{}", UI::center_text("❌ Repository name can only contain alphanumeric characters, hyphens, underscores, and dots. Please try again."));
-            continue;
-        }
-        break repo_name;
-    };
-
-    // Ask for description
-    let description = UI::prompt_input("Enter repository description (optional): ");
-    
-    // Ask if should be private
-    let is_private = UI::prompt_yes_no("Should this repository be private?");
-    
-    println!("\n{}", UI::center_text("🔄 Creating GitHub repository..."));
-    
-    // Create repository using GitHub API
-    let client = reqwest::blocking::Client::new();
-    let mut request_body = serde_json::json!({
-        "name": repo_name,
-        "private": is_private,
-    });
-    
-    if !description.trim().is_empty() {
-        request_body["description"] = serde_json::Value::String(description.trim().to_string());
-    }
-    
-    let response = client
-        .post("https://api.github.com/user/repos")
-        .header("User-Agent", "syncgit")
-        .header("Authorization", format!("Bearer {}", token))
-        .header("Accept", "application/vnd.github.v3+json")
-        .json(&request_body)
-        .send()
-        .map_err(|e| GitError::Other(format!("Failed to send request to GitHub API: {}", e)))?;
-    
-    if !response.status().is_success() {
-        let status = response.status();
-        let error_msg = response.text().unwrap_or_else(|_| "Unknown error".to_string());
-        
-        // Check if repository already exists (422 status with "already exists" message)
-        if status == 422 && error_msg.contains("already exists") {
-            println!("\n{}", UI::center_text(&format!("⚠️  Repository '{}' already exists on GitHub", repo_name)));
-            if UI::prompt_yes_no("Do you want to use the existing repository and push to it?") {
-                // Get GitHub username from API
-                let username = client
-                    .get("https://api.github.com/user")
-                    .header("User-Agent", "syncgit")
-                    .header("Authorization", format!("Bearer {}", token))
-                    .header("Accept", "application/vnd.github.v3+json")
-                    .send()
-                    .and_then(|r| r.json::<serde_json::Value>())
-                    .ok()
-                    .and_then(|json| json["login"].as_str().map(|s| s.to_string()))
-                    .unwrap_or_else(|| "unknown".to_string());
-                
-                // Get the existing repository URL
-                let existing_repo_url = format!("https://github.com/{}/{}", username, repo_name);
-                
-                // Add remote origin if it doesn't exist
-                if !repo.has_remote() {
-                    repo.run_command(&["remote", "add", "origin", &format!("{}.git", existing_repo_url)])?;
-                } else {
-                    // Update existing remote
-                    repo.run_command(&["remote", "set-url", "origin", &format!("{}.git", existing_repo_url)])?;
-                }
-                
-                // Get current branch and push
-                let branch = repo.run_command_with_output(&["rev-parse", "--abbrev-ref", "HEAD"])
-                    .map(|b| b.trim().to_string())
-                    .unwrap_or_else(|_| "main".to_string());
-                
-                println!("\n{}", UI::center_text("🚀 Pushing to existing GitHub repository..."));
-                repo.configure_auth_remote()?;
-                repo.run_command(&["push", "-u", "origin", &branch])?;
-                println!("\n{}", UI::center_text(&format!("✅ Successfully pushed to GitHub repository: {}", existing_repo_url)));
-                return Ok(());
-            } else {
-                return Err(GitError::Other("Repository creation cancelled by user".to_string()));
-            }
-        }
-        
-        // Provide helpful error messages for common issues
-        let detailed_error = if status == 401 {
-            format!("Authentication failed. Please check your GitHub token. Error: {}", error_msg)
-        } else if status == 422 {
-            format!("Invalid repository name or repository already exists. Error: {}", error_msg)
-        } else if status == 403 {
-            format!("Permission denied. Your token may not have 'repo' scope. Error: {}", error_msg)
-        } else {
-            format!("GitHub API error (status {}): {}", status, error_msg)
-        };
-        
-        return Err(GitError::Other(detailed_error));
-    }
-    
-    let response_json: serde_json::Value = response.json()
-        .map_err(|e| GitError::Other(format!("Failed to parse GitHub response: {}", e)))?;
-    
-    let repo_url = response_json["html_url"]
-        .as_str()
-        .ok_or_else(|| GitError::Other("Failed to get repository URL from GitHub response".to_string()))?;
-    
-    // Get current branch name (defaults to main)
-    let branch = repo.run_command_with_output(&["rev-parse", "--abbrev-ref", "HEAD"])
-        .map(|b| b.trim().to_string())
-        .unwrap_or_else(|_| "main".to_string());
-    
-    // Get the clone URL (SSH or HTTPS) from the response
-    let clone_url = response_json["clone_url"]
-        .as_str()
-        .ok_or_else(|| GitError::Other("Failed to get clone URL from GitHub response".to_string()))?;
-    
-    // Add remote origin
-    if let Err(e) = repo.run_command(&["remote", "add", "origin", clone_url]) {
-        if let Ok(output) = repo.run_command_with_output(&["remote", "get-url", "origin"]) {
-            println!("ℹ️  Remote 'origin' already exists: {}", output.trim());
-            if !UI::prompt_yes_no("Do you want to update the existing remote URL?") {
-                println!("\n⚠️  Using existing remote. You may need to manually set up tracking.");
-                return Ok(());
-            }
-            repo.run_command(&["remote", "set-url", "origin", clone_url])?;
-        } else {
-            return Err(e);
-        }
-    }
-    
-    // Ask for initial commit message if there are no commits yet
-    let has_commits = repo.run_command_with_output(&["rev-list", "--count", "--all"])
-        .map(|output| output.trim() != "0")  // If output is not "0", then there are commits
-        .unwrap_or(false);
-        
-    if !has_commits {
-        let commit_message = UI::prompt_input("Enter initial commit message (or press Enter for 'Initial commit'): ");
-        let commit_message = if commit_message.trim().is_empty() {
-            "Initial commit"
-        } else {
-            commit_message.trim()
-        };
-        
-        // Stage all files
-        repo.run_command(&["add", "."])?;
-        
-        // Create initial commit
-        repo.run_command(&["commit", "-m", commit_message])?;
-        println!("\n✅ Created initial commit with message: {}", commit_message);
-    }
-    
-    println!("\n🚀 Pushing to GitHub repository...");
-    
-    // First, try to push with -u (which sets upstream)
-    match repo.run_command(&["push", "-u", "origin", &branch]) {
-        Ok(_) => {
-            println!("\n✅ Successfully pushed to GitHub repository: {}", repo_url);
-            Ok(())
-        },
-        Err(e) => {
-            println!("\n⚠️  Failed to push to remote repository: {}", e);
-            
-            // Try to fetch first in case the remote has changes
-            println!("\n🔄 Fetching from remote...");
-            if let Err(e) = repo.run_command(&["fetch"]) {
-                println!("⚠️  Failed to fetch from remote: {}", e);
-            }
-            
-            // Try to set up tracking with a more robust approach
-            println!("\n🔗 Setting up tracking...");
-            
-            // Create commands with proper references to branch
-            let branch_ref = branch.as_str();
-            let setup_commands = [
-                ("branch", vec!["--set-upstream-to".to_string(), format!("origin/{}", branch_ref), branch_ref.to_string()]),
-                ("push", vec!["-u".to_string(), "origin".to_string(), branch_ref.to_string()]),
-            ];
-            
-            for (cmd, args) in setup_commands.iter() {
-                let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-                if let Err(e) = repo.run_command(&args_refs) {
-                    println!("⚠️  Command failed: git {} {}", cmd, args.join(" "));
-                    println!("   Error: {}", e);
-                }
-            }
-            
-            // Final attempt to push
-            if UI::prompt_yes_no("Would you like to try pushing again?") {
-                if let Err(e) = repo.run_command(&["push"]) {
-                    println!("\n❌ Final push attempt failed: {}", e);
-                    println!("\nYou may need to manually set up tracking with these commands:");
-                    println!("  git branch --set-upstream-to=origin/{} {}", branch, branch);
-                    println!("  git push -u origin {}", branch);
-                    return Err(GitError::Other("Failed to push to remote repository".to_string()));
-                } else {
-                    println!("\n✅ Successfully pushed to GitHub repository!");
-                    return Ok(());
-                }
-            }
-            
-            Err(GitError::Other("Push to remote repository was not completed".to_string()))
-        }
-    }
-}
-
 // ============================================================================
 // UTILITY FUNCTIONS
 // ============================================================================
 
-#[allow(dead_code)]
 fn print_token_setup_instructions() {
-    println!("{}", UI::center_text("❌ No GitHub token found in the environment"));
-    println!("{}", UI::center_text("   Please set it up before continuing:"));
+    println!("{}", UI::center_text(&t!("auth.setup.title")));
+    println!("{}", UI::center_text(&t!("auth.setup.intro")));
     println!();
-    println!("   1. Create a Personal Access Token in GitHub with 'repo' scope");
-    println!("   2. Add it to your shell configuration (e.g., ~/.zshrc):");
+    println!("{}", t!("auth.setup.step1"));
+    println!("{}", t!("auth.setup.step2"));
     println!("      export GITHUB_TOKEN=your_token_here");
-    println!("   3. Reload your shell: source ~/.zshrc");
+    println!("{}", t!("auth.setup.step3"));
     println!();
-    println!("   Alternatively, you can use SSH for authentication instead.");
+    println!("{}", t!("auth.setup.ssh"));
 }
 
 // ============================================================================
@@ -1045,55 +879,49 @@ fn check_sync_status(repo: &GitRepo) -> Result<()> {
     let (ahead, behind) = repo.get_ahead_behind_count();
     
     if ahead > 0 {
-        println!("\n{}", UI::center_text("⚠️  You have unpushed changes:"));
-        println!("{} commits ahead of remote", ahead);
+        println!("\n{}", UI::center_text(&t!("sync.unpushed")));
+        println!("{}", tn!("sync.commits_ahead", ahead));
         
-        if check_internet_connection() {
-            println!("\n{}", UI::center_text("Press Enter to push changes, or Ctrl+C to cancel"));
+        if auth::push_remote_reachable(repo) {
+            println!("\n{}", UI::center_text(&t!("sync.push_confirm")));
             if UI::wait_for_enter() {
                 repo.configure_auth_remote()?;
-                repo.run_command(&["push", "--"])?;
-                println!("{}", UI::center_text("✅ Changes pushed successfully!"));
+                push::push_current_branch(repo, false)?;
+                println!("{}", UI::center_text(&t!("push.success")));
             }
         } else {
-            println!("{}", UI::center_text("ℹ️  No internet connection. Changes will remain local for now."));
+            println!("{}", UI::center_text(&t!("sync.offline_local")));
+            queue::queue_for_later(repo);
         }
     }
     
     if behind > 0 {
-        println!("\n{}: {} commits behind remote", 
-            UI::center_text("⚠️  Your local branch is behind"), 
-            behind
-        );
+        println!("\n{}", UI::center_text(&tn!("sync.behind", behind)));
         
         if check_internet_connection() {
-            println!("\n{}", UI::center_text(&format!("You have {} commits to sync from remote", behind)));
-            println!("{}", UI::center_text("Press Enter to view and sync these changes, or Ctrl+C to cancel"));
+            println!("\n{}", UI::center_text(&tn!("sync.to_sync", behind)));
+            println!("{}", UI::center_text(&t!("sync.view_confirm")));
             
             if !UI::wait_for_enter() {
-                println!("\n{}", UI::center_text("❌ Sync cancelled"));
+                println!("\n{}", UI::center_text(&t!("sync.cancelled")));
                 return Ok(());
             }
             
-            // Show what will be synced
-            if let Ok(output) = repo.run_command(&["log", "--oneline", "--graph", "--decorate", "--all", "-n", "5", "--no-merges", "--"]) {
-                println!("\n{}", UI::center_text("Latest changes to sync:"));
-                println!("{:?}", output);
-            }
+            // Fetch first so the preview shows what will actually be synced
+            repo.run_command(&["fetch", "origin"])?;
+            incoming::show(repo)?;
             
-            println!("\n{}", UI::center_text("Press Enter to confirm sync, or Ctrl+C to cancel"));
+            println!("\n{}", UI::center_text(&t!("sync.confirm")));
             if !UI::wait_for_enter() {
-                println!("\n{}", UI::center_text("❌ Sync cancelled"));
+                println!("\n{}", UI::center_text(&t!("sync.cancelled")));
                 return Ok(());
             }
             
-            println!("\n{}", UI::center_text("🔄 Syncing changes..."));
+            println!("\n{}", UI::center_text(&t!("sync.syncing")));
             
-            // First fetch the latest changes
-            repo.run_command(&["fetch", "origin"])?;
-            
-            // Stash any local changes temporarily
-            let has_stash = repo.run_command(&["stash", "push", "--include-untracked"]).is_ok();
+            // Stash any local changes temporarily, marked so that they can be
+            // found with 'syncgit stash' if the sync is interrupted
+            let has_stash = stash::auto_stash(repo, &t!("stash.auto_message", branch = repo.get_branch()))?;
             
             // Get current branch's upstream
             let upstream = match repo.run_command_with_output(&["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{u}"]) {
@@ -1106,154 +934,227 @@ fn check_sync_status(repo: &GitRepo) -> Result<()> {
             
             // If we had stashed changes, apply them back
             if has_stash {
-                repo.run_command(&["stash", "pop"])?;
+                stash::restore_auto_stash(repo)?;
             }
             
-            println!("✅ Successfully synced with remote!");
+            println!("{}", t!("sync.done"));
         } else {
-            println!("\n{}", UI::center_text("ℹ️  No internet connection. Working with local version for now."));
+            println!("\n{}", UI::center_text(&t!("sync.offline_working")));
         }
     }
     
     if ahead == 0 && behind == 0 {
-        println!("\n{}", UI::center_text("✅ Your repository is in sync with remote"));
+        println!("\n{}", UI::center_text(&t!("sync.in_sync")));
     }
     
     Ok(())
 }
 
-fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+fn main() {
+    let code = match run() {
+        Ok(()) => EXIT_OK,
+        Err(e) => {
+            eprintln!("\n❌ {}", e);
+            e.exit_code()
+        }
+    };
+    std::process::exit(code);
+}
+
+/// Initializes a repository in `path` and offers to publish it on GitHub.
+/// Returns `None` when the repository was pushed and there is nothing left
+/// to do.
+fn bootstrap_repo(path: &Path, options: &InitOptions) -> Result<Option<GitRepo>> {
+    let new_repo = initialize_git_repo(path, options)?;
+
+    // Ask if user wants to create GitHub repository
+    UI::print_separator();
+    if UI::prompt_yes_no(&t!("github.create_prompt")) {
+        if let Err(e) = github::create_github_repo(&new_repo, &options.scaffold) {
+            println!("\n{}: {}", UI::center_text(&t!("main.warning")), e);
+            println!("{}", UI::center_text(&t!("github.create_later")));
+            UI::print_separator();
+        } else {
+            println!("\n{}", UI::center_text(&t!("github.created")));
+            UI::print_separator();
+            return Ok(None);
+        }
+    } else {
+        let project = scaffold::Project { name: &new_repo.name, description: "" };
+        scaffold::scaffold(&new_repo, &project, &options.scaffold)?;
+    }
+
+    Ok(Some(new_repo))
+}
+
+fn run() -> Result<()> {
+    let command = cli::parse(env::args().skip(1))?;
+
     // Get current directory
     let current_dir = env::current_dir()
-        .map_err(|e| GitError::Other(format!("Failed to get current directory: {}", e)))?;
+        .map_err(|e| GitError::io(t!("error.current_dir"), e))?;
+
+    match command {
+        cli::Command::Help => {
+            cli::print_help();
+            return Ok(());
+        }
+        cli::Command::Init(options) => {
+            bootstrap_repo(&current_dir, &options)?;
+            return Ok(());
+        }
+        cli::Command::Auth(cli::AuthCommand::Status) => return auth::print_status(),
+        cli::Command::Auth(cli::AuthCommand::Ssh(target)) => return ssh::print_diagnosis(target.as_deref()),
+        cli::Command::Bundle(cli::BundleCommand::Export(options)) => return bundle::export(&options),
+        cli::Command::Bundle(cli::BundleCommand::Import(file)) => return bundle::import(&file),
+        cli::Command::Stash(cli::StashCommand::Manage) => return stash::manage(),
+        cli::Command::Stash(cli::StashCommand::List) => return stash::print_status(),
+        cli::Command::Stash(cli::StashCommand::Show(index)) => return stash::show(index),
+        cli::Command::Stash(cli::StashCommand::Apply(index)) => return stash::apply(index, false),
+        cli::Command::Stash(cli::StashCommand::Pop(index)) => return stash::apply(index, true),
+        cli::Command::Stash(cli::StashCommand::Drop(index)) => return stash::drop(index),
+        cli::Command::Worktree(cli::WorktreeCommand::List) => return worktree::print_status(),
+        cli::Command::Worktree(cli::WorktreeCommand::Add { branch, path }) => return worktree::add(&branch, path.as_deref()),
+        cli::Command::Worktree(cli::WorktreeCommand::Remove(target)) => return worktree::remove(&target),
+        cli::Command::Release(options) => return release::release(&options),
+        cli::Command::Changelog(options) => return changelog::changelog(&options),
+        cli::Command::Identity(cli::IdentityCommand::Status) => return identity::print_status(),
+        cli::Command::Identity(cli::IdentityCommand::Use(profile)) => return identity::use_profile(&profile),
+        cli::Command::Identity(cli::IdentityCommand::Add(profile)) => return identity::add_profile(&profile),
+        cli::Command::Flush => return queue::flush(),
+        cli::Command::Queue => return queue::print_status(),
+        cli::Command::Tui if io::stdout().is_terminal() => {
+            let repo = GitRepo::from_current_dir()?;
+            let pathspec = compute_pathspec(&repo.root, &current_dir);
+            return tui::run(&repo, pathspec);
+        }
+        cli::Command::Diff(source) => {
+            let repo = GitRepo::from_current_dir()?;
+            return diff::view(&repo, &compute_pathspec(&repo.root, &current_dir), source);
+        }
+        // Without a terminal to draw on, fall back to the line-oriented flow
+        cli::Command::Tui | cli::Command::Sync => queue::flush_on_startup(),
+    }
 
     // Try to find existing git repo or initialize a new one
-    let repo = match GitRepo::find_from_path(&current_dir) {
-        Some(repo) => repo,
-        None => {
-            println!("No Git repository found in current directory or its parents.");
-            println!("Do you want to initialize a new Git repository here? (y/n)");
-            
-            let mut input = String::new();
-            io::stdin().read_line(&mut input)
-                .map_err(|e| GitError::Other(format!("Failed to read input: {}", e)))?;
+    let repo = match GitRepo::lookup(&current_dir) {
+        Lookup::Found(repo) => repo,
+        Lookup::BrokenLink(path) => {
+            return Err(GitError::Usage(t!("worktree.broken_link", path = path.display())));
+        }
+        Lookup::NotFound => {
+            println!("{}", t!("init.no_repo_found"));
             
-            if input.trim().to_lowercase() == "y" {
-                let new_repo = initialize_git_repo(&current_dir)?;
-                
-                // Ask if user wants to create GitHub repository
-                UI::print_separator();
-                if UI::prompt_yes_no("Do you want to create a GitHub repository and push to it?") {
-                    if let Err(e) = create_github_repo(&new_repo) {
-                        println!("\n{}: {}", UI::center_text("⚠️  Warning"), e);
-                        println!("{}", UI::center_text("You can create the repository manually later."));
-                        UI::print_separator();
-                    } else {
-                        println!("\n{}", UI::center_text("✅ Repository created and pushed to GitHub!"));
-                        UI::print_separator();
-                        return Ok(());
-                    }
-                }
-                
-                new_repo
-            } else {
-                println!("Exiting...");
+            if !UI::prompt_yes_no(&t!("init.ask_init")) {
+                println!("{}", t!("init.exiting"));
                 return Ok(());
             }
+
+            match bootstrap_repo(&current_dir, &InitOptions::default())? {
+                Some(new_repo) => new_repo,
+                None => return Ok(()),
+            }
         }
     };
 
+    // Inside a submodule, sync from the superproject so its pointer to the
+    // submodule gets committed and pushed along with it
+    let (repo, submodule_root) = submodule::outermost(repo);
+    if submodule_root.is_some() {
+        println!("{}", UI::center_text(&t!("submodule.using_superproject", name = repo.name)));
+    }
+
     // Check sync status at startup
     if let Err(e) = check_sync_status(&repo) {
-        println!("\n{}: {}", UI::center_text("⚠️  Warning"), e);
+        println!("\n{}: {}", UI::center_text(&t!("main.warning")), e);
         // Continue execution even if sync check fails
     }
 
     UI::print_separator();
-    println!("{}", UI::center_text(&format!("📁 Repository root: {}", repo.name)));
+    println!("{}", UI::center_text(&t!("main.repo_root", name = repo.name)));
+    worktree::print_note(&repo);
     UI::print_separator();
 
-    let pathspec = compute_pathspec(&repo.root, &current_dir);
+    // Git can't take a pathspec inside a submodule, so take the whole one
+    let pathspec = compute_pathspec(&repo.root, submodule_root.as_deref().unwrap_or(&current_dir));
     let subpath_display = if pathspec == "." {
-        ". (repo root)".to_string()
+        t!("main.subpath_root")
     } else {
         pathspec.clone()
     };
-    println!("{}", UI::center_text(&format!("🧭 Subpath: {}", subpath_display)));
+    println!("{}", UI::center_text(&t!("main.subpath", path = subpath_display)));
     UI::print_separator();
 
     // Show status
-    println!("{}", UI::center_text("🔍 Repository status:"));
+    println!("{}", UI::center_text(&t!("main.status_header")));
     // Status is safe as it doesn't use user input
     repo.run_command(&["status", "--", "-sb"])?;
     UI::print_separator();
+    submodule::print_status(&repo);
+    lfs::check_setup(&repo);
 
     // Check pending pushes
-    println!("{}", UI::center_text("🔍 Checking for pending pushes..."));
+    println!("{}", UI::center_text(&t!("main.checking_pending")));
     handle_pending_pushes(&repo)?;
 
     // Pull only if remote exists
     if repo.has_remote() {
-        println!("{}", UI::center_text("⬇️  Pulling changes..."));
+        println!("{}", UI::center_text(&t!("main.pulling")));
         // Pull is safe as it doesn't directly use user input
-        if let Err(e) = repo.run_command(&["pull", "--"]) {
+        match repo.run_command(&["pull", "--"]) {
             // If pull fails due to no upstream, that's okay for new repos
-            let error_msg = e.to_string();
-            if !error_msg.contains("no upstream configured") && !error_msg.contains("no tracking information") {
-                return Err(Box::new(e) as Box<dyn std::error::Error>);
-            }
-            // Otherwise, just continue
+            Ok(()) | Err(GitError::MissingUpstream(_)) => {}
+            Err(e) => return Err(e),
         }
         UI::print_separator();
     } else {
-        println!("{}", UI::center_text("ℹ️  No remote configured. Skipping pull."));
+        println!("{}", UI::center_text(&t!("main.no_remote_skip_pull")));
         UI::print_separator();
     }
 
     // Check for changes
-    println!("{}", UI::center_text("📦 Checking local changes..."));
+    println!("{}", UI::center_text(&t!("main.checking_changes")));
     
     let all_changes = repo.has_changes(None);
     let current_changes = repo.has_changes(Some(&pathspec));
 
     if all_changes && !current_changes {
-        println!("{}", UI::center_text("ℹ️  No changes detected in the current folder"));
-        println!("{}", UI::center_text("   However, there are pending changes elsewhere in the repository."));
-        println!("{}", UI::center_text("   Tip: run this tool from the repo root or navigate to the folder with changes."));
+        println!("{}", UI::center_text(&t!("main.no_changes_here")));
+        println!("{}", UI::center_text(&t!("main.changes_elsewhere")));
+        println!("{}", UI::center_text(&t!("main.tip_root")));
         return Ok(());
     }
 
-    // Stage and commit
+    // Stage and commit, submodules first so the superproject records their new commits
+    submodule::commit_changes(&repo, &pathspec)?;
     stage_and_commit(&repo, &pathspec)?;
 
     // Only push if remote exists
     if repo.has_remote() {
         // Ask for confirmation before pushing
-        println!("\n{}", UI::center_text("⚠️  You're about to push your changes to the remote repository."));
-        println!("{}", UI::center_text("   Press Enter to confirm push, or Ctrl+C to cancel"));
+        println!("\n{}", UI::center_text(&t!("main.push_about")));
+        println!("{}", UI::center_text(&t!("main.push_confirm")));
         
         if !UI::wait_for_enter() {
-            println!("\n{}", UI::center_text("❌ Push cancelled"));
+            println!("\n{}", UI::center_text(&t!("main.push_cancelled")));
             return Ok(());
         }
         
-        println!("\n{}", UI::center_text("⬆️  Pushing changes..."));
+        println!("\n{}", UI::center_text(&t!("main.pushing")));
         
-        if !check_internet_connection() {
-            println!("{}", UI::center_text(MSG_NO_INTERNET_PUSH));
-            println!("{}", UI::center_text(MSG_RUN_PUSH_MANUALLY));
+        if !auth::push_remote_reachable(&repo) {
+            println!("{}", UI::center_text(&t!("push.no_internet")));
+            queue::queue_for_later(&repo);
             return Ok(());
         }
 
         repo.configure_auth_remote()?;
-
-        // Ensure push doesn't receive any unwanted parameters
-        repo.run_command(&["push", "--"])?;
-        println!("\n{}", UI::center_text("✅ Changes pushed successfully!"));
+        push::push_current_branch(&repo, false)?;
+        println!("\n{}", UI::center_text(&t!("push.success")));
     } else {
-        println!("\n{}", UI::center_text("ℹ️  No remote configured. Changes committed locally."));
-        if UI::prompt_yes_no("Do you want to create a GitHub repository and push to it?") {
-            create_github_repo(&repo)?;
+        println!("\n{}", UI::center_text(&t!("main.committed_locally")));
+        if UI::prompt_yes_no(&t!("github.create_prompt")) {
+            github::create_github_repo(&repo, &scaffold::Options::skip())?;
         }
     }
     
@@ -0,0 +1,379 @@
+use std::fmt;
+use std::process::Stdio;
+
+use crate::error::{Failure, GitError, Result};
+use crate::i18n::{t, tn};
+use crate::{lfs, queue, ssh, submodule, tasks, GitRepo, UI};
+
+// ============================================================================
+// PUSH REJECTIONS
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushRejection {
+    NonFastForward,
+    ProtectedBranch,
+    HookDeclined,
+    AuthFailed,
+    TooLarge,
+    Unknown,
+}
+
+impl PushRejection {
+    /// Classifies a failed `git push` from its stderr.
+    ///
+    /// Order matters: GitHub reports protected branches and oversized files
+    /// through the pre-receive hook, so those are checked before the generic
+    /// "hook declined" case.
+    pub fn classify(stderr: &str) -> Self {
+        let text = stderr.to_lowercase();
+        let any = |needles: &[&str]| needles.iter().any(|n| text.contains(n));
+
+        if any(&["protected branch", "gh006", "gh013", "repository rule violations", "not allowed to push code to protected", "not allowed to force push"]) {
+            PushRejection::ProtectedBranch
+        } else if any(&["gh001", "large files detected", "exceeds github's file size limit", "http 413", "pack exceeds maximum allowed size", "entity too large"]) {
+            PushRejection::TooLarge
+        } else if any(&["non-fast-forward", "(fetch first)", "(stale info)", "tip of your current branch is behind", "updates were rejected because the remote contains work"]) {
+            PushRejection::NonFastForward
+        } else if any(&["hook declined", "pre-receive hook", "update hook"]) {
+            PushRejection::HookDeclined
+        } else if any(&["authentication failed", "could not read username", "invalid username or password", "permission denied", "returned error: 403", "permission to", "publickey"]) {
+            PushRejection::AuthFailed
+        } else {
+            PushRejection::Unknown
+        }
+    }
+
+    fn explanation(&self) -> String {
+        match self {
+            PushRejection::NonFastForward => t!("push.explain.non_fast_forward"),
+            PushRejection::ProtectedBranch => t!("push.explain.protected"),
+            PushRejection::HookDeclined => t!("push.explain.hook"),
+            PushRejection::AuthFailed => t!("push.explain.auth"),
+            PushRejection::TooLarge => t!("push.explain.too_large"),
+            PushRejection::Unknown => t!("push.explain.unknown"),
+        }
+    }
+}
+
+impl fmt::Display for PushRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            PushRejection::NonFastForward => t!("push.reason.non_fast_forward"),
+            PushRejection::ProtectedBranch => t!("push.reason.protected"),
+            PushRejection::HookDeclined => t!("push.reason.hook"),
+            PushRejection::AuthFailed => t!("push.reason.auth"),
+            PushRejection::TooLarge => t!("push.reason.too_large"),
+            PushRejection::Unknown => t!("push.reason.unknown"),
+        };
+        write!(f, "{}", label)
+    }
+}
+
+// ============================================================================
+// PUSH TARGET
+// ============================================================================
+
+pub struct PushTarget {
+    pub remote: String,
+    pub local_branch: String,
+    pub remote_branch: String,
+}
+
+impl PushTarget {
+    /// Resolves where the current branch pushes to: its upstream if one is
+    /// configured, otherwise a branch of the same name on `origin`.
+    pub fn resolve(repo: &GitRepo) -> Result<Self> {
+        let local_branch = repo.run_command_with_output(&["symbolic-ref", "--short", "HEAD"])
+            .map_err(|_| GitError::Other(t!("error.detached_head")))?;
+        Ok(Self::for_branch(repo, local_branch))
+    }
+
+    /// Where `local_branch` pushes to, resolved the same way.
+    pub fn for_branch(repo: &GitRepo, local_branch: String) -> Self {
+        let config = |key: &str| {
+            repo.run_command_with_output(&["config", "--get", &format!("branch.{}.{}", local_branch, key)])
+                .ok()
+                .filter(|v| !v.is_empty())
+        };
+
+        let remote = config("remote")
+            .filter(|r| r != ".")
+            .unwrap_or_else(|| "origin".to_string());
+        let remote_branch = config("merge")
+            .map(|m| m.trim_start_matches("refs/heads/").to_string())
+            .unwrap_or_else(|| local_branch.clone());
+
+        PushTarget { remote, local_branch, remote_branch }
+    }
+
+    fn refspec(&self) -> String {
+        format!("{}:refs/heads/{}", self.local_branch, self.remote_branch)
+    }
+
+    fn tracking_ref(&self) -> String {
+        format!("refs/remotes/{}/{}", self.remote, self.remote_branch)
+    }
+}
+
+/// The URL `git push` will use for the current branch, if its remote has one.
+pub fn push_url(repo: &GitRepo) -> Option<String> {
+    let target = PushTarget::resolve(repo).ok()?;
+    repo.run_command_with_output(&["remote", "get-url", "--push", &target.remote])
+        .ok()
+        .filter(|url| !url.is_empty())
+}
+
+// ============================================================================
+// PUSH WORKFLOW
+// ============================================================================
+
+/// Pushes the current branch, explaining any rejection. When the branch has
+/// diverged, lists the commits on each side and offers an explicit
+/// `--force-with-lease` overwrite pinned to the remote commit just shown.
+/// A push that fails for lack of a connection is queued for `syncgit flush`.
+/// Submodules the pushed commit points at are offered a push first, then
+/// the project's pre-push tasks run and LFS objects are uploaded if no hook
+/// will.
+pub fn push_current_branch(repo: &GitRepo, set_upstream: bool) -> Result<()> {
+    submodule::push_first(repo)?;
+    let target = PushTarget::resolve(repo)?;
+    tasks::before_push(repo, &target)?;
+    lfs::before_push(repo, &target)?;
+    let result = push_target(repo, &target, set_upstream);
+    match &result {
+        Ok(()) => queue::dequeue(repo, &target),
+        Err(GitError::Network(_)) => queue::queue_for_later(repo),
+        Err(_) => {}
+    }
+    result
+}
+
+fn push_target(repo: &GitRepo, target: &PushTarget, set_upstream: bool) -> Result<()> {
+    let refspec = target.refspec();
+
+    let mut args = vec!["push"];
+    if set_upstream {
+        args.push("-u");
+    }
+    args.push(&target.remote);
+    args.push(&refspec);
+
+    let failure = match run_push(repo, &args)? {
+        None => return Ok(()),
+        Some(failure) => failure,
+    };
+
+    let kind = PushRejection::classify(&failure.stderr);
+    report_rejection(kind, &failure.stderr);
+    if let Some(url) = push_url(repo) {
+        ssh::explain_failure(&url, &failure.stderr);
+    }
+
+    match kind {
+        PushRejection::NonFastForward => resolve_divergence(repo, target, set_upstream, failure),
+        // Not a rejection by the server (e.g. DNS or connection failure)
+        PushRejection::Unknown => Err(GitError::from_failure(failure)),
+        _ => Err(GitError::PushRejected { kind, failure }),
+    }
+}
+
+/// Pushes a branch without any prompts, for retrying queued pushes. A
+/// diverged branch is reported, never overwritten. The same pre-push steps
+/// as an interactive push run first, failing instead of asking.
+pub fn push_branch(repo: &GitRepo, target: &PushTarget) -> Result<()> {
+    submodule::check_pushed(repo, &target.local_branch)?;
+    tasks::before_queued_push(repo, target)?;
+    lfs::before_push(repo, target)?;
+    let refspec = target.refspec();
+    let failure = match run_push(repo, &["push", &target.remote, &refspec])? {
+        None => {
+            queue::dequeue(repo, target);
+            return Ok(());
+        }
+        Some(failure) => failure,
+    };
+    Err(match PushRejection::classify(&failure.stderr) {
+        PushRejection::Unknown => GitError::from_failure(failure),
+        kind => GitError::PushRejected { kind, failure },
+    })
+}
+
+/// Pushes a single commit to the branch `target` pushes to, without
+/// prompts. Used for a submodule commit the superproject records that the
+/// submodule's checked-out branch doesn't contain.
+pub fn push_commit(repo: &GitRepo, target: &PushTarget, commit: &str) -> Result<()> {
+    let refspec = format!("{}:refs/heads/{}", commit, target.remote_branch);
+    let failure = match run_push(repo, &["push", &target.remote, &refspec])? {
+        None => return Ok(()),
+        Some(failure) => failure,
+    };
+    Err(match PushRejection::classify(&failure.stderr) {
+        PushRejection::Unknown => GitError::from_failure(failure),
+        kind => GitError::PushRejected { kind, failure },
+    })
+}
+
+/// Runs `git push`, returning `None` on success or what went wrong.
+fn run_push(repo: &GitRepo, args: &[&str]) -> Result<Option<Failure>> {
+    let output = repo.create_command(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| GitError::io(t!("error.git_push"), e))?;
+
+    if output.status.success() {
+        Ok(None)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Ok(Some(Failure::command(args, output.status, &stderr)))
+    }
+}
+
+fn report_rejection(kind: PushRejection, stderr: &str) {
+    UI::print_separator();
+    println!("{}", UI::center_text(&t!("push.rejected", reason = kind)));
+    UI::print_separator();
+    for line in stderr.lines() {
+        println!("   {}", line);
+    }
+    println!();
+    for line in kind.explanation().lines() {
+        println!("{}", UI::center_text(line));
+    }
+}
+
+fn resolve_divergence(repo: &GitRepo, target: &PushTarget, set_upstream: bool, failure: Failure) -> Result<()> {
+    let rejected = || GitError::PushRejected { kind: PushRejection::NonFastForward, failure: failure.clone() };
+
+    println!("\n{}", UI::center_text(&t!("push.fetching_compare", remote = target.remote, branch = target.remote_branch)));
+    let fetch_refspec = format!("+refs/heads/{}:{}", target.remote_branch, target.tracking_ref());
+    if let Err(e) = repo.run_command(&["fetch", &target.remote, &fetch_refspec]) {
+        println!("{}", UI::center_text(&t!("push.fetch_failed", error = e)));
+        return Err(rejected());
+    }
+
+    // The exact remote commit the user is shown; the lease is pinned to it so
+    // anything pushed after this point is never overwritten silently.
+    let seen = repo.run_command_with_output(&["rev-parse", &target.tracking_ref()])?;
+
+    let local_only = list_commits(repo, &format!("{}..HEAD", seen));
+    let remote_only = list_commits(repo, &format!("HEAD..{}", seen));
+
+    UI::print_separator();
+    println!("{}", UI::center_text(&tn!("push.local_only", local_only.len(), remote = target.remote, branch = target.remote_branch)));
+    for commit in &local_only {
+        println!("   {}", commit);
+    }
+    println!();
+    println!("{}", UI::center_text(&tn!("push.remote_only", remote_only.len())));
+    for commit in &remote_only {
+        println!("   {}", commit);
+    }
+    UI::print_separator();
+
+    if remote_only.is_empty() {
+        // The remote moved back to an ancestor of ours between attempts; a
+        // normal push is enough.
+        return match run_push(repo, &["push", &target.remote, &target.refspec()])? {
+            None => Ok(()),
+            Some(_) => Err(rejected()),
+        };
+    }
+
+    println!("{}", UI::center_text(&t!("push.recommend_pull")));
+    println!("{}", UI::center_text(&t!("push.overwrite_warning")));
+
+    if !UI::prompt_yes_no(&t!("push.overwrite_prompt")) {
+        println!("{}", UI::center_text(&t!("push.cancelled_local_kept")));
+        return Err(rejected());
+    }
+
+    let confirmation = UI::prompt_input(&t!("push.type_to_confirm", branch = target.remote_branch));
+    if confirmation != target.remote_branch {
+        println!("{}", UI::center_text(&t!("push.confirm_mismatch")));
+        return Err(rejected());
+    }
+
+    let lease = format!("--force-with-lease=refs/heads/{}:{}", target.remote_branch, seen);
+    let refspec = target.refspec();
+    let mut args = vec!["push", lease.as_str()];
+    if set_upstream {
+        args.push("-u");
+    }
+    args.push(&target.remote);
+    args.push(&refspec);
+
+    println!("\n{}", UI::center_text(&t!("push.force_pushing")));
+    match run_push(repo, &args)? {
+        None => {
+            println!("{}", UI::center_text(&t!("push.overwritten")));
+            Ok(())
+        }
+        Some(failure) => {
+            if failure.stderr.contains("stale info") {
+                println!("{}", UI::center_text(&t!("push.stale_lease")));
+                println!("{}", UI::center_text(&t!("push.stale_lease_hint")));
+                return Err(GitError::PushRejected { kind: PushRejection::NonFastForward, failure });
+            }
+            let kind = PushRejection::classify(&failure.stderr);
+            report_rejection(kind, &failure.stderr);
+            Err(GitError::PushRejected { kind, failure })
+        }
+    }
+}
+
+fn list_commits(repo: &GitRepo, range: &str) -> Vec<String> {
+    repo.run_command_with_output(&["log", "--format=%h %s (%an, %ar)", range, "--"])
+        .map(|out| out.lines().map(|l| l.to_string()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_push_rejections() {
+        let cases = [
+            (
+                "To github.com:ada/app.git\n ! [rejected]        main -> main (non-fast-forward)\nerror: failed to push some refs to 'github.com:ada/app.git'\nhint: Updates were rejected because the tip of your current branch is behind",
+                PushRejection::NonFastForward,
+            ),
+            (
+                "To github.com:ada/app.git\n ! [rejected]        main -> main (fetch first)\nerror: failed to push some refs to 'github.com:ada/app.git'\nhint: Updates were rejected because the remote contains work that you do not",
+                PushRejection::NonFastForward,
+            ),
+            (
+                "To github.com:ada/app.git\n ! [rejected]        main -> main (stale info)\nerror: failed to push some refs to 'github.com:ada/app.git'",
+                PushRejection::NonFastForward,
+            ),
+            (
+                "remote: error: GH006: Protected branch update failed for refs/heads/main.\nremote: error: Changes must be made through a pull request.\nTo github.com:ada/app.git\n ! [remote rejected] main -> main (protected branch hook declined)",
+                PushRejection::ProtectedBranch,
+            ),
+            (
+                "remote: error: GH013: Repository rule violations found for refs/heads/main.\nremote: - Changes must be made through a pull request.\nTo github.com:ada/app.git\n ! [remote rejected] main -> main (push declined due to repository rule violations)",
+                PushRejection::ProtectedBranch,
+            ),
+            (
+                "remote: error: GH001: Large files detected. You may want to try Git Large File Storage - https://git-lfs.github.com.\nremote: error: File data.bin is 120.00 MB; this exceeds GitHub's file size limit of 100.00 MB\n ! [remote rejected] main -> main (pre-receive hook declined)",
+                PushRejection::TooLarge,
+            ),
+            (
+                "remote: commit message must reference an issue\nTo example.com:ada/app.git\n ! [remote rejected] main -> main (pre-receive hook declined)",
+                PushRejection::HookDeclined,
+            ),
+            (
+                "remote: Permission to ada/app.git denied to bob.\nfatal: unable to access 'https://github.com/ada/app.git/': The requested URL returned error: 403",
+                PushRejection::AuthFailed,
+            ),
+            ("git@github.com: Permission denied (publickey).\nfatal: Could not read from remote repository.", PushRejection::AuthFailed),
+            ("fatal: unable to access 'https://github.com/ada/app.git/': Could not resolve host: github.com", PushRejection::Unknown),
+        ];
+        for (stderr, expected) in cases {
+            assert_eq!(PushRejection::classify(stderr), expected, "{}", stderr);
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::auth::{home_dir, RemoteKind};
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::push::{self, PushTarget};
+use crate::{check_internet_connection, GitRepo, UI};
+
+// ============================================================================
+// OFFLINE PUSH QUEUE
+// ============================================================================
+
+// Pushes that couldn't happen for lack of a connection, across every
+// repository. One tab-separated line per branch:
+//   <repo root> <local branch> <remote> <remote branch> <queued at, Unix secs>
+// A branch is queued at most once; a later successful push removes it.
+
+const QUEUE_FILE: &str = "push-queue";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub root: PathBuf,
+    pub branch: String,
+    pub remote: String,
+    pub remote_branch: String,
+    pub queued_at: u64,
+}
+
+impl Entry {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        let entry = Entry {
+            root: PathBuf::from(fields.next().filter(|f| !f.is_empty())?),
+            branch: fields.next()?.to_string(),
+            remote: fields.next()?.to_string(),
+            remote_branch: fields.next()?.to_string(),
+            queued_at: fields.next()?.parse().ok()?,
+        };
+        Some(entry)
+    }
+
+    fn to_line(&self) -> String {
+        format!("{}\t{}\t{}\t{}\t{}", self.root.display(), self.branch, self.remote, self.remote_branch, self.queued_at)
+    }
+
+    fn is_for(&self, root: &Path, target: &PushTarget) -> bool {
+        self.root == root && self.branch == target.local_branch && self.remote == target.remote
+    }
+
+    fn target(&self) -> PushTarget {
+        PushTarget {
+            remote: self.remote.clone(),
+            local_branch: self.branch.clone(),
+            remote_branch: self.remote_branch.clone(),
+        }
+    }
+
+    fn repo_name(&self) -> String {
+        self.root.file_name().map_or_else(|| self.root.display().to_string(), |n| n.to_string_lossy().to_string())
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// `$XDG_STATE_HOME/syncgit/push-queue`, `%LOCALAPPDATA%\syncgit\push-queue`
+/// or `~/.local/state/syncgit/push-queue`.
+fn queue_file() -> Option<PathBuf> {
+    let dir = env::var_os("XDG_STATE_HOME")
+        .or_else(|| env::var_os("LOCALAPPDATA"))
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| home_dir().map(|home| home.join(".local").join("state")))?;
+    Some(dir.join("syncgit").join(QUEUE_FILE))
+}
+
+pub fn load() -> Vec<Entry> {
+    queue_file()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|content| content.lines().filter_map(Entry::parse).collect())
+        .unwrap_or_default()
+}
+
+fn save(entries: &[Entry]) -> Result<()> {
+    let path = queue_file().ok_or_else(|| GitError::Other(t!("queue.no_state_dir")))?;
+    if entries.is_empty() {
+        return match fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(GitError::io(t!("error.write_file", path = path.display()), e)),
+            _ => Ok(()),
+        };
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| GitError::io(t!("error.create_dir", path = dir.display()), e))?;
+    }
+
+    // Write then rename, so a concurrent run never reads half a file
+    let content: String = entries.iter().map(|e| e.to_line() + "\n").collect();
+    let temp = path.with_extension(format!("tmp{}", std::process::id()));
+    fs::write(&temp, content)
+        .and_then(|()| fs::rename(&temp, &path))
+        .map_err(|e| GitError::io(t!("error.write_file", path = path.display()), e))
+}
+
+/// Queues the current branch for `syncgit flush`. Queuing it again keeps the
+/// original time.
+pub fn enqueue(repo: &GitRepo) -> Result<()> {
+    let target = PushTarget::resolve(repo)?;
+    let mut entries = load();
+    if !entries.iter().any(|e| e.is_for(&repo.root, &target)) {
+        entries.push(Entry {
+            root: repo.root.clone(),
+            branch: target.local_branch,
+            remote: target.remote,
+            remote_branch: target.remote_branch,
+            queued_at: now(),
+        });
+        save(&entries)?;
+    }
+    Ok(())
+}
+
+/// Queues the current branch and says so; if that fails, falls back to
+/// asking for a manual push.
+pub fn queue_for_later(repo: &GitRepo) {
+    match enqueue(repo) {
+        Ok(()) => println!("{}", UI::center_text(&t!("queue.queued"))),
+        Err(e) => {
+            println!("{}", UI::center_text(&t!("queue.queue_failed", error = e)));
+            println!("{}", UI::center_text(&t!("push.run_manually")));
+        }
+    }
+}
+
+/// Drops a branch from the queue once it has been pushed.
+pub fn dequeue(repo: &GitRepo, target: &PushTarget) {
+    let mut entries = load();
+    let before = entries.len();
+    entries.retain(|e| !e.is_for(&repo.root, target));
+    if entries.len() != before {
+        // Failing to clean up only means a no-op retry later
+        let _ = save(&entries);
+    }
+}
+
+// ============================================================================
+// RETRYING
+// ============================================================================
+
+enum Outcome {
+    Pushed,
+    /// The repository, branch or remote no longer exists.
+    Gone,
+    Offline,
+    Failed(GitError),
+}
+
+fn retry(entry: &Entry, online: &mut Option<bool>) -> Outcome {
+    let Some(repo) = GitRepo::find_from_path(&entry.root).filter(|r| r.root == entry.root) else {
+        return Outcome::Gone;
+    };
+    if repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", entry.branch)]).is_err() {
+        return Outcome::Gone;
+    }
+    let Ok(url) = repo.run_command_with_output(&["remote", "get-url", "--push", &entry.remote]) else {
+        return Outcome::Gone;
+    };
+
+    let kind = RemoteKind::classify(&url);
+    if kind.needs_network() && !*online.get_or_insert_with(check_internet_connection) {
+        return Outcome::Offline;
+    }
+    if let Err(e) = repo.configure_auth(&url) {
+        return Outcome::Failed(e);
+    }
+    match push::push_branch(&repo, &entry.target()) {
+        Ok(()) => Outcome::Pushed,
+        Err(GitError::Network(_)) | Err(GitError::NoInternet) => Outcome::Offline,
+        Err(e) => Outcome::Failed(e),
+    }
+}
+
+fn describe(entry: &Entry) -> String {
+    t!(
+        "queue.entry",
+        repo = entry.repo_name(),
+        branch = entry.branch,
+        remote = entry.remote,
+        remote_branch = entry.remote_branch
+    )
+}
+
+/// Retries every queued push. Entries that are still offline or failed stay
+/// queued; the first failure (or being offline) is returned.
+fn flush_entries(retried: Vec<Entry>) -> Result<()> {
+    println!("{}", UI::center_text(&tn!("queue.retrying", retried.len())));
+    UI::print_separator();
+
+    let mut online = None;
+    let mut remaining = Vec::new();
+    let mut first_error = None;
+    for entry in retried.iter().cloned() {
+        println!("{}", describe(&entry));
+        match retry(&entry, &mut online) {
+            Outcome::Pushed => println!("{}", t!("queue.pushed")),
+            Outcome::Gone => println!("{}", t!("queue.gone")),
+            Outcome::Offline => {
+                println!("{}", t!("queue.offline"));
+                remaining.push(entry);
+            }
+            Outcome::Failed(e) => {
+                println!("{}", t!("queue.failed", error = e));
+                println!("{}", t!("queue.failed_hint", path = entry.root.display()));
+                first_error.get_or_insert(e);
+                remaining.push(entry);
+            }
+        }
+    }
+
+    // Keep anything another run queued meanwhile
+    let mut entries = load();
+    entries.retain(|e| remaining.contains(e) || !retried.contains(e));
+    save(&entries)?;
+    UI::print_separator();
+
+    if remaining.is_empty() {
+        println!("{}", UI::center_text(&t!("queue.flushed")));
+        return Ok(());
+    }
+    println!("{}", UI::center_text(&tn!("queue.still_waiting", remaining.len())));
+    Err(first_error.unwrap_or(GitError::NoInternet))
+}
+
+/// `syncgit flush`: retries every queued push now.
+pub fn flush() -> Result<()> {
+    let entries = load();
+    if entries.is_empty() {
+        println!("{}", UI::center_text(&t!("queue.empty")));
+        return Ok(());
+    }
+    flush_entries(entries)
+}
+
+/// Offers to retry queued pushes at the start of a run, if there are any.
+/// They may be for other repositories, whose credentials and pre-push
+/// checks a retry uses, so nothing is pushed without asking. Problems are
+/// reported but never stop the run.
+pub fn flush_on_startup() {
+    let entries = load();
+    if entries.is_empty() {
+        return;
+    }
+    println!("{}", UI::center_text(&tn!("queue.title", entries.len())));
+    UI::print_separator();
+    print_entries(&entries);
+    UI::print_separator();
+    if !UI::prompt_yes_no(&t!("queue.startup_confirm")) {
+        println!("{}", UI::center_text(&t!("queue.flush_hint")));
+        UI::print_separator();
+        return;
+    }
+    if let Err(e) = flush_entries(entries) {
+        if !matches!(e, GitError::NoInternet) {
+            println!("\n{}: {}", UI::center_text(&t!("main.warning")), e);
+        }
+    }
+}
+
+// ============================================================================
+// LISTING
+// ============================================================================
+
+pub fn age(queued_at: u64) -> String {
+    let minutes = now().saturating_sub(queued_at) / 60;
+    if minutes < 1 {
+        t!("queue.age.now")
+    } else if minutes < 60 {
+        tn!("queue.age.minutes", minutes)
+    } else if minutes < 60 * 24 {
+        tn!("queue.age.hours", minutes / 60)
+    } else {
+        tn!("queue.age.days", minutes / (60 * 24))
+    }
+}
+
+/// How many commits the entry's branch has that its remote branch doesn't,
+/// as far as the last fetch knows.
+fn commits_waiting(entry: &Entry) -> Option<usize> {
+    let repo = GitRepo::find_from_path(&entry.root).filter(|r| r.root == entry.root)?;
+    let range = format!("refs/remotes/{}/{}..refs/heads/{}", entry.remote, entry.remote_branch, entry.branch);
+    repo.run_command_with_output(&["rev-list", "--count", &range]).ok()?.parse().ok()
+}
+
+/// `syncgit queue`: lists every push still waiting, across repositories.
+pub fn print_status() -> Result<()> {
+    let entries = load();
+    if entries.is_empty() {
+        println!("{}", UI::center_text(&t!("queue.empty")));
+        return Ok(());
+    }
+
+    println!("{}", UI::center_text(&tn!("queue.title", entries.len())));
+    UI::print_separator();
+    print_entries(&entries);
+    UI::print_separator();
+    println!("{}", UI::center_text(&t!("queue.flush_hint")));
+    Ok(())
+}
+
+fn print_entries(entries: &[Entry]) {
+    for entry in entries {
+        println!("{}", describe(entry));
+        let commits = match commits_waiting(entry) {
+            Some(count) => tn!("queue.commits", count),
+            None if !entry.root.exists() => t!("queue.missing_repo"),
+            None => t!("queue.new_branch"),
+        };
+        println!("{}", t!("queue.details", path = entry.root.display(), commits = commits, age = age(entry.queued_at)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry() -> Entry {
+        Entry {
+            root: PathBuf::from("/home/ada/My Projects/app"),
+            branch: "feature/login".to_string(),
+            remote: "origin".to_string(),
+            remote_branch: "feature/login".to_string(),
+            queued_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn entries_round_trip() {
+        let line = entry().to_line();
+        assert_eq!(line, "/home/ada/My Projects/app\tfeature/login\torigin\tfeature/login\t1700000000");
+        assert_eq!(Entry::parse(&line), Some(entry()));
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let cases = [
+            "",
+            "\tmain\torigin\tmain\t1",
+            "/repo\tmain\torigin\tmain",
+            "/repo\tmain\torigin\tmain\tyesterday",
+            "/repo main origin main 1",
+        ];
+        for line in cases {
+            assert_eq!(Entry::parse(line), None, "{:?}", line);
+        }
+    }
+}
@@ -0,0 +1,590 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fs;
+
+use serde_json::json;
+
+use crate::api::{self, ApiClient};
+use crate::auth;
+use crate::config;
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::push::{self, PushTarget};
+use crate::{get_github_token, GitRepo, UI};
+
+// ============================================================================
+// VERSIONS
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// `rc.1` in `1.0.0-rc.1`.
+    pub pre: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bump {
+    Major,
+    Minor,
+    Patch,
+}
+
+impl Version {
+    /// `1.2.3`, `v1.2.3` or `1.2.3-rc.1`; build metadata is ignored.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().strip_prefix('v').unwrap_or(text.trim());
+        let text = text.split('+').next()?;
+        let (core, pre) = match text.split_once('-') {
+            Some((core, pre)) if !pre.is_empty() => (core, Some(pre.to_string())),
+            Some(_) => return None,
+            None => (text, None),
+        };
+        let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+        let version = Version { major: parts.next()??, minor: parts.next()??, patch: parts.next()??, pre };
+        parts.next().is_none().then_some(version)
+    }
+
+    /// Releasing a pre-release drops the suffix rather than bumping again.
+    pub fn bump(&self, bump: Bump) -> Self {
+        let (major, minor, patch) = match (bump, &self.pre) {
+            (_, Some(_)) => (self.major, self.minor, self.patch),
+            (Bump::Major, None) => (self.major + 1, 0, 0),
+            (Bump::Minor, None) => (self.major, self.minor + 1, 0),
+            (Bump::Patch, None) => (self.major, self.minor, self.patch + 1),
+        };
+        Version { major, minor, patch, pre: None }
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            // A pre-release comes before the release itself
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => compare_pre(a, b),
+            })
+    }
+}
+
+/// Pre-release precedence (SemVer §11): identifier by identifier, numeric
+/// ones by value and before alphanumeric ones; when one list is a prefix
+/// of the other, the shorter comes first.
+fn compare_pre(a: &str, b: &str) -> Ordering {
+    let numeric = |id: &str| !id.is_empty() && id.bytes().all(|c| c.is_ascii_digit());
+    a.split('.')
+        .zip(b.split('.'))
+        .map(|(x, y)| match (numeric(x), numeric(y)) {
+            // No leading zeros, so the longer number is the larger one
+            (true, true) => x.len().cmp(&y.len()).then_with(|| x.cmp(y)),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => x.cmp(y),
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.split('.').count().cmp(&b.split('.').count()))
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// HISTORY
+// ============================================================================
+
+/// A commit, with its Conventional Commits parts when the subject follows
+/// `type(scope)!: summary`.
+pub struct Commit {
+    pub hash: String,
+    pub kind: Option<String>,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    /// The subject without the `type(scope):` prefix.
+    pub summary: String,
+}
+
+impl Commit {
+    fn parse(hash: &str, subject: &str, body: &str) -> Self {
+        let breaking_note = body.contains("BREAKING CHANGE:") || body.contains("BREAKING-CHANGE:");
+        let conventional = subject.split_once(": ").and_then(|(prefix, summary)| {
+            let (prefix, bang) = match prefix.strip_suffix('!') {
+                Some(prefix) => (prefix, true),
+                None => (prefix, false),
+            };
+            let (kind, scope) = match prefix.split_once('(') {
+                Some((kind, scope)) => (kind, Some(scope.strip_suffix(')')?.to_string())),
+                None => (prefix, None),
+            };
+            let valid = !kind.is_empty() && kind.chars().all(|c| c.is_ascii_alphabetic());
+            valid.then(|| (kind.to_lowercase(), scope, bang, summary.trim().to_string()))
+        });
+        match conventional {
+            Some((kind, scope, bang, summary)) => Commit {
+                hash: hash.to_string(),
+                kind: Some(kind),
+                scope,
+                breaking: bang || breaking_note,
+                summary,
+            },
+            None => Commit {
+                hash: hash.to_string(),
+                kind: None,
+                scope: None,
+                breaking: breaking_note,
+                summary: subject.to_string(),
+            },
+        }
+    }
+
+    pub fn short_hash(&self) -> &str {
+        &self.hash[..7.min(self.hash.len())]
+    }
+}
+
+/// Non-merge commits in `range` (anything `git log` takes), newest first.
+pub fn commits(repo: &GitRepo, range: &str) -> Vec<Commit> {
+    let output = repo.run_command_with_output(&["log", "--no-merges", "--format=%H%x1f%s%x1f%b%x1e", range, "--"])
+        .unwrap_or_default();
+    output.split('\x1e')
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').split('\x1f');
+            let (hash, subject, body) = (fields.next()?, fields.next()?, fields.next().unwrap_or(""));
+            (!hash.is_empty()).then(|| Commit::parse(hash, subject, body))
+        })
+        .collect()
+}
+
+/// Every tag reachable from `rev` that names a version, newest version first.
+pub fn version_tags(repo: &GitRepo, rev: &str) -> Vec<(String, Version)> {
+    let output = repo.run_command_with_output(&["tag", "--merged", rev]).unwrap_or_default();
+    let mut tags: Vec<(String, Version)> = output.lines()
+        .filter_map(|tag| Version::parse(tag).map(|version| (tag.to_string(), version)))
+        .collect();
+    tags.sort_by(|a, b| b.1.cmp(&a.1));
+    tags
+}
+
+/// The bump the commits call for: major for breaking changes, minor for
+/// features, patch otherwise. Before 1.0 breaking changes only bump minor.
+fn suggested_bump(commits: &[Commit], current: &Version) -> Bump {
+    if commits.iter().any(|c| c.breaking) {
+        if current.major == 0 { Bump::Minor } else { Bump::Major }
+    } else if commits.iter().any(|c| c.kind.as_deref() == Some("feat")) {
+        Bump::Minor
+    } else {
+        Bump::Patch
+    }
+}
+
+/// Which part of the release notes a commit belongs in.
+fn section(commit: &Commit) -> usize {
+    match commit.kind.as_deref() {
+        _ if commit.breaking => 0,
+        Some("feat") => 1,
+        Some("fix") => 2,
+        _ => 3,
+    }
+}
+
+/// Markdown notes: breaking changes, features, fixes, then everything else.
+pub fn release_notes(commits: &[Commit]) -> String {
+    let titles = [
+        t!("release.notes.breaking"),
+        t!("release.notes.features"),
+        t!("release.notes.fixes"),
+        t!("release.notes.misc"),
+    ];
+    let mut notes = String::new();
+    for (index, title) in titles.iter().enumerate() {
+        let entries: Vec<&Commit> = commits.iter().filter(|c| section(c) == index).collect();
+        if entries.is_empty() {
+            continue;
+        }
+        notes.push_str(&format!("### {}\n\n", title));
+        for commit in entries {
+            let scope = commit.scope.as_ref().map(|s| format!("**{}:** ", s)).unwrap_or_default();
+            notes.push_str(&format!("- {}{} ({})\n", scope, commit.summary, commit.short_hash()));
+        }
+        notes.push('\n');
+    }
+    notes.trim_end().to_string()
+}
+
+// ============================================================================
+// VERSION FILES
+// ============================================================================
+
+/// A file that states the project version, and where.
+struct VersionFile {
+    path: String,
+    current: String,
+    /// Line holding the version; `None` to replace its first occurrence.
+    line: Option<usize>,
+}
+
+/// The `version = "..."` line of the first matching section of a TOML
+/// manifest, and its value.
+fn toml_version(text: &str, sections: &[&str]) -> Option<(usize, String)> {
+    let mut in_section = false;
+    for (index, line) in text.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_section = sections.contains(&trimmed);
+            continue;
+        }
+        let Some(value) = trimmed.strip_prefix("version") else { continue };
+        let Some(value) = value.trim_start().strip_prefix('=') else { continue };
+        if in_section {
+            let value = value.trim().strip_prefix('"')?.split('"').next()?;
+            return Some((index, value.to_string()));
+        }
+    }
+    None
+}
+
+fn detect_version_files(repo: &GitRepo) -> Result<Vec<VersionFile>> {
+    let read = |path: &str| fs::read_to_string(repo.root.join(path)).ok();
+    let mut files = Vec::new();
+
+    if let Some((line, current)) = read("Cargo.toml").and_then(|text| toml_version(&text, &["[package]", "[workspace.package]"])) {
+        files.push(VersionFile { path: "Cargo.toml".to_string(), current, line: Some(line) });
+    }
+    if let Some((line, current)) = read("pyproject.toml").and_then(|text| toml_version(&text, &["[project]", "[tool.poetry]"])) {
+        files.push(VersionFile { path: "pyproject.toml".to_string(), current, line: Some(line) });
+    }
+    if let Some(text) = read("package.json") {
+        let current = serde_json::from_str::<serde_json::Value>(&text).ok()
+            .and_then(|json| json["version"].as_str().map(str::to_string));
+        // The top-level field comes before any dependency of the same name
+        let line = text.lines().position(|line| line.contains("\"version\""));
+        if let (Some(current), Some(line)) = (current, line) {
+            files.push(VersionFile { path: "package.json".to_string(), current, line: Some(line) });
+        }
+    }
+
+    // Anything else listed under [release] in .syncgit.toml
+    let extra = config::load(repo)?
+        .and_then(|config| config.get("release")?.get("files")?.as_array().cloned())
+        .unwrap_or_default();
+    let current = files.first().map(|f| f.current.clone());
+    for path in extra.iter().filter_map(|p| p.as_str()) {
+        let Some(current) = current.clone() else {
+            return Err(config::invalid(t!("release.extra_without_version", file = path)));
+        };
+        files.push(VersionFile { path: path.to_string(), current, line: None });
+    }
+    Ok(files)
+}
+
+fn update_file(repo: &GitRepo, file: &VersionFile, next: &str) -> Result<()> {
+    let path = repo.root.join(&file.path);
+    let text = fs::read_to_string(&path).map_err(|e| GitError::io(t!("error.read_file", path = file.path), e))?;
+    let updated = match file.line {
+        Some(index) => text.split_inclusive('\n')
+            .enumerate()
+            .map(|(i, line)| if i == index { line.replacen(&file.current, next, 1) } else { line.to_string() })
+            .collect(),
+        None => text.replacen(&file.current, next, 1),
+    };
+    if updated == text {
+        return Err(GitError::Other(t!("release.version_not_found", file = file.path, version = file.current)));
+    }
+    fs::write(&path, updated).map_err(|e| GitError::io(t!("error.write_file", path = file.path), e))
+}
+
+/// Keeps a tracked `Cargo.lock` in step with a bumped `Cargo.toml`.
+fn update_cargo_lock(repo: &GitRepo, current: &str, next: &str) -> Result<bool> {
+    if repo.run_command_with_output(&["ls-files", "--error-unmatch", "Cargo.lock"]).is_err() {
+        return Ok(false);
+    }
+    let manifest = fs::read_to_string(repo.root.join("Cargo.toml")).unwrap_or_default();
+    let Some(name) = manifest.lines()
+        .skip_while(|line| line.trim() != "[package]")
+        .find_map(|line| line.trim().strip_prefix("name")?.trim_start().strip_prefix('=')?.trim().strip_prefix('"')?.split('"').next().map(str::to_string))
+    else {
+        return Ok(false);
+    };
+    let path = repo.root.join("Cargo.lock");
+    let lock = fs::read_to_string(&path).map_err(|e| GitError::io(t!("error.read_file", path = "Cargo.lock"), e))?;
+    let entry = format!("name = \"{}\"\nversion = \"{}\"", name, current);
+    if !lock.contains(&entry) {
+        return Ok(false);
+    }
+    let updated = lock.replacen(&entry, &format!("name = \"{}\"\nversion = \"{}\"", name, next), 1);
+    fs::write(&path, updated).map_err(|e| GitError::io(t!("error.write_file", path = "Cargo.lock"), e))?;
+    Ok(true)
+}
+
+// ============================================================================
+// RELEASE COMMAND
+// ============================================================================
+
+#[derive(Debug, Default)]
+pub struct Options {
+    /// `major`, `minor`, `patch` or an explicit version; asked for if unset.
+    pub version: Option<String>,
+    /// Sign the tag with the configured GPG or SSH key.
+    pub sign: bool,
+}
+
+/// Parses the version asked for. It has to be newer than `current`, or may
+/// equal it for a first release of what the version files already state.
+fn target_version(input: &str, current: &Version, first: bool) -> Result<Version> {
+    let next = match input.to_lowercase().as_str() {
+        "major" => current.bump(Bump::Major),
+        "minor" => current.bump(Bump::Minor),
+        "patch" => current.bump(Bump::Patch),
+        _ => Version::parse(input).ok_or_else(|| GitError::Usage(t!("release.invalid_version", version = input)))?,
+    };
+    if next < *current || (next == *current && !first) {
+        return Err(GitError::Usage(t!("release.not_newer", version = next, current = current)));
+    }
+    Ok(next)
+}
+
+/// `syncgit release [major|minor|patch|<version>] [--sign]`: bumps the
+/// version files, commits, tags and pushes, then offers a GitHub release.
+pub fn release(options: &Options) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    if repo.has_changes(None) {
+        return Err(GitError::Usage(t!("release.dirty")));
+    }
+    if repo.run_command_with_output(&["symbolic-ref", "-q", "HEAD"]).is_err() {
+        return Err(GitError::Other(t!("error.detached_head")));
+    }
+
+    let previous = version_tags(&repo, "HEAD").into_iter().next();
+    let range = previous.as_ref().map_or_else(|| String::from("HEAD"), |(tag, _)| format!("{}..HEAD", tag));
+    let commits = commits(&repo, &range);
+    if commits.is_empty() {
+        return Err(GitError::Usage(match previous {
+            Some((tag, _)) => t!("release.nothing_new", tag = tag),
+            None => t!("release.no_commits"),
+        }));
+    }
+
+    let mut files = detect_version_files(&repo)?;
+    let stated = files.first().and_then(|f| Version::parse(&f.current));
+    let first = previous.is_none();
+    let (current, suggested) = match (&previous, stated) {
+        (Some((_, version)), _) => (version.clone(), version.bump(suggested_bump(&commits, version))),
+        // The first release tags whatever the version files already say
+        (None, Some(version)) => (version.clone(), version),
+        (None, None) => {
+            let none = Version { major: 0, minor: 0, patch: 0, pre: None };
+            (none.clone(), none.bump(Bump::Minor))
+        }
+    };
+
+    UI::print_separator();
+    match &previous {
+        Some((tag, _)) => println!("{}", UI::center_text(&tn!("release.since_tag", commits.len(), tag = tag))),
+        None => println!("{}", UI::center_text(&tn!("release.first", commits.len()))),
+    }
+    let features = commits.iter().filter(|c| c.kind.as_deref() == Some("feat")).count();
+    let fixes = commits.iter().filter(|c| c.kind.as_deref() == Some("fix")).count();
+    let breaking = commits.iter().filter(|c| c.breaking).count();
+    println!("{}", UI::center_text(&t!("release.breakdown", breaking = breaking, features = features, fixes = fixes)));
+
+    let next = match &options.version {
+        Some(input) => target_version(input, &current, first)?,
+        None => {
+            println!("{}", UI::center_text(&t!("release.suggested", version = suggested, current = current)));
+            match UI::prompt_input(&t!("release.version_prompt", version = suggested)).as_str() {
+                "" => suggested,
+                input => target_version(input, &current, first)?,
+            }
+        }
+    };
+
+    // Follow the existing tags' style, `v1.2.3` unless they're bare
+    let prefix = match &previous {
+        Some((tag, _)) if !tag.starts_with('v') => "",
+        _ => "v",
+    };
+    let tag = format!("{}{}", prefix, next);
+    if repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", &format!("refs/tags/{}", tag)]).is_ok() {
+        return Err(GitError::Usage(t!("release.tag_exists", tag = tag)));
+    }
+
+    let next_text = next.to_string();
+    files.retain(|file| file.current != next_text);
+
+    UI::print_separator();
+    println!("{}", UI::center_text(&t!("release.plan_title", tag = tag)));
+    for file in &files {
+        println!("{}", t!("release.plan_file", file = file.path, from = file.current, to = next));
+    }
+    println!("{}", t!(if options.sign { "release.plan_tag_signed" } else { "release.plan_tag" }, tag = tag));
+    if repo.has_remote() {
+        println!("{}", t!("release.plan_push"));
+    }
+    if !UI::prompt_yes_no(&t!("release.confirm")) {
+        return Err(GitError::Cancelled(t!("cancel.operation")));
+    }
+
+    if !files.is_empty() {
+        for file in &files {
+            update_file(&repo, file, &next_text)?;
+            repo.run_command_with_output(&["add", "--", &file.path])?;
+        }
+        if let Some(cargo) = files.iter().find(|f| f.path == "Cargo.toml") {
+            if update_cargo_lock(&repo, &cargo.current, &next_text)? {
+                repo.run_command_with_output(&["add", "--", "Cargo.lock"])?;
+            }
+        }
+        repo.run_command(&["commit", "-m", &format!("chore(release): {}", tag), "--"])?;
+    }
+
+    let notes = release_notes(&commits);
+    let message = format!("{}\n\n{}", tag, notes);
+    // Keep the notes' "###" headings, which git would strip as comments
+    let mode = if options.sign { "-s" } else { "-a" };
+    repo.run_command_with_output(&["tag", mode, "--cleanup=whitespace", &tag, "-m", &message])?;
+    println!("{}", UI::center_text(&t!("release.tagged", tag = tag)));
+
+    if !repo.has_remote() {
+        return Ok(());
+    }
+    if !auth::push_remote_reachable(&repo) {
+        println!("{}", UI::center_text(&t!("release.offline", tag = tag)));
+        return Ok(());
+    }
+    repo.configure_auth_remote()?;
+    let set_upstream = repo.run_command_with_output(&["rev-parse", "--abbrev-ref", "@{u}"]).is_err();
+    push::push_current_branch(&repo, set_upstream)?;
+    let target = PushTarget::resolve(&repo)?;
+    repo.run_command(&["push", &target.remote, &format!("refs/tags/{}", tag)])?;
+    println!("{}", UI::center_text(&t!("release.pushed", tag = tag, remote = target.remote)));
+
+    create_github_release(&repo, &tag, &next, &notes);
+    Ok(())
+}
+
+/// Offers a GitHub release for the pushed tag when the push remote is on
+/// GitHub. Failures are reported; the tag is out either way.
+fn create_github_release(repo: &GitRepo, tag: &str, version: &Version, notes: &str) {
+    let Some((host, path)) = push::push_url(repo).and_then(|url| auth::split_remote(&url)) else {
+        return;
+    };
+    if host != "github.com" && host != api::web_host() {
+        return;
+    }
+    if !UI::prompt_yes_no(&t!("release.github_prompt")) {
+        return;
+    }
+    let Some(token) = get_github_token() else {
+        println!("{}", UI::center_text(&t!("release.github_no_token", tag = tag)));
+        return;
+    };
+    let body = json!({
+        "tag_name": tag,
+        "name": tag,
+        "body": notes,
+        "prerelease": version.pre.is_some(),
+    });
+    match ApiClient::new(&token).post(&format!("/repos/{}/releases", path), &body) {
+        Ok(release) => {
+            let url = release["html_url"].as_str().unwrap_or_default();
+            println!("{}", UI::center_text(&t!("release.github_created", url = url)));
+        }
+        Err(e) => println!("{}", UI::center_text(&t!("release.github_failed", error = e))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(text: &str) -> Version {
+        Version::parse(text).unwrap()
+    }
+
+    #[test]
+    fn parses_versions() {
+        assert_eq!(version("v1.2.3"), Version { major: 1, minor: 2, patch: 3, pre: None });
+        assert_eq!(version("1.0.0-rc.1+build.5").pre.as_deref(), Some("rc.1"));
+        for invalid in ["1.2", "1.2.3.4", "1.2.x", "1.2.3-", "release"] {
+            assert_eq!(Version::parse(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn bumps_versions() {
+        assert_eq!(version("1.2.3").bump(Bump::Major).to_string(), "2.0.0");
+        assert_eq!(version("1.2.3").bump(Bump::Minor).to_string(), "1.3.0");
+        assert_eq!(version("1.2.3").bump(Bump::Patch).to_string(), "1.2.4");
+        assert_eq!(version("2.0.0-rc.2").bump(Bump::Major).to_string(), "2.0.0");
+    }
+
+    #[test]
+    fn orders_versions_by_semver_precedence() {
+        // The example from SemVer §11
+        let ordered = [
+            "1.0.0-alpha", "1.0.0-alpha.1", "1.0.0-alpha.beta", "1.0.0-beta",
+            "1.0.0-beta.2", "1.0.0-beta.11", "1.0.0-rc.1", "1.0.0", "1.0.1", "1.10.0",
+        ];
+        for pair in ordered.windows(2) {
+            assert!(version(pair[0]) < version(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+        assert_eq!(version("1.0.0-rc.1").cmp(&version("v1.0.0-rc.1+build")), Ordering::Equal);
+    }
+
+    #[test]
+    fn parses_conventional_commits() {
+        let commit = Commit::parse("abc", "feat(cli)!: add release", "");
+        assert_eq!(commit.kind.as_deref(), Some("feat"));
+        assert_eq!(commit.scope.as_deref(), Some("cli"));
+        assert!(commit.breaking);
+        assert_eq!(commit.summary, "add release");
+
+        let commit = Commit::parse("abc", "Fix: typo", "BREAKING CHANGE: renamed flag");
+        assert_eq!(commit.kind.as_deref(), Some("fix"));
+        assert!(commit.breaking);
+
+        let commit = Commit::parse("abc", "Update docs: README", "");
+        assert_eq!(commit.kind, None);
+        assert_eq!(commit.summary, "Update docs: README");
+    }
+
+    #[test]
+    fn finds_toml_versions_in_the_right_section() {
+        let manifest = "[package]\nname = \"x\"\nversion = \"0.3.0\"\n\n[dependencies]\nversion_check = \"1\"\n";
+        assert_eq!(toml_version(manifest, &["[package]"]), Some((2, "0.3.0".to_string())));
+
+        let manifest = "[dependencies]\nfoo = { version = \"1\" }\nversion = \"9.9.9\"\n[tool.poetry]\nversion = \"1.2.0\"\n";
+        assert_eq!(toml_version(manifest, &["[project]", "[tool.poetry]"]), Some((4, "1.2.0".to_string())));
+        assert_eq!(toml_version(manifest, &["[package]"]), None);
+    }
+
+    #[test]
+    fn suggests_bumps_from_commits() {
+        let commits = |subjects: &[&str]| -> Vec<Commit> {
+            subjects.iter().map(|subject| Commit::parse("abc", subject, "")).collect()
+        };
+        assert_eq!(suggested_bump(&commits(&["fix: a", "docs: b"]), &version("1.0.0")), Bump::Patch);
+        assert_eq!(suggested_bump(&commits(&["fix: a", "feat: b"]), &version("1.0.0")), Bump::Minor);
+        assert_eq!(suggested_bump(&commits(&["feat!: a"]), &version("1.0.0")), Bump::Major);
+        // Before 1.0, breaking changes only bump minor
+        assert_eq!(suggested_bump(&commits(&["feat!: a"]), &version("0.3.0")), Bump::Minor);
+    }
+}
@@ -0,0 +1,257 @@
+use std::env;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::{GitRepo, UI};
+
+// ============================================================================
+// LICENSES
+// ============================================================================
+
+struct License {
+    id: &'static str,
+    // `{year}` and `{author}` are filled in when the file is written
+    text: &'static str,
+}
+
+const LICENSES: &[License] = &[
+    License { id: "MIT", text: include_str!("licenses/MIT") },
+    License { id: "Apache-2.0", text: include_str!("licenses/Apache-2.0") },
+    License { id: "BSD-2-Clause", text: include_str!("licenses/BSD-2-Clause") },
+    License { id: "BSD-3-Clause", text: include_str!("licenses/BSD-3-Clause") },
+    License { id: "ISC", text: include_str!("licenses/ISC") },
+    License { id: "Unlicense", text: include_str!("licenses/Unlicense") },
+];
+
+const DEFAULT_LICENSE: &str = "MIT";
+
+fn find_license(id: &str) -> Option<&'static License> {
+    LICENSES.iter().find(|l| l.id.eq_ignore_ascii_case(id.trim()))
+}
+
+pub fn license_ids() -> Vec<&'static str> {
+    LICENSES.iter().map(|l| l.id).collect()
+}
+
+/// Returns `Ok(None)` for `none`/`-`, which means "no LICENSE file".
+fn parse_license(id: &str) -> Result<Option<&'static License>> {
+    if id.eq_ignore_ascii_case("none") || id == "-" {
+        return Ok(None);
+    }
+    find_license(id).map(Some).ok_or_else(|| GitError::Usage(t!(
+        "scaffold.unknown_license",
+        license = id,
+        available = license_ids().join(", ")
+    )))
+}
+
+/// The current calendar year (UTC), from the days-since-epoch civil date
+/// conversion.
+fn current_year() -> i64 {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| (d.as_secs() / 86_400) as i64)
+        .unwrap_or(0);
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    yoe + era * 400 + i64::from(mp >= 10)
+}
+
+fn copyright_holder(repo: &GitRepo) -> String {
+    repo.run_command_with_output(&["config", "--get", "user.name"])
+        .ok()
+        .filter(|name| !name.is_empty())
+        .or_else(|| env::var("USER").ok())
+        .unwrap_or_else(|| UI::prompt_input(&t!("scaffold.author_prompt")))
+}
+
+// ============================================================================
+// FILE TEMPLATES
+// ============================================================================
+
+const EDITORCONFIG: &str = "\
+root = true
+
+[*]
+charset = utf-8
+end_of_line = lf
+insert_final_newline = true
+trim_trailing_whitespace = true
+indent_style = space
+indent_size = 4
+
+[*.{md,markdown}]
+trim_trailing_whitespace = false
+
+[*.{json,toml,yml,yaml}]
+indent_size = 2
+
+[Makefile]
+indent_style = tab
+";
+
+const GITATTRIBUTES: &str = "\
+# Normalize line endings; keep Windows scripts in CRLF
+* text=auto eol=lf
+*.bat text eol=crlf
+*.cmd text eol=crlf
+*.ps1 text eol=crlf
+
+# Never diff or merge binary assets
+*.png binary
+*.jpg binary
+*.jpeg binary
+*.gif binary
+*.ico binary
+*.pdf binary
+*.zip binary
+*.gz binary
+";
+
+/// What the generated README describes.
+pub struct Project<'a> {
+    pub name: &'a str,
+    pub description: &'a str,
+}
+
+fn readme(project: &Project, license: Option<&License>) -> String {
+    let mut out = format!("# {}\n", project.name);
+    if !project.description.trim().is_empty() {
+        out.push_str(&format!("\n{}\n", project.description.trim()));
+    }
+    out.push_str(&format!("\n{}\n", t!("scaffold.readme_getting_started")));
+    if let Some(license) = license {
+        out.push_str(&format!("\n{}\n", t!("scaffold.readme_license", license = license.id)));
+    }
+    out
+}
+
+struct PlannedFile {
+    path: &'static str,
+    content: String,
+}
+
+// ============================================================================
+// SCAFFOLDING
+// ============================================================================
+
+/// Whether new repositories get README/LICENSE/editor configuration files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Selection {
+    /// Ask before generating anything.
+    #[default]
+    Ask,
+    /// Generate without asking first (`--scaffold`); the preview is still
+    /// confirmed before writing.
+    Yes,
+    /// Never generate (`--no-scaffold`).
+    Skip,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub selection: Selection,
+    /// SPDX identifier from `--license`, or `none`.
+    pub license: Option<String>,
+}
+
+impl Options {
+    pub fn skip() -> Self {
+        Options { selection: Selection::Skip, license: None }
+    }
+}
+
+fn choose_license(options: &Options) -> Result<Option<&'static License>> {
+    if let Some(id) = &options.license {
+        return parse_license(id);
+    }
+    println!("{}", UI::center_text(&t!("scaffold.licenses_available", licenses = license_ids().join(", "))));
+    loop {
+        let input = UI::prompt_input(&t!("scaffold.license_prompt", default = DEFAULT_LICENSE));
+        let id = if input.is_empty() { DEFAULT_LICENSE.to_string() } else { input };
+        match parse_license(&id) {
+            Ok(license) => return Ok(license),
+            Err(e) => println!("{}", UI::center_text(&format!("❌ {}", e))),
+        }
+    }
+}
+
+/// Builds the files to generate, leaving out any that already exist.
+fn plan(repo: &GitRepo, project: &Project, license: Option<&License>) -> Vec<PlannedFile> {
+    let mut files = vec![PlannedFile { path: "README.md", content: readme(project, license) }];
+    if let Some(license) = license {
+        let text = license.text
+            .replace("{year}", &current_year().to_string())
+            .replace("{author}", &copyright_holder(repo));
+        files.push(PlannedFile { path: "LICENSE", content: text });
+    }
+    files.push(PlannedFile { path: ".editorconfig", content: EDITORCONFIG.to_string() });
+    files.push(PlannedFile { path: ".gitattributes", content: GITATTRIBUTES.to_string() });
+
+    files.retain(|f| !repo.root.join(f.path).exists());
+    files
+}
+
+const PREVIEW_LINES: usize = 8;
+
+fn preview(files: &[PlannedFile]) {
+    println!("\n{}", UI::center_text(&t!("scaffold.preview_title")));
+    for file in files {
+        let total = file.content.lines().count();
+        println!("\n── {} ({}) ──", file.path, tn!("scaffold.preview_lines", total));
+        for line in file.content.lines().take(PREVIEW_LINES) {
+            println!("  {}", line);
+        }
+        if total > PREVIEW_LINES {
+            println!("  …");
+        }
+    }
+    println!();
+}
+
+/// Offers README, LICENSE, `.editorconfig` and `.gitattributes` for a freshly
+/// bootstrapped repository, previews them and commits whatever was written.
+pub fn scaffold(repo: &GitRepo, project: &Project, options: &Options) -> Result<()> {
+    match options.selection {
+        Selection::Skip => return Ok(()),
+        Selection::Ask => {
+            if !UI::prompt_yes_no(&t!("scaffold.ask")) {
+                return Ok(());
+            }
+        }
+        Selection::Yes => {}
+    }
+
+    let license = choose_license(options)?;
+    let files = plan(repo, project, license);
+    if files.is_empty() {
+        println!("{}", UI::center_text(&t!("scaffold.nothing_to_do")));
+        return Ok(());
+    }
+
+    preview(&files);
+    if !UI::prompt_yes_no(&t!("scaffold.confirm")) {
+        println!("{}", UI::center_text(&t!("scaffold.skipped")));
+        return Ok(());
+    }
+
+    for file in &files {
+        fs::write(repo.root.join(file.path), &file.content)
+            .map_err(|e| GitError::io(t!("error.write_file", path = file.path), e))?;
+    }
+
+    let mut add = vec!["add", "--"];
+    add.extend(files.iter().map(|f| f.path));
+    repo.run_command(&add)?;
+    repo.run_command(&["commit", "-m", "Add project scaffolding"])?;
+
+    let names: Vec<&str> = files.iter().map(|f| f.path).collect();
+    println!("\n{}", UI::center_text(&t!("scaffold.created", files = names.join(", "))));
+    Ok(())
+}
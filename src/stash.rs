@@ -0,0 +1,252 @@
+use std::io::{self, IsTerminal};
+
+use crate::diff::{self, Source};
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::queue;
+use crate::{GitRepo, UI};
+
+// ============================================================================
+// STASH MANAGER
+// ============================================================================
+
+/// Message prefix of the stashes syncgit makes itself. One still around
+/// after a run means a sync was interrupted before restoring it.
+pub const AUTO_PREFIX: &str = "syncgit-auto:";
+
+pub struct Stash {
+    pub index: usize,
+    pub branch: String,
+    pub message: String,
+    /// Unix seconds.
+    pub created: u64,
+}
+
+impl Stash {
+    pub fn reference(&self) -> String {
+        format!("stash@{{{}}}", self.index)
+    }
+
+    pub fn is_auto(&self) -> bool {
+        self.message.starts_with(AUTO_PREFIX)
+    }
+
+    /// `(files, added, removed)`, untracked files included.
+    fn summary(&self, repo: &GitRepo) -> (usize, usize, usize) {
+        let reference = self.reference();
+        let numstat = repo.run_command_with_output(&["stash", "show", "--numstat", "--include-untracked", &reference])
+            // Before git 2.32 `stash show` knows nothing about untracked files
+            .or_else(|_| repo.run_command_with_output(&["stash", "show", "--numstat", &reference]))
+            .unwrap_or_default();
+        numstat.lines().fold((0, 0, 0), |(files, added, removed), line| {
+            let mut fields = line.split('\t');
+            let count = |field: Option<&str>| field.and_then(|f| f.parse::<usize>().ok()).unwrap_or(0);
+            (files + 1, added + count(fields.next()), removed + count(fields.next()))
+        })
+    }
+}
+
+/// Every stash, newest first.
+pub fn list(repo: &GitRepo) -> Vec<Stash> {
+    let output = repo.run_command_with_output(&["stash", "list", "--format=%gd%x1f%ct%x1f%gs"]).unwrap_or_default();
+    output.lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\x1f');
+            let (reference, created, subject) = (fields.next()?, fields.next()?, fields.next()?);
+            let index = reference.strip_prefix("stash@{")?.strip_suffix('}')?.parse().ok()?;
+            // "WIP on main: 1a2b3c4 subject" or "On main: message"
+            let (branch, message) = subject.strip_prefix("WIP on ")
+                .or_else(|| subject.strip_prefix("On "))
+                .and_then(|rest| rest.split_once(": "))
+                .unwrap_or(("?", subject));
+            Some(Stash {
+                index,
+                branch: branch.to_string(),
+                message: message.to_string(),
+                created: created.parse().unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+fn stash_ref(repo: &GitRepo) -> Option<String> {
+    repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", "refs/stash"]).ok()
+}
+
+/// Stashes every local change, untracked files included, marked as made
+/// by syncgit. Returns whether there was anything to stash.
+pub fn auto_stash(repo: &GitRepo, purpose: &str) -> Result<bool> {
+    let before = stash_ref(repo);
+    repo.run_command(&["stash", "push", "--include-untracked", "-m", &format!("{} {}", AUTO_PREFIX, purpose)])?;
+    Ok(stash_ref(repo) != before)
+}
+
+/// Brings back the stash `auto_stash` just made. If it doesn't apply
+/// cleanly it stays in the list, and the user is told where to find it.
+pub fn restore_auto_stash(repo: &GitRepo) -> Result<()> {
+    repo.run_command(&["stash", "pop"]).inspect_err(|_| {
+        println!("{}", UI::center_text(&t!("stash.auto_kept")));
+        println!("{}", UI::center_text(&t!("stash.manage_hint")));
+    })
+}
+
+/// What the startup checks say about existing stashes. Asks before going on
+/// only when syncgit left some behind, since those hold work from a sync
+/// that never finished.
+pub fn check_leftovers(repo: &GitRepo) -> Result<()> {
+    let stashes = list(repo);
+    let auto = stashes.iter().filter(|s| s.is_auto()).count();
+    if auto > 0 {
+        println!("{}", UI::center_text(&tn!("stash.leftover_warning", auto)));
+        println!("{}", UI::center_text(&t!("stash.manage_hint")));
+        if !UI::prompt_yes_no(&t!("prompt.continue_anyway")) {
+            return Err(GitError::Cancelled(t!("cancel.by_user")));
+        }
+    } else if !stashes.is_empty() {
+        println!("{}", UI::center_text(&tn!("stash.existing_note", stashes.len())));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// COMMANDS
+// ============================================================================
+
+fn find(repo: &GitRepo, index: usize) -> Result<Stash> {
+    list(repo).into_iter()
+        .find(|s| s.index == index)
+        .ok_or_else(|| GitError::Usage(t!("stash.not_found", stash = format!("stash@{{{}}}", index))))
+}
+
+fn print_entry(repo: &GitRepo, stash: &Stash) {
+    let (files, added, removed) = stash.summary(repo);
+    println!(
+        "{}",
+        t!(
+            "stash.entry",
+            stash = stash.reference(),
+            branch = stash.branch,
+            age = queue::age(stash.created),
+            files = tn!("stash.files", files, added = added, removed = removed)
+        )
+    );
+    if stash.is_auto() {
+        println!("{}", t!("stash.entry_auto", message = stash.message.trim_start_matches(AUTO_PREFIX).trim()));
+    } else {
+        println!("{}", t!("stash.entry_message", message = stash.message));
+    }
+}
+
+fn print_list(repo: &GitRepo, stashes: &[Stash]) {
+    println!("{}", UI::center_text(&tn!("stash.title", stashes.len())));
+    UI::print_separator();
+    for stash in stashes {
+        print_entry(repo, stash);
+    }
+    UI::print_separator();
+    if let Some(auto) = stashes.iter().find(|s| s.is_auto()) {
+        println!("{}", UI::center_text(&t!("stash.restore_hint", index = auto.index)));
+    }
+}
+
+/// `syncgit stash list`
+pub fn print_status() -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    let stashes = list(&repo);
+    if stashes.is_empty() {
+        println!("{}", UI::center_text(&t!("stash.empty")));
+        return Ok(());
+    }
+    print_list(&repo, &stashes);
+    Ok(())
+}
+
+/// `syncgit stash show [n]`: the stash's changes in the diff viewer.
+pub fn show(index: usize) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    find(&repo, index)?;
+    diff::view(&repo, ".", Source::Stash(index))
+}
+
+fn apply_stash(repo: &GitRepo, stash: &Stash, pop: bool) -> Result<()> {
+    let reference = stash.reference();
+    repo.run_command(&["stash", if pop { "pop" } else { "apply" }, &reference])?;
+    println!("{}", UI::center_text(&if pop { t!("stash.popped", stash = reference) } else { t!("stash.applied", stash = reference) }));
+    Ok(())
+}
+
+fn drop_stash(repo: &GitRepo, stash: &Stash) -> Result<()> {
+    print_entry(repo, stash);
+    if !UI::prompt_yes_no(&t!("stash.drop_confirm", stash = stash.reference())) {
+        return Err(GitError::Cancelled(t!("cancel.operation")));
+    }
+    repo.run_command_with_output(&["stash", "drop", &stash.reference()])?;
+    println!("{}", UI::center_text(&t!("stash.dropped", stash = stash.reference())));
+    Ok(())
+}
+
+/// `syncgit stash apply|pop [n]`
+pub fn apply(index: usize, pop: bool) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    let stash = find(&repo, index)?;
+    apply_stash(&repo, &stash, pop)
+}
+
+/// `syncgit stash drop [n]`
+pub fn drop(index: usize) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    let stash = find(&repo, index)?;
+    drop_stash(&repo, &stash)
+}
+
+/// `syncgit stash`: lists the stashes and lets the user pick one to show,
+/// apply, pop or drop, until Enter on an empty line.
+pub fn manage() -> Result<()> {
+    if !io::stdin().is_terminal() {
+        return print_status();
+    }
+    let repo = GitRepo::from_current_dir()?;
+    loop {
+        let stashes = list(&repo);
+        if stashes.is_empty() {
+            println!("{}", UI::center_text(&t!("stash.empty")));
+            return Ok(());
+        }
+        print_list(&repo, &stashes);
+
+        let choice = UI::prompt_input(&t!("stash.choose"));
+        if choice.is_empty() {
+            return Ok(());
+        }
+        let Some(stash) = parse_index(&choice).and_then(|i| stashes.into_iter().find(|s| s.index == i)) else {
+            println!("{}", UI::center_text(&t!("stash.not_found", stash = choice)));
+            continue;
+        };
+
+        let action = UI::prompt_input(&t!("stash.action_prompt", stash = stash.reference()));
+        let result = if UI::is_answer(&action, &t!("stash.answers.show")) {
+            diff::view(&repo, ".", Source::Stash(stash.index))
+        } else if UI::is_answer(&action, &t!("stash.answers.apply")) {
+            apply_stash(&repo, &stash, false)
+        } else if UI::is_answer(&action, &t!("stash.answers.pop")) {
+            apply_stash(&repo, &stash, true)
+        } else if UI::is_answer(&action, &t!("stash.answers.drop")) {
+            drop_stash(&repo, &stash)
+        } else {
+            Ok(())
+        };
+        if let Err(e) = result {
+            println!("\n{}: {}", UI::center_text(&t!("main.warning")), e);
+        }
+        UI::print_separator();
+    }
+}
+
+/// `3` or `stash@{3}`.
+pub fn parse_index(arg: &str) -> Option<usize> {
+    arg.strip_prefix("stash@{")
+        .and_then(|rest| rest.strip_suffix('}'))
+        .unwrap_or(arg)
+        .parse()
+        .ok()
+}
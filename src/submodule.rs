@@ -0,0 +1,331 @@
+use std::path::PathBuf;
+
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::push;
+use crate::{compute_pathspec, stage_and_commit, GitRepo, UI};
+
+// ============================================================================
+// SUBMODULES
+// ============================================================================
+
+// A superproject records one commit per submodule (its "pointer"). Syncing
+// the superproject is only useful to others if every pointer refers to a
+// commit they can fetch, so submodule changes are committed and pushed
+// before the superproject that points at them.
+
+/// Where a submodule's checkout stands relative to the commit the
+/// superproject records for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Checkout {
+    /// At the recorded commit.
+    Recorded,
+    /// Has commits the superproject doesn't point at yet.
+    Ahead,
+    /// The superproject points at a newer commit (e.g. after a pull);
+    /// `git submodule update` brings the checkout there.
+    Behind,
+    Diverged,
+}
+
+pub struct Submodule {
+    /// Relative to the superproject root.
+    pub path: String,
+    /// `None` until `git submodule update --init` clones it.
+    pub repo: Option<GitRepo>,
+    pub checkout: Checkout,
+    pub conflict: bool,
+    /// Uncommitted changes inside the submodule.
+    pub modified: bool,
+    /// Commits on its HEAD that none of its remote-tracking branches have.
+    pub unpushed: usize,
+    pub detached: bool,
+}
+
+fn is_ancestor(repo: &GitRepo, ancestor: &str, descendant: &str) -> bool {
+    repo.run_command_with_output(&["merge-base", "--is-ancestor", ancestor, descendant]).is_ok()
+}
+
+/// Commits reachable from `commit` that no remote-tracking branch has.
+fn unpushed_count(repo: &GitRepo, commit: &str) -> usize {
+    repo.run_command_with_output(&["rev-list", "--count", commit, "--not", "--remotes"])
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(0)
+}
+
+/// The direct submodules of `repo`.
+pub fn list(repo: &GitRepo) -> Vec<Submodule> {
+    // Cheap check first: most repositories have none
+    if !repo.root.join(".gitmodules").exists() {
+        return Vec::new();
+    }
+    let status = repo.run_command_with_output(&["submodule", "status"]).unwrap_or_default();
+    status.lines()
+        .filter_map(|line| {
+            // "<state><recorded sha> <path> (<describe>)"
+            let state = line.chars().next()?;
+            let mut fields = line[state.len_utf8()..].split_whitespace();
+            let recorded = fields.next()?.to_string();
+            let path = fields.next()?.to_string();
+
+            let repo = (state != '-')
+                .then(|| GitRepo::find_from_path(&repo.root.join(&path)))
+                .flatten()
+                .filter(|sub| sub.root != repo.root);
+            let mut submodule = Submodule {
+                path,
+                repo: None,
+                checkout: Checkout::Recorded,
+                conflict: state == 'U',
+                modified: false,
+                unpushed: 0,
+                detached: false,
+            };
+            let Some(sub) = repo else {
+                return Some(submodule);
+            };
+
+            if state == '+' {
+                submodule.checkout = if is_ancestor(&sub, &recorded, "HEAD") {
+                    Checkout::Ahead
+                } else if is_ancestor(&sub, "HEAD", &recorded) {
+                    Checkout::Behind
+                } else {
+                    Checkout::Diverged
+                };
+            }
+            submodule.modified = sub.run_command_with_output(&["status", "--porcelain"])
+                .map(|s| !s.is_empty())
+                .unwrap_or(false);
+            submodule.unpushed = unpushed_count(&sub, "HEAD");
+            submodule.detached = sub.run_command_with_output(&["symbolic-ref", "-q", "HEAD"]).is_err();
+            submodule.repo = Some(sub);
+            Some(submodule)
+        })
+        .collect()
+}
+
+/// The superproject `repo` is a submodule of, if any.
+fn superproject(repo: &GitRepo) -> Option<GitRepo> {
+    let path = repo.run_command_with_output(&["rev-parse", "--show-superproject-working-tree"]).ok()?;
+    if path.is_empty() {
+        return None;
+    }
+    GitRepo::find_from_path(&PathBuf::from(path))
+}
+
+/// The outermost superproject above `repo`, and which of its submodules
+/// leads down to `repo`. `repo` itself if it isn't a submodule.
+pub fn outermost(repo: GitRepo) -> (GitRepo, Option<PathBuf>) {
+    let mut current = repo;
+    let mut entry = None;
+    while let Some(parent) = superproject(&current) {
+        entry = Some(current.root.clone());
+        current = parent;
+    }
+    (current, entry)
+}
+
+/// Only the submodules inside `pathspec`.
+fn in_pathspec(submodules: Vec<Submodule>, pathspec: &str) -> Vec<Submodule> {
+    let prefix = pathspec.trim_end_matches('/');
+    submodules.into_iter()
+        .filter(|s| prefix == "." || s.path == prefix || s.path.starts_with(&format!("{}/", prefix)) || prefix.starts_with(&format!("{}/", s.path)))
+        .collect()
+}
+
+fn describe(submodule: &Submodule) -> String {
+    if submodule.repo.is_none() {
+        return t!("submodule.state.uninitialized");
+    }
+    let mut states = Vec::new();
+    if submodule.conflict {
+        states.push(t!("submodule.state.conflict"));
+    }
+    match submodule.checkout {
+        Checkout::Recorded => {}
+        Checkout::Ahead => states.push(t!("submodule.state.ahead")),
+        Checkout::Behind => states.push(t!("submodule.state.behind")),
+        Checkout::Diverged => states.push(t!("submodule.state.diverged")),
+    }
+    if submodule.modified {
+        states.push(t!("submodule.state.modified"));
+    }
+    if submodule.unpushed > 0 {
+        states.push(tn!("submodule.state.unpushed", submodule.unpushed));
+    }
+    if submodule.detached {
+        states.push(t!("submodule.state.detached"));
+    }
+    if states.is_empty() {
+        states.push(t!("submodule.state.clean"));
+    }
+    states.join(", ")
+}
+
+fn print_tree(repo: &GitRepo, prefix: &str) {
+    for submodule in list(repo) {
+        println!("{}", t!("submodule.entry", path = format!("{}{}", prefix, submodule.path), state = describe(&submodule)));
+        if let Some(sub) = &submodule.repo {
+            print_tree(sub, &format!("{}{}/", prefix, submodule.path));
+        }
+    }
+}
+
+/// Lists every submodule, nested ones included, with its state.
+pub fn print_status(repo: &GitRepo) {
+    let submodules = list(repo);
+    if submodules.is_empty() {
+        return;
+    }
+    println!("{}", UI::center_text(&tn!("submodule.title", submodules.len())));
+    print_tree(repo, "");
+    if submodules.iter().any(|s| s.repo.is_none() || s.checkout == Checkout::Behind) {
+        println!("{}", UI::center_text(&t!("submodule.update_hint")));
+    }
+    UI::print_separator();
+}
+
+// ============================================================================
+// RECURSIVE COMMIT AND PUSH
+// ============================================================================
+
+/// Offers to commit uncommitted changes inside the submodules under
+/// `pathspec`, innermost first, so the superproject can then record them.
+pub fn commit_changes(repo: &GitRepo, pathspec: &str) -> Result<()> {
+    for submodule in in_pathspec(list(repo), pathspec) {
+        let Some(sub) = &submodule.repo else { continue };
+        let inner = compute_pathspec(&sub.root, &repo.root.join(pathspec));
+        commit_changes(sub, &inner)?;
+        if !submodule.modified {
+            continue;
+        }
+
+        UI::print_separator();
+        println!("{}", UI::center_text(&t!("submodule.has_changes", path = submodule.path)));
+        if submodule.detached {
+            println!("{}", UI::center_text(&t!("submodule.detached_commit")));
+            continue;
+        }
+        if !UI::prompt_yes_no(&t!("submodule.commit_first", path = submodule.path)) {
+            continue;
+        }
+        match stage_and_commit(sub, &inner) {
+            Ok(()) | Err(GitError::NoChanges) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+/// The commit `repo`'s HEAD records for a submodule.
+fn recorded_commit(repo: &GitRepo, rev: &str, path: &str) -> Option<String> {
+    repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", &format!("{}:{}", rev, path)]).ok()
+}
+
+/// Submodules whose commit recorded at `rev` isn't on any of their remotes,
+/// with that commit and how many commits up to it aren't pushed.
+fn unpushed_pointers(repo: &GitRepo, rev: &str) -> Vec<(Submodule, String, usize)> {
+    list(repo).into_iter()
+        .filter_map(|submodule| {
+            let sub = submodule.repo.as_ref()?;
+            let recorded = recorded_commit(repo, rev, &submodule.path)?;
+            let unpushed = unpushed_count(sub, &recorded);
+            (unpushed > 0).then_some((submodule, recorded, unpushed))
+        })
+        .collect()
+}
+
+/// A local branch that contains `commit`, to push it to that branch's remote.
+fn branch_containing(repo: &GitRepo, commit: &str) -> Option<String> {
+    let branches = repo.run_command_with_output(&["for-each-ref", "--contains", commit, "--format=%(refname:short)", "refs/heads/"]).ok()?;
+    branches.lines().next().map(str::to_string)
+}
+
+/// Runs before the superproject is pushed: offers to push each submodule
+/// whose recorded commit isn't on its remote yet, then asks for
+/// confirmation if any pointer still refers to an unpushed commit.
+pub fn push_first(repo: &GitRepo) -> Result<()> {
+    push_pointers(repo, "HEAD")
+}
+
+/// `push_first` for the pointers recorded at `rev`. The submodule's
+/// checked-out branch is pushed when it contains the recorded commit;
+/// otherwise the recorded commit itself goes to the remote branch of a
+/// local branch that contains it.
+fn push_pointers(repo: &GitRepo, rev: &str) -> Result<()> {
+    let pending = unpushed_pointers(repo, rev);
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    for (submodule, recorded, unpushed) in &pending {
+        let Some(sub) = &submodule.repo else { continue };
+        let commit = &recorded[..7.min(recorded.len())];
+        println!("{}", UI::center_text(&tn!("submodule.needs_push", *unpushed, path = submodule.path)));
+
+        let on_current_branch = !submodule.detached && is_ancestor(sub, recorded, "HEAD");
+        let target = if on_current_branch {
+            None
+        } else {
+            match branch_containing(sub, recorded) {
+                Some(branch) => Some(push::PushTarget::for_branch(sub, branch)),
+                None => {
+                    println!("{}", UI::center_text(&t!("submodule.commit_on_no_branch", path = submodule.path, commit = commit)));
+                    continue;
+                }
+            }
+        };
+        let question = match &target {
+            None => t!("submodule.push_first", path = submodule.path),
+            Some(target) => t!("submodule.push_commit_first", path = submodule.path, commit = commit, remote = target.remote, branch = target.remote_branch),
+        };
+        if !UI::prompt_yes_no(&question) {
+            continue;
+        }
+
+        let result = match &target {
+            None => sub.configure_auth_remote().and_then(|()| {
+                let set_upstream = sub.run_command_with_output(&["rev-parse", "--abbrev-ref", "@{u}"]).is_err();
+                // Nested submodules are handled by the same check inside this push
+                push::push_current_branch(sub, set_upstream)
+            }),
+            Some(target) => sub.run_command_with_output(&["remote", "get-url", "--push", &target.remote])
+                .map_err(|_| GitError::Other(t!("error.no_remote_url")))
+                .and_then(|url| sub.configure_auth(&url))
+                // Nested pointers are checked at the commit being pushed instead of HEAD
+                .and_then(|()| push_pointers(sub, recorded))
+                .and_then(|()| push::push_commit(sub, target, recorded)),
+        };
+        match result {
+            Ok(()) => println!("{}", UI::center_text(&t!("submodule.pushed", path = submodule.path))),
+            Err(e) => println!("{}", UI::center_text(&t!("submodule.push_failed", path = submodule.path, error = e))),
+        }
+    }
+
+    let still_pending = unpushed_pointers(repo, rev);
+    if still_pending.is_empty() {
+        return Ok(());
+    }
+    println!("{}", UI::center_text(&t!("submodule.dangling_warning")));
+    for (submodule, recorded, _) in &still_pending {
+        println!("{}", t!("submodule.dangling_entry", path = submodule.path, commit = &recorded[..7.min(recorded.len())]));
+    }
+    if !UI::prompt_yes_no(&t!("submodule.push_anyway")) {
+        return Err(GitError::Cancelled(t!("cancel.operation")));
+    }
+    Ok(())
+}
+
+/// The check `push_first` makes, for a queued push retried without
+/// prompts: fails while `branch` points at submodule commits that aren't
+/// on a remote, so the push waits until they are.
+pub fn check_pushed(repo: &GitRepo, branch: &str) -> Result<()> {
+    let pending = unpushed_pointers(repo, &format!("refs/heads/{}", branch));
+    if pending.is_empty() {
+        return Ok(());
+    }
+    let paths: Vec<&str> = pending.iter().map(|(submodule, _, _)| submodule.path.as_str()).collect();
+    Err(GitError::Other(t!("submodule.unpushed_queued", paths = paths.join(", "))))
+}
@@ -0,0 +1,439 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::push::PushTarget;
+use crate::{GitRepo, UI};
+
+// ============================================================================
+// PROJECT TASKS
+// ============================================================================
+
+// Checks declared in `.syncgit.toml`, run before committing or pushing:
+//
+//     [[task]]
+//     name = "format"
+//     run = "cargo fmt --check"
+//     fix = "cargo fmt"
+//     paths = ["*.rs"]
+//     when = ["pre-commit", "pre-push"]
+//
+// `paths` limits a task to changes matching any of the globs, and `{files}`
+// in `run` or `fix` expands to those changed files.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hook {
+    PreCommit,
+    PrePush,
+}
+
+impl Hook {
+    fn key(self) -> &'static str {
+        match self {
+            Hook::PreCommit => "pre-commit",
+            Hook::PrePush => "pre-push",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pre-commit" => Some(Hook::PreCommit),
+            "pre-push" => Some(Hook::PrePush),
+            _ => None,
+        }
+    }
+}
+
+pub struct Task {
+    pub name: String,
+    pub run: String,
+    /// Run to repair what `run` complains about, e.g. a formatter.
+    pub fix: Option<String>,
+    /// Globs of changed paths the task cares about; empty means any.
+    pub paths: Vec<String>,
+    pub hooks: Vec<Hook>,
+}
+
+fn string_list(table: &toml::Table, key: &str, task: &str) -> Result<Vec<String>> {
+    match table.get(key) {
+        None => Ok(Vec::new()),
+        Some(toml::Value::String(s)) => Ok(vec![s.clone()]),
+        Some(toml::Value::Array(items)) => items.iter()
+            .map(|item| item.as_str().map(str::to_string))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| config::invalid(t!("tasks.expected_strings", task = task, key = key))),
+        Some(_) => Err(config::invalid(t!("tasks.expected_strings", task = task, key = key))),
+    }
+}
+
+fn parse_task(index: usize, value: &toml::Value) -> Result<Task> {
+    let table = value.as_table()
+        .ok_or_else(|| config::invalid(t!("tasks.expected_table", index = index + 1)))?;
+    let text = |key: &str| table.get(key).and_then(toml::Value::as_str).map(str::to_string);
+
+    let run = text("run").ok_or_else(|| config::invalid(t!("tasks.missing_run", index = index + 1)))?;
+    let name = text("name").unwrap_or_else(|| run.clone());
+    let hooks = match string_list(table, "when", &name)? {
+        hooks if hooks.is_empty() => vec![Hook::PreCommit],
+        hooks => hooks.iter()
+            .map(|h| Hook::parse(h).ok_or_else(|| config::invalid(t!("tasks.unknown_hook", task = name, hook = h))))
+            .collect::<Result<_>>()?,
+    };
+    let paths = string_list(table, "paths", &name)?;
+    Ok(Task { fix: text("fix"), paths, hooks, name, run })
+}
+
+/// The `[[task]]` entries of `.syncgit.toml`, in order.
+pub fn load(repo: &GitRepo) -> Result<Vec<Task>> {
+    let Some(config) = config::load(repo)? else {
+        return Ok(Vec::new());
+    };
+    match config.get("task") {
+        None => Ok(Vec::new()),
+        Some(toml::Value::Array(tasks)) => tasks.iter().enumerate().map(|(i, task)| parse_task(i, task)).collect(),
+        Some(_) => Err(config::invalid(t!("tasks.expected_array"))),
+    }
+}
+
+// ============================================================================
+// PATH FILTERS
+// ============================================================================
+
+/// `*` and `?` stay within one path segment, `**` crosses them.
+fn glob(pattern: &[u8], path: &[u8]) -> bool {
+    match (pattern.first(), path.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) if pattern.get(1) == Some(&b'*') => {
+            // "**/" also matches no directory at all
+            let (rest, whole_segments) = match pattern[2..].strip_prefix(b"/") {
+                Some(rest) => (rest, true),
+                None => (&pattern[2..], false),
+            };
+            (0..=path.len())
+                .filter(|&i| !whole_segments || i == 0 || path[i - 1] == b'/')
+                .any(|i| glob(rest, &path[i..]))
+        }
+        (Some(b'*'), _) => glob(&pattern[1..], path) || (path.first().is_some_and(|&c| c != b'/') && glob(pattern, &path[1..])),
+        (Some(b'?'), Some(&c)) if c != b'/' => glob(&pattern[1..], &path[1..]),
+        (Some(p), Some(c)) if p == c => glob(&pattern[1..], &path[1..]),
+        _ => false,
+    }
+}
+
+/// As in `.gitignore`: a pattern without a slash matches the file name
+/// anywhere, one ending in a slash matches everything below it.
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+    if pattern.ends_with('/') {
+        return glob(format!("{}**", pattern).as_bytes(), path.as_bytes());
+    }
+    if !pattern.contains('/') {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        return glob(pattern.as_bytes(), name.as_bytes());
+    }
+    glob(pattern.as_bytes(), path.as_bytes())
+}
+
+/// Quotes for `sh`. Git runs credential helpers through `sh` on every
+/// platform, so this is also right for their arguments.
+pub fn shell_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+/// Quotes a file name for the shell `shell` starts: `cmd` only knows double
+/// quotes, which Windows file names can't contain.
+fn quote_file(path: &str) -> String {
+    if cfg!(windows) {
+        format!("\"{}\"", path)
+    } else {
+        shell_quote(path)
+    }
+}
+
+// ============================================================================
+// APPROVAL
+// ============================================================================
+
+// A cloned repository's `.syncgit.toml` could run anything, so its commands
+// only run once the user has approved them. The approval is a hash of the
+// commands, kept in the repository's local git config, which a clone never
+// brings along; changing any command asks again.
+
+const APPROVAL_KEY: &str = "syncgit.approvedTasks";
+
+/// `git hash-object` of every `run` and `fix` command, in order.
+fn commands_hash(repo: &GitRepo, tasks: &[Task]) -> Result<String> {
+    let commands: String = tasks.iter()
+        .map(|task| format!("{}\0{}\n", task.run, task.fix.as_deref().unwrap_or("")))
+        .collect();
+    let mut child = repo.create_command(["hash-object", "--stdin"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| GitError::io(t!("error.spawn_git"), e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(commands.as_bytes()).map_err(|e| GitError::io(t!("error.exec_git"), e))?;
+    }
+    let output = child.wait_with_output().map_err(|e| GitError::io(t!("error.exec_git"), e))?;
+    if !output.status.success() {
+        return Err(GitError::from_command(&["hash-object", "--stdin"], output.status, &String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Whether the tasks may run: approved before, or approved now after
+/// showing every command. Without `prompt` an unapproved config fails.
+fn approve(repo: &GitRepo, tasks: &[Task], prompt: bool) -> Result<bool> {
+    let hash = commands_hash(repo, tasks)?;
+    if repo.run_command_with_output(&["config", "--local", "--get", APPROVAL_KEY]).is_ok_and(|approved| approved == hash) {
+        return Ok(true);
+    }
+    if !prompt {
+        return Err(GitError::Other(t!("tasks.unapproved_unattended")));
+    }
+
+    UI::print_separator();
+    println!("{}", UI::center_text(&t!("tasks.approval_header")));
+    for task in tasks {
+        println!("{}", t!("tasks.approval_command", name = task.name, command = task.run));
+        if let Some(fix) = &task.fix {
+            println!("{}", t!("tasks.approval_command", name = t!("tasks.fix_name", name = task.name), command = fix));
+        }
+    }
+    if !UI::prompt_yes_no(&t!("tasks.approval_confirm")) {
+        println!("{}", UI::center_text(&t!("tasks.not_approved")));
+        return Ok(false);
+    }
+    repo.run_command_with_output(&["config", "--local", APPROVAL_KEY, &hash])?;
+    Ok(true)
+}
+
+// ============================================================================
+// RUNNING
+// ============================================================================
+
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    }
+}
+
+fn format_duration(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs_f64();
+    if secs < 60.0 {
+        format!("{:.1}s", secs)
+    } else {
+        format!("{}m {:02}s", elapsed.as_secs() / 60, elapsed.as_secs() % 60)
+    }
+}
+
+/// Runs a task's command from the repository root, output going straight
+/// to the terminal. Whether it succeeded.
+fn execute(repo: &GitRepo, hook: Hook, name: &str, command: &str) -> bool {
+    println!("{}", t!("tasks.running", name = name, command = command));
+    let started = Instant::now();
+    let status = shell(command)
+        .current_dir(&repo.root)
+        .env("SYNCGIT_HOOK", hook.key())
+        .status();
+    let elapsed = format_duration(started.elapsed());
+    match status {
+        Ok(status) if status.success() => {
+            println!("{}", t!("tasks.passed", name = name, time = elapsed));
+            true
+        }
+        Ok(status) => {
+            let code = status.code().map_or_else(|| String::from("?"), |c| c.to_string());
+            println!("{}", t!("tasks.failed", name = name, code = code, time = elapsed));
+            false
+        }
+        Err(e) => {
+            println!("{}", t!("tasks.spawn_failed", name = name, error = e));
+            false
+        }
+    }
+}
+
+/// Runs the tasks for `hook` against `changed` (paths relative to the
+/// root). A failing task lets the user fix and retry, skip it, or abort;
+/// without `prompt` it just fails. Commands the user hasn't approved don't
+/// run at all (see `approve`). With `restage`, the changed files still
+/// there are staged again before a retry, so fixes made for a pre-commit
+/// check end up in the commit.
+fn run(repo: &GitRepo, hook: Hook, changed: &[String], restage: bool, prompt: bool) -> Result<()> {
+    let all = load(repo)?;
+    if !all.iter().any(|task| task.hooks.contains(&hook)) || !approve(repo, &all, prompt)? {
+        return Ok(());
+    }
+    let tasks: Vec<Task> = all.into_iter().filter(|task| task.hooks.contains(&hook)).collect();
+
+    UI::print_separator();
+    println!("{}", UI::center_text(&tn!("tasks.header", tasks.len(), hook = hook.key())));
+    let started = Instant::now();
+    let (mut passed, mut skipped, mut overridden) = (0, 0, 0);
+
+    for task in &tasks {
+        let files: Vec<&String> = changed.iter()
+            .filter(|path| task.paths.is_empty() || task.paths.iter().any(|p| path_matches(p, path)))
+            .collect();
+        if files.is_empty() && (!task.paths.is_empty() || task.run.contains("{files}")) {
+            println!("{}", t!("tasks.not_applicable", name = task.name));
+            skipped += 1;
+            continue;
+        }
+        let quoted: Vec<String> = files.iter().map(|f| quote_file(f)).collect();
+        let files_arg = quoted.join(" ");
+        let command = task.run.replace("{files}", &files_arg);
+
+        let mut ok = true;
+        while !execute(repo, hook, &task.name, &command) {
+            if !prompt {
+                return Err(GitError::Other(t!("tasks.failed_unattended", name = task.name)));
+            }
+            let prompt = match &task.fix {
+                Some(fix) => t!("tasks.choice_fix", fix = fix),
+                None => t!("tasks.choice"),
+            };
+            let choice = UI::prompt_input(&prompt);
+            if let Some(fix) = task.fix.as_ref().filter(|_| UI::is_answer(&choice, &t!("tasks.answers.fix"))) {
+                execute(repo, hook, &t!("tasks.fix_name", name = task.name), &fix.replace("{files}", &files_arg));
+            } else if UI::is_answer(&choice, &t!("tasks.answers.skip")) {
+                println!("{}", t!("tasks.overridden", name = task.name));
+                ok = false;
+                break;
+            } else if !UI::is_answer(&choice, &t!("tasks.answers.retry")) {
+                return Err(GitError::Cancelled(t!("tasks.aborted", name = task.name)));
+            }
+            if restage {
+                restage_files(repo, changed)?;
+            }
+        }
+        if ok {
+            passed += 1;
+        } else {
+            overridden += 1;
+        }
+    }
+
+    let time = format_duration(started.elapsed());
+    let summary = if overridden > 0 {
+        t!("tasks.summary_overridden", passed = passed, overridden = overridden, skipped = skipped, time = time)
+    } else {
+        t!("tasks.summary", passed = passed, skipped = skipped, time = time)
+    };
+    println!("{}", UI::center_text(&summary));
+    Ok(())
+}
+
+/// Re-adds staged files after a fix. Only these, so a partly staged
+/// change doesn't pull in what was left out on purpose; deleted files
+/// need nothing.
+fn restage_files(repo: &GitRepo, files: &[String]) -> Result<()> {
+    let present: Vec<&str> = files.iter().filter(|f| repo.root.join(f).exists()).map(String::as_str).collect();
+    if present.is_empty() {
+        return Ok(());
+    }
+    let mut args = vec!["add", "--"];
+    args.extend(present);
+    repo.run_command_with_output(&args)?;
+    Ok(())
+}
+
+/// Whether any task runs on `hook`. A broken config counts as yes, so the
+/// caller goes on to report it.
+pub fn has_tasks(repo: &GitRepo, hook: Hook) -> bool {
+    load(repo).map_or(true, |tasks| tasks.iter().any(|task| task.hooks.contains(&hook)))
+}
+
+fn path_list(output: &str) -> Vec<String> {
+    output.split('\0').filter(|p| !p.is_empty()).map(str::to_string).collect()
+}
+
+/// Pre-commit tasks, against the staged changes under `pathspec`.
+pub fn before_commit(repo: &GitRepo, pathspec: &str) -> Result<()> {
+    let staged = repo.run_command_with_output(&["diff", "--cached", "--name-only", "-z", "--", pathspec])?;
+    run(repo, Hook::PreCommit, &path_list(&staged), true, true)
+}
+
+/// Every file the outgoing commits change. A branch the remote doesn't have
+/// yet counts all its files as changed.
+fn pushed_files(repo: &GitRepo, target: &PushTarget) -> Result<Vec<String>> {
+    let remote_ref = format!("refs/remotes/{}/{}", target.remote, target.remote_branch);
+    let local_ref = format!("refs/heads/{}", target.local_branch);
+    let changed = if repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", &remote_ref]).is_ok() {
+        repo.run_command_with_output(&["diff", "--name-only", "-z", &format!("{}...{}", remote_ref, local_ref)])?
+    } else {
+        repo.run_command_with_output(&["ls-tree", "-r", "--name-only", "-z", &local_ref])?
+    };
+    Ok(path_list(&changed))
+}
+
+/// Pre-push tasks, against every file the outgoing commits change.
+pub fn before_push(repo: &GitRepo, target: &PushTarget) -> Result<()> {
+    run(repo, Hook::PrePush, &pushed_files(repo, target)?, false, true)
+}
+
+/// Pre-push tasks for a queued push retried without anyone at the
+/// terminal: the first failing check stops the push.
+pub fn before_queued_push(repo: &GitRepo, target: &PushTarget) -> Result<()> {
+    run(repo, Hook::PrePush, &pushed_files(repo, target)?, false, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn star_stays_within_one_segment() {
+        assert!(path_matches("src/*.rs", "src/main.rs"));
+        assert!(!path_matches("src/*.rs", "src/i18n/mod.rs"));
+        assert!(path_matches("src/?ui.rs", "src/tui.rs"));
+        assert!(!path_matches("a?b", "a/b"));
+    }
+
+    #[test]
+    fn double_star_slash_matches_zero_or_more_directories() {
+        assert!(path_matches("src/**/*.rs", "src/main.rs"));
+        assert!(path_matches("src/**/*.rs", "src/i18n/mod.rs"));
+        assert!(path_matches("**/Cargo.toml", "Cargo.toml"));
+        assert!(path_matches("**/Cargo.toml", "crates/core/Cargo.toml"));
+        assert!(!path_matches("**/Cargo.toml", "crates/MyCargo.toml"));
+    }
+
+    #[test]
+    fn trailing_slash_matches_everything_below() {
+        assert!(path_matches("docs/", "docs/index.md"));
+        assert!(path_matches("docs/", "docs/guide/setup.md"));
+        assert!(path_matches("/docs/", "docs/index.md"));
+        assert!(!path_matches("docs/", "docs.md"));
+        assert!(!path_matches("docs/", "src/docs/index.md"));
+    }
+
+    #[test]
+    fn quotes_for_sh() {
+        assert_eq!(shell_quote("my file.txt"), "'my file.txt'");
+        assert_eq!(shell_quote("it's.md"), r"'it'\''s.md'");
+        if cfg!(windows) {
+            assert_eq!(quote_file("my file.txt"), "\"my file.txt\"");
+        } else {
+            assert_eq!(quote_file("my file.txt"), "'my file.txt'");
+        }
+    }
+
+    #[test]
+    fn bare_names_match_at_any_depth() {
+        assert!(path_matches("*.py", "setup.py"));
+        assert!(path_matches("*.py", "pkg/sub/module.py"));
+        assert!(path_matches("Makefile", "tools/Makefile"));
+        assert!(!path_matches("*.py", "pkg/module.pyc"));
+    }
+}
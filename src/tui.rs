@@ -0,0 +1,988 @@
+use std::fs;
+use std::io::{self, Stdout, Write};
+use std::time::{Duration, UNIX_EPOCH};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor};
+use crossterm::{cursor, queue, terminal};
+
+use crate::auth::{self, RemoteKind};
+use crate::diff;
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::identity;
+use crate::lfs;
+use crate::push::{self, PushTarget};
+use crate::queue as push_queue;
+use crate::tasks;
+use crate::{check_git_conflicts, group_status, incoming, GitRepo, UI};
+
+// ============================================================================
+// DASHBOARD
+// ============================================================================
+
+// `syncgit tui`: the same repository state the line-oriented flow prints,
+// kept on screen and refreshed. Quick actions (stage, commit, stash) run in
+// place; pull and push leave the dashboard so their previews and prompts
+// work exactly as in the normal flow.
+
+/// How often the panes are refreshed without a key press, to pick up
+/// changes made outside syncgit.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(3);
+const LOG_ENTRIES: usize = 30;
+const MIN_WIDTH: u16 = 60;
+const MIN_HEIGHT: u16 = 16;
+const TAB: &str = "    ";
+
+// ============================================================================
+// REPOSITORY SNAPSHOT
+// ============================================================================
+
+/// One line of `git status --porcelain`.
+struct FileEntry {
+    /// The two status columns: index, then working tree.
+    index: char,
+    worktree: char,
+    /// Every path the entry covers: a rename has the old and the new one.
+    paths: Vec<String>,
+    display: String,
+}
+
+impl FileEntry {
+    fn parse(line: &str) -> Option<Self> {
+        let mut status = line.chars();
+        let (index, worktree) = (status.next()?, status.next()?);
+        let display = line.get(3..)?.to_string();
+        let paths = match display.split_once(" -> ") {
+            Some((from, to)) => vec![unquote(from), unquote(to)],
+            None => vec![unquote(&display)],
+        };
+        Some(FileEntry { index, worktree, paths, display })
+    }
+
+    /// Everything in the entry is staged, so toggling unstages it.
+    fn fully_staged(&self) -> bool {
+        !matches!(self.index, ' ' | '?') && self.worktree == ' '
+    }
+}
+
+/// Undoes the C-style quoting git applies to paths with special characters.
+pub fn unquote(path: &str) -> String {
+    let Some(inner) = path.strip_prefix('"').and_then(|p| p.strip_suffix('"')) else {
+        return path.to_string();
+    };
+    let mut bytes = Vec::new();
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        match chars.next() {
+            Some('n') => bytes.push(b'\n'),
+            Some('t') => bytes.push(b'\t'),
+            Some(d @ '0'..='7') => {
+                let mut value = d.to_digit(8).unwrap_or(0);
+                for _ in 0..2 {
+                    if let Some(d) = chars.peek().and_then(|c| c.to_digit(8)) {
+                        value = value * 8 + d;
+                        chars.next();
+                    }
+                }
+                bytes.push(value as u8);
+            }
+            Some(other) => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+            }
+            None => bytes.push(b'\\'),
+        }
+    }
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
+/// Everything the panes show, re-read from git on every refresh.
+struct Snapshot {
+    branch: Option<String>,
+    upstream: Option<String>,
+    ahead: usize,
+    behind: usize,
+    stashes: usize,
+    /// Changed files grouped by top-level folder, as in the normal flow.
+    groups: Vec<(String, Vec<FileEntry>)>,
+    staged_diff: Vec<String>,
+    log: Vec<String>,
+    remote: Vec<String>,
+}
+
+impl Snapshot {
+    fn load(repo: &GitRepo, pathspec: &str) -> Self {
+        let git = |args: &[&str]| repo.run_command_with_output(args).ok();
+        let branch = git(&["symbolic-ref", "--short", "HEAD"]);
+        let upstream = git(&["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{u}"]);
+        let (ahead, behind) = upstream.as_ref()
+            .and_then(|_| git(&["rev-list", "--left-right", "--count", "HEAD...@{u}"]))
+            .and_then(|counts| {
+                let (ahead, behind) = counts.split_once(char::is_whitespace)?;
+                Some((ahead.trim().parse().ok()?, behind.trim().parse().ok()?))
+            })
+            .unwrap_or((0, 0));
+        let stashes = git(&["stash", "list"]).map_or(0, |list| list.lines().count());
+
+        let groups = group_status(repo, pathspec)
+            .into_iter()
+            .map(|(group, lines)| (group, lines.iter().filter_map(|l| FileEntry::parse(l)).collect()))
+            .collect();
+
+        let staged_diff = git(&["diff", "--cached", "--no-color", "--", pathspec])
+            .map(|diff| diff.lines().map(|l| l.replace('\t', TAB)).collect())
+            .unwrap_or_default();
+        let log = git(&["log", "--format=%h %s (%ar)", "-n", &LOG_ENTRIES.to_string(), "--"])
+            .map(|log| log.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+
+        let remote = remote_lines(repo, upstream.as_deref(), ahead, behind);
+        Snapshot { branch, upstream, ahead, behind, stashes, groups, staged_diff, log, remote }
+    }
+
+    fn files(&self) -> impl Iterator<Item = &FileEntry> {
+        self.groups.iter().flat_map(|(_, files)| files)
+    }
+}
+
+fn remote_lines(repo: &GitRepo, upstream: Option<&str>, ahead: usize, behind: usize) -> Vec<String> {
+    let Some(target) = PushTarget::resolve(repo).ok() else {
+        return vec![t!("tui.remote.detached")];
+    };
+    let Some(url) = push::push_url(repo) else {
+        return vec![t!("tui.remote.none")];
+    };
+
+    let kind = match RemoteKind::classify(&url) {
+        RemoteKind::GitHub { host } => t!("tui.remote.kind_github", host = host),
+        RemoteKind::Ssh { host } => t!("tui.remote.kind_ssh", host = host),
+        RemoteKind::OtherHost { host } => t!("tui.remote.kind_other", host = host),
+        RemoteKind::Local => t!("tui.remote.kind_local"),
+    };
+    let mut lines = vec![
+        t!("tui.remote.name", remote = target.remote, url = url),
+        kind,
+        match upstream {
+            Some(upstream) => t!("tui.remote.tracking", upstream = upstream, ahead = ahead, behind = behind),
+            None => t!("tui.remote.no_upstream", branch = target.local_branch),
+        },
+    ];
+
+    let fetched = fs::metadata(repo.git_path("FETCH_HEAD")).ok()
+        .and_then(|meta| meta.modified().ok())
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok());
+    lines.push(match fetched {
+        Some(time) => t!("tui.remote.fetched", age = push_queue::age(time.as_secs())),
+        None => t!("tui.remote.never_fetched"),
+    });
+
+    if let Some(entry) = push_queue::load().into_iter()
+        .find(|e| e.root == repo.root && e.branch == target.local_branch && e.remote == target.remote)
+    {
+        lines.push(t!("tui.remote.queued", age = push_queue::age(entry.queued_at)));
+    }
+    lines
+}
+
+// ============================================================================
+// DRAWING
+// ============================================================================
+
+#[derive(Clone, Copy)]
+struct Rect {
+    x: u16,
+    y: u16,
+    width: u16,
+    height: u16,
+}
+
+/// A run of text in one color.
+pub struct Span {
+    pub text: String,
+    pub color: Option<Color>,
+    pub background: Option<Color>,
+}
+
+impl Span {
+    pub fn plain(text: impl Into<String>) -> Self {
+        Span { text: text.into(), color: None, background: None }
+    }
+
+    pub fn colored(text: impl Into<String>, color: Color) -> Self {
+        Span { text: text.into(), color: Some(color), background: None }
+    }
+}
+
+#[derive(Default)]
+pub struct Line {
+    pub spans: Vec<Span>,
+    pub highlight: bool,
+    pub bold: bool,
+}
+
+impl Line {
+    pub fn plain(text: impl Into<String>) -> Self {
+        Line { spans: vec![Span::plain(text)], ..Default::default() }
+    }
+
+    pub fn colored(text: impl Into<String>, color: Color) -> Self {
+        Line { spans: vec![Span::colored(text, color)], ..Default::default() }
+    }
+}
+
+/// Terminal columns taken by `c`: two for wide CJK characters and emoji,
+/// none for combining marks, so lines never wrap and scroll the screen.
+pub fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x0300..=0x036F | 0x200B..=0x200F | 0x20E3 | 0xFE00..=0xFE0F => 0,
+        0x1100..=0x115F
+        | 0x231A..=0x231B
+        | 0x23E9..=0x23EC
+        | 0x23F0..=0x23F3
+        | 0x25FD..=0x25FE
+        | 0x2614..=0x2615
+        | 0x2648..=0x2653
+        | 0x267F
+        | 0x2693
+        | 0x26A1
+        | 0x26AA..=0x26AB
+        | 0x26BD..=0x26BE
+        | 0x26C4..=0x26C5
+        | 0x26CE
+        | 0x26D4
+        | 0x26EA
+        | 0x26F2..=0x26F5
+        | 0x26FA
+        | 0x26FD
+        | 0x2705
+        | 0x270A..=0x270B
+        | 0x2728
+        | 0x274C
+        | 0x274E
+        | 0x2753..=0x2757
+        | 0x2795..=0x2797
+        | 0x27B0
+        | 0x27BF
+        | 0x2B1B..=0x2B1C
+        | 0x2B50
+        | 0x2B55
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F680..=0x1F6FF
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+/// Prints `line` at the cursor, cut or padded to exactly `width` columns.
+pub fn print_line(out: &mut Stdout, line: &Line, width: usize) -> io::Result<()> {
+    if line.highlight {
+        queue!(out, SetAttribute(Attribute::Reverse))?;
+    }
+    if line.bold {
+        queue!(out, SetAttribute(Attribute::Bold))?;
+    }
+    let mut remaining = width;
+    for span in &line.spans {
+        let mut text = String::new();
+        for c in span.text.chars().filter(|c| !c.is_control()) {
+            let columns = char_width(c);
+            if columns > remaining {
+                break;
+            }
+            remaining -= columns;
+            text.push(c);
+        }
+        if let Some(color) = span.color {
+            queue!(out, SetForegroundColor(color))?;
+        }
+        if let Some(background) = span.background {
+            queue!(out, SetBackgroundColor(background))?;
+        }
+        queue!(out, Print(text))?;
+        if span.color.is_some() {
+            queue!(out, SetForegroundColor(Color::Reset))?;
+        }
+        if span.background.is_some() {
+            queue!(out, SetBackgroundColor(Color::Reset))?;
+        }
+    }
+    queue!(out, Print(" ".repeat(remaining)), SetAttribute(Attribute::Reset), ResetColor)
+}
+
+/// Draws a bordered pane with `lines[offset..]` inside.
+fn draw_pane(out: &mut Stdout, area: Rect, title: &str, lines: &[Line], offset: usize) -> io::Result<()> {
+    let inner = area.width.saturating_sub(2) as usize;
+    let title: String = format!(" {} ", title).chars().take(inner).collect();
+    let fill = "─".repeat(inner - title.chars().count());
+    queue!(out, cursor::MoveTo(area.x, area.y), Print("┌"), SetAttribute(Attribute::Bold), Print(title),
+        SetAttribute(Attribute::Reset), Print(fill), Print("┐"))?;
+
+    let rows = area.height.saturating_sub(2);
+    for row in 0..rows {
+        queue!(out, cursor::MoveTo(area.x, area.y + 1 + row), Print("│"))?;
+        match lines.get(offset + row as usize) {
+            Some(line) => print_line(out, line, inner)?,
+            None => queue!(out, Print(" ".repeat(inner)))?,
+        }
+        queue!(out, Print("│"))?;
+    }
+    queue!(out, cursor::MoveTo(area.x, area.y + area.height - 1), Print("└"), Print("─".repeat(inner)), Print("┘"))
+}
+
+/// Status columns: staged in green, unstaged in red, conflicts stand out.
+fn status_color(column: char, staged: bool) -> Color {
+    match column {
+        '?' | '!' => Color::DarkGrey,
+        'U' => Color::Magenta,
+        _ if staged => Color::Green,
+        _ => Color::Red,
+    }
+}
+
+fn diff_line(line: &str) -> Line {
+    match line.chars().next() {
+        _ if line.starts_with("+++") || line.starts_with("---") => Line { bold: true, ..Line::plain(line) },
+        Some('+') => Line::colored(line, Color::Green),
+        Some('-') => Line::colored(line, Color::Red),
+        _ if line.starts_with("@@") => Line::colored(line, Color::Cyan),
+        _ if line.starts_with("diff --git") => Line { bold: true, ..Line::colored(line, Color::Yellow) },
+        _ => Line::plain(line),
+    }
+}
+
+// ============================================================================
+// TERMINAL
+// ============================================================================
+
+/// Raw mode on the alternate screen. Dropping it restores the terminal, even
+/// when unwinding from a panic.
+pub struct Screen {
+    pub out: Stdout,
+    active: bool,
+}
+
+impl Screen {
+    pub fn enter() -> io::Result<Self> {
+        let mut screen = Screen { out: io::stdout(), active: false };
+        screen.resume()?;
+        Ok(screen)
+    }
+
+    fn resume(&mut self) -> io::Result<()> {
+        terminal::enable_raw_mode()?;
+        self.active = true;
+        crossterm::execute!(self.out, terminal::EnterAlternateScreen, cursor::Hide, terminal::Clear(terminal::ClearType::All))
+    }
+
+    pub fn leave(&mut self) -> io::Result<()> {
+        if !self.active {
+            return Ok(());
+        }
+        self.active = false;
+        crossterm::execute!(self.out, ResetColor, cursor::Show, terminal::LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = self.leave();
+    }
+}
+
+pub fn terminal_error(e: io::Error) -> GitError {
+    GitError::io(t!("tui.terminal_error"), e)
+}
+
+// ============================================================================
+// APPLICATION
+// ============================================================================
+
+enum Mode {
+    Browse,
+    /// Typing a commit message.
+    Commit(String),
+}
+
+struct App<'a> {
+    repo: &'a GitRepo,
+    pathspec: String,
+    snapshot: Snapshot,
+    mode: Mode,
+    /// Index of the selected file, across all groups.
+    selected: usize,
+    changes_offset: usize,
+    diff_offset: usize,
+    diff_rows: usize,
+    /// The outcome of the last action, shown in place of the key help.
+    message: Option<(String, bool)>,
+}
+
+impl<'a> App<'a> {
+    fn new(repo: &'a GitRepo, pathspec: String) -> Self {
+        let snapshot = Snapshot::load(repo, &pathspec);
+        App {
+            repo,
+            pathspec,
+            snapshot,
+            mode: Mode::Browse,
+            selected: 0,
+            changes_offset: 0,
+            diff_offset: 0,
+            diff_rows: 0,
+            message: None,
+        }
+    }
+
+    fn refresh(&mut self) {
+        self.snapshot = Snapshot::load(self.repo, &self.pathspec);
+        let files = self.snapshot.files().count();
+        self.selected = self.selected.min(files.saturating_sub(1));
+        self.diff_offset = self.diff_offset.min(self.snapshot.staged_diff.len().saturating_sub(1));
+    }
+
+    fn info(&mut self, message: String) {
+        self.message = Some((message, false));
+    }
+
+    fn error(&mut self, error: &GitError) {
+        let text = error.to_string();
+        let first = text.lines().find(|l| !l.trim().is_empty()).unwrap_or_default().trim().to_string();
+        self.message = Some((first, true));
+    }
+
+    // ------------------------------------------------------------------------
+    // Drawing
+    // ------------------------------------------------------------------------
+
+    fn draw(&mut self, out: &mut Stdout) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        if width < MIN_WIDTH || height < MIN_HEIGHT {
+            queue!(out, terminal::Clear(terminal::ClearType::All), cursor::MoveTo(0, 0),
+                Print(t!("tui.too_small", width = MIN_WIDTH, height = MIN_HEIGHT)))?;
+            return out.flush();
+        }
+
+        queue!(out, cursor::MoveTo(0, 0))?;
+        print_line(out, &self.header(), width as usize)?;
+
+        let body = height - 2;
+        let bottom = (body / 3).max(7);
+        let top = body - bottom;
+        let left = width / 2;
+        let right = width - left;
+
+        let changes = Rect { x: 0, y: 1, width: left, height: top };
+        let (lines, selected_row) = self.changes_lines();
+        let visible = changes.height.saturating_sub(2) as usize;
+        if let Some(row) = selected_row {
+            if row < self.changes_offset {
+                self.changes_offset = row.saturating_sub(1);
+            } else if row >= self.changes_offset + visible {
+                self.changes_offset = row + 1 - visible;
+            }
+        }
+        self.changes_offset = self.changes_offset.min(lines.len().saturating_sub(visible));
+        let title = tn!("tui.pane.changes", self.snapshot.files().count());
+        draw_pane(out, changes, &title, &lines, self.changes_offset)?;
+
+        let diff = Rect { x: left, y: 1, width: right, height: top };
+        self.diff_rows = diff.height.saturating_sub(2) as usize;
+        let lines: Vec<Line> = if self.snapshot.staged_diff.is_empty() {
+            vec![Line::colored(t!("tui.nothing_staged"), Color::DarkGrey)]
+        } else {
+            self.snapshot.staged_diff.iter().map(|l| diff_line(l)).collect()
+        };
+        draw_pane(out, diff, &t!("tui.pane.staged_diff"), &lines, self.diff_offset)?;
+
+        let log = Rect { x: 0, y: 1 + top, width: left, height: bottom };
+        let lines: Vec<Line> = if self.snapshot.log.is_empty() {
+            vec![Line::colored(t!("tui.no_commits"), Color::DarkGrey)]
+        } else {
+            self.snapshot.log.iter()
+                .map(|entry| match entry.split_once(' ') {
+                    Some((hash, rest)) => Line { spans: vec![Span::colored(hash, Color::Yellow), Span::plain(format!(" {}", rest))], ..Default::default() },
+                    None => Line::plain(entry.as_str()),
+                })
+                .collect()
+        };
+        draw_pane(out, log, &t!("tui.pane.log"), &lines, 0)?;
+
+        let remote = Rect { x: left, y: 1 + top, width: right, height: bottom };
+        let lines: Vec<Line> = self.snapshot.remote.iter().map(|l| Line::plain(l.as_str())).collect();
+        draw_pane(out, remote, &t!("tui.pane.remote"), &lines, 0)?;
+
+        queue!(out, cursor::MoveTo(0, height - 1))?;
+        print_line(out, &self.footer(), width as usize)?;
+        out.flush()
+    }
+
+    fn header(&self) -> Line {
+        let snapshot = &self.snapshot;
+        let mut spans = vec![Span::plain(format!(" syncgit · {} · ", self.repo.name))];
+        match &snapshot.branch {
+            Some(branch) => spans.push(Span::colored(branch.as_str(), Color::Green)),
+            None => spans.push(Span::colored(t!("tui.detached"), Color::Red)),
+        }
+        match &snapshot.upstream {
+            Some(upstream) => {
+                spans.push(Span::plain(format!(" → {}", upstream)));
+                let state = match (snapshot.ahead, snapshot.behind) {
+                    (0, 0) => Span::colored(format!("  {}", t!("tui.in_sync")), Color::Green),
+                    (ahead, behind) => Span::colored(format!("  ↑{} ↓{}", ahead, behind), Color::Yellow),
+                };
+                spans.push(state);
+            }
+            None => spans.push(Span::colored(format!("  {}", t!("tui.no_upstream")), Color::DarkGrey)),
+        }
+        if self.pathspec != "." {
+            spans.push(Span::plain(format!("  · {}", t!("main.subpath", path = self.pathspec))));
+        }
+        if snapshot.stashes > 0 {
+            spans.push(Span::plain(format!("  · {}", tn!("tui.stashes", snapshot.stashes))));
+        }
+        Line { spans, highlight: true, ..Default::default() }
+    }
+
+    /// The changes pane, and which of its lines holds the selected file.
+    fn changes_lines(&self) -> (Vec<Line>, Option<usize>) {
+        if self.snapshot.groups.is_empty() {
+            return (vec![Line::colored(t!("status.no_changes_subpath"), Color::DarkGrey)], None);
+        }
+
+        let mut lines = Vec::new();
+        let mut selected_row = None;
+        let mut index = 0;
+        for (group, files) in &self.snapshot.groups {
+            let name = if group == "." { t!("status.root_group") } else { format!("{}/", group) };
+            lines.push(Line { bold: true, ..Line::plain(name) });
+            for file in files {
+                let highlight = index == self.selected;
+                if highlight {
+                    selected_row = Some(lines.len());
+                }
+                lines.push(Line {
+                    spans: vec![
+                        Span::plain("  "),
+                        Span::colored(file.index.to_string(), status_color(file.index, true)),
+                        Span::colored(file.worktree.to_string(), status_color(file.worktree, false)),
+                        Span::plain(format!(" {}", file.display)),
+                    ],
+                    highlight,
+                    bold: false,
+                });
+                index += 1;
+            }
+        }
+        (lines, selected_row)
+    }
+
+    fn footer(&self) -> Line {
+        match (&self.mode, &self.message) {
+            (Mode::Commit(message), _) => Line {
+                spans: vec![
+                    Span::colored(t!("tui.commit_prompt"), Color::Cyan),
+                    Span::plain(format!(" {}█", message)),
+                ],
+                ..Default::default()
+            },
+            (Mode::Browse, Some((text, true))) => Line::colored(format!(" {}", text), Color::Red),
+            (Mode::Browse, Some((text, false))) => Line::colored(format!(" {}", text), Color::Green),
+            (Mode::Browse, None) => Line::colored(format!(" {}", t!("tui.keys")), Color::DarkGrey),
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Actions
+    // ------------------------------------------------------------------------
+
+    fn selected_file(&self) -> Option<&FileEntry> {
+        self.snapshot.files().nth(self.selected)
+    }
+
+    fn toggle_selected(&mut self, screen: &mut Screen) -> Result<()> {
+        let Some(file) = self.selected_file() else {
+            return Ok(());
+        };
+        if !file.fully_staged() {
+            let paths = file.paths.clone();
+            return self.stage(screen, paths);
+        }
+        let mut args = if self.repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", "HEAD"]).is_ok() {
+            vec!["restore", "--staged", "--"]
+        } else {
+            // Nothing committed yet, so there is no HEAD to restore from
+            vec!["rm", "--cached", "-q", "-r", "--"]
+        };
+        let paths = file.paths.clone();
+        args.extend(paths.iter().map(String::as_str));
+        self.repo.run_command_with_output(&args)?;
+        Ok(())
+    }
+
+    fn stage_all(&mut self, screen: &mut Screen) -> Result<()> {
+        self.stage(screen, vec![self.pathspec.clone()])?;
+        if self.message.is_none() {
+            self.info(t!("stage.added"));
+        }
+        Ok(())
+    }
+
+    /// Stages `paths`. Large files LFS won't store are raised on the normal
+    /// screen first, as the line-oriented flow does.
+    fn stage(&mut self, screen: &mut Screen, paths: Vec<String>) -> Result<()> {
+        let pathspecs: Vec<&str> = paths.iter().map(String::as_str).collect();
+        let large = lfs::has_large_files(self.repo, &pathspecs);
+        let add = move |repo: &GitRepo| -> Result<()> {
+            let pathspecs: Vec<&str> = paths.iter().map(String::as_str).collect();
+            lfs::check_large_files(repo, &pathspecs)?;
+            let mut args = vec!["add", "-A", "--"];
+            args.extend(pathspecs);
+            repo.run_command_with_output(&args)?;
+            Ok(())
+        };
+        if large {
+            self.suspended(screen, add)
+        } else {
+            add(self.repo)
+        }
+    }
+
+    fn start_commit(&mut self) {
+        if self.snapshot.staged_diff.is_empty() {
+            self.info(t!("tui.nothing_staged"));
+        } else {
+            self.mode = Mode::Commit(String::new());
+        }
+    }
+
+    /// Commits what's staged. Pre-commit checks, identity questions and the
+    /// co-author prompt run first on the normal screen, when there are any.
+    fn commit(&mut self, screen: &mut Screen, message: String) -> Result<()> {
+        if message.trim().is_empty() {
+            return Err(GitError::NoCommitMessage);
+        }
+        self.message = None;
+        let commit = move |repo: &GitRepo| -> Result<()> {
+            // The commit takes everything staged, not only what's on screen
+            tasks::before_commit(repo, ":/")?;
+            identity::check(repo)?;
+            let mut message = message;
+            let trailers = identity::co_author_trailers(repo)?;
+            if !trailers.is_empty() {
+                message = format!("{}\n\n{}", message.trim_end(), trailers.join("\n"));
+            }
+            // Use -- to prevent the message from being interpreted as an option
+            repo.run_command_with_output(&["commit", "-q", "-m", &message, "--"])?;
+            Ok(())
+        };
+        if tasks::has_tasks(self.repo, tasks::Hook::PreCommit) || identity::needs_prompt(self.repo) {
+            self.suspended(screen, commit)?;
+        } else {
+            commit(self.repo)?;
+        }
+        if self.message.is_none() {
+            self.info(t!("tui.committed"));
+        }
+        Ok(())
+    }
+
+    fn stash(&mut self) -> Result<()> {
+        if self.snapshot.groups.is_empty() {
+            self.info(t!("tui.nothing_to_stash"));
+            return Ok(());
+        }
+        self.repo.run_command_with_output(&["stash", "push", "--include-untracked"])?;
+        self.info(t!("tui.stashed"));
+        Ok(())
+    }
+
+    fn stash_pop(&mut self) -> Result<()> {
+        if self.snapshot.stashes == 0 {
+            self.info(t!("tui.no_stash"));
+            return Ok(());
+        }
+        self.repo.run_command_with_output(&["stash", "pop"])?;
+        self.info(t!("tui.stash_popped"));
+        Ok(())
+    }
+
+    fn fetch(&mut self) -> Result<()> {
+        // The dashboard owns the terminal: fail rather than prompt for a password
+        let output = self.repo.create_command(["fetch", "--quiet"])
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdin(std::process::Stdio::null())
+            .output()
+            .map_err(|e| GitError::io(t!("error.exec_git"), e))?;
+        if !output.status.success() {
+            return Err(GitError::from_command(&["fetch"], output.status, &String::from_utf8_lossy(&output.stderr)));
+        }
+        self.info(t!("tui.fetched"));
+        Ok(())
+    }
+
+    /// Leaves the dashboard to run `action` on the normal screen, then waits
+    /// for Enter before coming back.
+    fn suspended(&mut self, screen: &mut Screen, action: impl FnOnce(&GitRepo) -> Result<()>) -> Result<()> {
+        screen.leave().map_err(terminal_error)?;
+        UI::print_separator();
+        let result = action(self.repo);
+        if let Err(e) = &result {
+            println!("\n{}: {}", UI::center_text(&t!("main.warning")), e);
+        }
+        UI::print_separator();
+        println!("{}", UI::center_text(&t!("tui.press_enter")));
+        let _ = io::stdin().read_line(&mut String::new());
+        screen.resume().map_err(terminal_error)?;
+        match result {
+            Ok(()) => self.message = None,
+            Err(e) => self.error(&e),
+        }
+        Ok(())
+    }
+
+    /// Handles one key press; returns `false` to quit.
+    fn handle_key(&mut self, key: KeyEvent, screen: &mut Screen) -> Result<bool> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Ok(false);
+        }
+
+        if let Mode::Commit(message) = &mut self.mode {
+            match key.code {
+                KeyCode::Esc => {
+                    self.mode = Mode::Browse;
+                    self.info(t!("commit.cancelled"));
+                }
+                KeyCode::Enter => {
+                    let message = std::mem::take(message);
+                    self.mode = Mode::Browse;
+                    if let Err(e) = self.commit(screen, message) {
+                        self.error(&e);
+                    }
+                }
+                KeyCode::Backspace => {
+                    message.pop();
+                }
+                KeyCode::Char(c) => message.push(c),
+                _ => {}
+            }
+            self.refresh();
+            return Ok(true);
+        }
+
+        self.message = None;
+        let files = self.snapshot.files().count();
+        let result = match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+                Ok(())
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(files.saturating_sub(1));
+                Ok(())
+            }
+            KeyCode::PageUp => {
+                self.diff_offset = self.diff_offset.saturating_sub(self.diff_rows.max(1));
+                Ok(())
+            }
+            KeyCode::PageDown => {
+                let last = self.snapshot.staged_diff.len().saturating_sub(self.diff_rows);
+                self.diff_offset = (self.diff_offset + self.diff_rows.max(1)).min(last);
+                Ok(())
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => self.toggle_selected(screen),
+            KeyCode::Char('a') => self.stage_all(screen),
+            KeyCode::Char('c') => {
+                self.start_commit();
+                Ok(())
+            }
+            KeyCode::Char('s') => self.stash(),
+            KeyCode::Char('S') => self.stash_pop(),
+            KeyCode::Char('f') => self.fetch(),
+            KeyCode::Char('p') => self.suspended(screen, pull),
+            KeyCode::Char('P') => {
+                let set_upstream = self.snapshot.upstream.is_none();
+                self.suspended(screen, |repo| push(repo, set_upstream))
+            }
+            KeyCode::Char('d') => {
+                let source = if self.snapshot.staged_diff.is_empty() { diff::Source::Unstaged } else { diff::Source::Staged };
+                diff::run_viewer(screen, self.repo, &self.pathspec, source)
+            }
+            KeyCode::Char('r') => Ok(()),
+            _ => return Ok(true),
+        };
+        if let Err(e) = result {
+            self.error(&e);
+        }
+        self.refresh();
+        Ok(true)
+    }
+}
+
+/// Fetches, previews the incoming commits and pulls them once confirmed.
+fn pull(repo: &GitRepo) -> Result<()> {
+    println!("{}", UI::center_text(&t!("main.pulling")));
+    check_git_conflicts(repo)?;
+    if repo.run_command_with_output(&["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{u}"]).is_err() {
+        return Err(GitError::Other(t!("tui.no_upstream_to_pull")));
+    }
+    if !auth::push_remote_reachable(repo) {
+        return Err(GitError::NoInternet);
+    }
+
+    repo.run_command(&["fetch"])?;
+    let behind: usize = repo.run_command_with_output(&["rev-list", "--count", "HEAD..@{u}"])?.parse().unwrap_or(0);
+    if behind == 0 {
+        println!("{}", UI::center_text(&t!("sync.in_sync")));
+        return Ok(());
+    }
+    incoming::show(repo)?;
+    if !UI::prompt_yes_no(&t!("tui.pull_confirm")) {
+        return Err(GitError::Cancelled(t!("cancel.operation")));
+    }
+    repo.run_command(&["pull", "--no-edit"])?;
+    println!("{}", UI::center_text(&t!("sync.done")));
+    Ok(())
+}
+
+/// The normal flow's push, setting the upstream when there isn't one yet.
+fn push(repo: &GitRepo, set_upstream: bool) -> Result<()> {
+    println!("{}", UI::center_text(&t!("main.pushing")));
+    if !auth::push_remote_reachable(repo) {
+        println!("{}", UI::center_text(&t!("push.no_internet")));
+        push_queue::queue_for_later(repo);
+        return Ok(());
+    }
+    repo.configure_auth_remote()?;
+    push::push_current_branch(repo, set_upstream)?;
+    println!("{}", UI::center_text(&t!("push.success")));
+    Ok(())
+}
+
+/// `syncgit tui`: runs the dashboard until the user quits.
+pub fn run(repo: &GitRepo, pathspec: String) -> Result<()> {
+    let mut app = App::new(repo, pathspec);
+    let mut screen = Screen::enter().map_err(terminal_error)?;
+
+    loop {
+        app.draw(&mut screen.out).map_err(terminal_error)?;
+        if !event::poll(REFRESH_INTERVAL).map_err(terminal_error)? {
+            app.refresh();
+            continue;
+        }
+        match event::read().map_err(terminal_error)? {
+            Event::Resize(..) => {
+                queue!(screen.out, terminal::Clear(terminal::ClearType::All)).map_err(terminal_error)?;
+            }
+            // Windows also reports releases
+            Event::Key(key) if key.kind != KeyEventKind::Release && !app.handle_key(key, &mut screen)? => break,
+            _ => {}
+        }
+    }
+    screen.leave().map_err(terminal_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_status_lines() {
+        let cases: [(&str, char, char, &[&str], bool); 6] = [
+            ("M  src/main.rs", 'M', ' ', &["src/main.rs"], true),
+            (" M README.md", ' ', 'M', &["README.md"], false),
+            ("MM src/tui.rs", 'M', 'M', &["src/tui.rs"], false),
+            ("?? notes.txt", '?', '?', &["notes.txt"], false),
+            ("R  src/old.rs -> src/new.rs", 'R', ' ', &["src/old.rs", "src/new.rs"], true),
+            ("A  \"docs/caf\\303\\251.md\"", 'A', ' ', &["docs/café.md"], true),
+        ];
+        for (line, index, worktree, paths, staged) in cases {
+            let entry = FileEntry::parse(line).unwrap();
+            assert_eq!((entry.index, entry.worktree), (index, worktree), "{}", line);
+            assert_eq!(entry.paths, paths, "{}", line);
+            assert_eq!(entry.display, &line[3..], "{}", line);
+            assert_eq!(entry.fully_staged(), staged, "{}", line);
+        }
+        assert!(FileEntry::parse("M").is_none());
+    }
+
+    #[test]
+    fn unquotes_paths() {
+        let cases = [
+            ("plain.txt", "plain.txt"),
+            ("\"with space.txt\"", "with space.txt"),
+            ("\"caf\\303\\251.txt\"", "café.txt"),
+            ("\"tab\\there\"", "tab\there"),
+            ("\"line\\nbreak\"", "line\nbreak"),
+            ("\"say \\\"hi\\\".txt\"", "say \"hi\".txt"),
+            ("\"back\\\\slash\"", "back\\slash"),
+            ("\"unterminated", "\"unterminated"),
+        ];
+        for (quoted, expected) in cases {
+            assert_eq!(unquote(quoted), expected, "{}", quoted);
+        }
+    }
+
+    #[test]
+    fn measures_terminal_columns() {
+        let cases = [
+            ('a', 1),
+            ('─', 1),
+            ('é', 1),
+            ('\u{0301}', 0),
+            ('\u{FE0F}', 0),
+            ('中', 2),
+            ('한', 2),
+            ('✅', 2),
+            ('❌', 2),
+            ('🧩', 2),
+            ('🚀', 2),
+        ];
+        for (c, expected) in cases {
+            assert_eq!(char_width(c), expected, "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn colors_status_columns() {
+        assert_eq!(status_color('M', true), Color::Green);
+        assert_eq!(status_color('M', false), Color::Red);
+        assert_eq!(status_color('?', false), Color::DarkGrey);
+        assert_eq!(status_color('U', true), Color::Magenta);
+    }
+
+    #[test]
+    fn colors_diff_lines() {
+        let cases = [
+            ("diff --git a/x b/x", Some(Color::Yellow), true),
+            ("--- a/x", None, true),
+            ("+++ b/x", None, true),
+            ("@@ -1,2 +1,3 @@", Some(Color::Cyan), false),
+            ("+added", Some(Color::Green), false),
+            ("-removed", Some(Color::Red), false),
+            (" context", None, false),
+        ];
+        for (text, color, bold) in cases {
+            let line = diff_line(text);
+            assert_eq!((line.spans[0].color, line.bold), (color, bold), "{}", text);
+        }
+    }
+}
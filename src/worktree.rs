@@ -0,0 +1,263 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{GitError, Result};
+use crate::i18n::{t, tn};
+use crate::{GitRepo, UI};
+
+// ============================================================================
+// WORKTREES
+// ============================================================================
+
+pub struct Worktree {
+    pub path: PathBuf,
+    pub head: String,
+    /// `None` on a detached HEAD.
+    pub branch: Option<String>,
+    pub bare: bool,
+    pub locked: Option<String>,
+    /// Set when the directory is gone; `git worktree prune` forgets it.
+    pub prunable: Option<String>,
+}
+
+/// Every worktree of the repository, the main one first.
+pub fn list(repo: &GitRepo) -> Vec<Worktree> {
+    let output = repo.run_command_with_output(&["worktree", "list", "--porcelain"]).unwrap_or_default();
+    output.split("\n\n")
+        .filter_map(|block| {
+            let mut worktree: Option<Worktree> = None;
+            for line in block.lines() {
+                let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+                if key == "worktree" {
+                    worktree = Some(Worktree {
+                        path: PathBuf::from(value),
+                        head: String::new(),
+                        branch: None,
+                        bare: false,
+                        locked: None,
+                        prunable: None,
+                    });
+                    continue;
+                }
+                let Some(worktree) = worktree.as_mut() else { continue };
+                match key {
+                    "HEAD" => worktree.head = value.to_string(),
+                    "branch" => worktree.branch = Some(value.trim_start_matches("refs/heads/").to_string()),
+                    "bare" => worktree.bare = true,
+                    "locked" => worktree.locked = Some(value.to_string()),
+                    "prunable" => worktree.prunable = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+            worktree
+        })
+        .collect()
+}
+
+/// Git reports worktree paths with symlinks resolved. A worktree whose
+/// directory is gone still has to match, so fall back to its parent.
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path)
+        .ok()
+        .or_else(|| Some(fs::canonicalize(path.parent()?).ok()?.join(path.file_name()?)))
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    canonical(a) == canonical(b)
+}
+
+/// Whether `repo` is a linked worktree: its git dir is then
+/// `<common dir>/worktrees/<id>` rather than the common dir itself.
+fn is_linked(repo: &GitRepo) -> bool {
+    let git_dir = repo.run_command_with_output(&["rev-parse", "--absolute-git-dir"]);
+    let common_dir = repo.run_command_with_output(&["rev-parse", "--git-common-dir"]);
+    match (git_dir, common_dir) {
+        (Ok(git_dir), Ok(common_dir)) => !same_path(Path::new(&git_dir), &repo.root.join(common_dir)),
+        _ => false,
+    }
+}
+
+/// One line under the repository header when syncing from a linked worktree.
+pub fn print_note(repo: &GitRepo) {
+    if !is_linked(repo) {
+        return;
+    }
+    if let Some(main) = list(repo).first() {
+        println!("{}", UI::center_text(&t!("worktree.linked_note", main = main.path.display())));
+    }
+}
+
+fn changed_files(repo: &GitRepo) -> usize {
+    repo.run_command_with_output(&["status", "--porcelain"])
+        .map(|s| s.lines().count())
+        .unwrap_or(0)
+}
+
+fn describe(worktree: &Worktree, is_main: bool) -> String {
+    let mut states = Vec::new();
+    if is_main {
+        states.push(t!("worktree.state.main"));
+    }
+    if worktree.bare {
+        states.push(t!("worktree.state.bare"));
+    } else if worktree.prunable.is_some() {
+        states.push(t!("worktree.state.missing"));
+    } else if let Some(repo) = GitRepo::find_from_path(&worktree.path) {
+        states.push(match changed_files(&repo) {
+            0 => t!("worktree.state.clean"),
+            changed => tn!("worktree.state.changed", changed),
+        });
+        let counts = repo.run_command_with_output(&["rev-list", "--left-right", "--count", "HEAD...@{u}"]).unwrap_or_default();
+        let mut counts = counts.split_whitespace().map(|n| n.parse::<usize>().unwrap_or(0));
+        if let (Some(ahead), Some(behind)) = (counts.next(), counts.next()) {
+            if ahead > 0 {
+                states.push(tn!("worktree.state.ahead", ahead));
+            }
+            if behind > 0 {
+                states.push(tn!("worktree.state.behind", behind));
+            }
+        }
+    }
+    match worktree.locked.as_deref() {
+        Some("") => states.push(t!("worktree.state.locked")),
+        Some(reason) => states.push(t!("worktree.state.locked_reason", reason = reason)),
+        None => {}
+    }
+    states.join(", ")
+}
+
+// ============================================================================
+// COMMANDS
+// ============================================================================
+
+/// `syncgit worktree [list]`
+pub fn print_status() -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    let worktrees = list(&repo);
+    println!("{}", UI::center_text(&tn!("worktree.title", worktrees.len())));
+    UI::print_separator();
+    for (index, worktree) in worktrees.iter().enumerate() {
+        let branch = match &worktree.branch {
+            Some(branch) => branch.clone(),
+            None if worktree.bare => String::from("-"),
+            None => t!("worktree.detached", commit = &worktree.head[..7.min(worktree.head.len())]),
+        };
+        println!(
+            "{}",
+            t!(
+                "worktree.entry",
+                marker = if same_path(&worktree.path, &repo.root) { "▶" } else { " " },
+                branch = branch,
+                path = worktree.path.display(),
+                state = describe(worktree, index == 0)
+            )
+        );
+    }
+    UI::print_separator();
+    if worktrees.iter().any(|w| w.prunable.is_some()) {
+        println!("{}", UI::center_text(&t!("worktree.prune_hint")));
+    }
+    println!("{}", UI::center_text(&t!("worktree.add_hint")));
+    Ok(())
+}
+
+/// `<main worktree>-<branch>`, next to the main worktree.
+fn default_path(main: &Path, branch: &str) -> PathBuf {
+    let name = main.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let dir = format!("{}-{}", name, branch.replace('/', "-"));
+    main.parent().map(|parent| parent.join(&dir)).unwrap_or_else(|| PathBuf::from(dir))
+}
+
+/// The first remote with a branch called `branch`, as `<remote>/<branch>`.
+fn remote_branch(repo: &GitRepo, branch: &str) -> Option<String> {
+    let remotes = repo.run_command_with_output(&["remote"]).ok()?;
+    remotes.lines()
+        .map(|remote| format!("{}/{}", remote, branch))
+        .find(|candidate| {
+            repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", &format!("refs/remotes/{}", candidate)]).is_ok()
+        })
+}
+
+/// `syncgit worktree add <branch> [path]`: checks the branch out in a new
+/// worktree, tracking a remote branch of that name or creating it from
+/// the current HEAD if needed.
+pub fn add(branch: &str, path: Option<&str>) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    if repo.run_command_with_output(&["check-ref-format", "--branch", branch]).is_err() {
+        return Err(GitError::Usage(t!("worktree.invalid_branch", branch = branch)));
+    }
+
+    let worktrees = list(&repo);
+    if let Some(existing) = worktrees.iter().find(|w| w.branch.as_deref() == Some(branch)) {
+        return Err(GitError::Usage(t!("worktree.already_checked_out", branch = branch, path = existing.path.display())));
+    }
+
+    let path = match path {
+        Some(path) => env::current_dir().map_err(|e| GitError::io(t!("error.current_dir"), e))?.join(path),
+        None => default_path(worktrees.first().map_or(&repo.root, |main| &main.path), branch),
+    };
+    if path.exists() {
+        return Err(GitError::Usage(t!("worktree.path_exists", path = path.display())));
+    }
+    let path_arg = path.to_string_lossy().to_string();
+
+    if repo.run_command_with_output(&["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", branch)]).is_ok() {
+        repo.run_command(&["worktree", "add", &path_arg, branch])?;
+    } else if let Some(upstream) = remote_branch(&repo, branch) {
+        println!("{}", UI::center_text(&t!("worktree.tracking", upstream = upstream)));
+        repo.run_command(&["worktree", "add", "--track", "-b", branch, &path_arg, &upstream])?;
+    } else {
+        let from = repo.run_command_with_output(&["rev-parse", "--abbrev-ref", "HEAD"]).unwrap_or_else(|_| String::from("HEAD"));
+        if !UI::prompt_yes_no(&t!("worktree.create_branch", branch = branch, from = from)) {
+            return Err(GitError::Cancelled(t!("cancel.operation")));
+        }
+        repo.run_command(&["worktree", "add", "-b", branch, &path_arg])?;
+    }
+
+    println!("{}", UI::center_text(&t!("worktree.created", branch = branch, path = path.display())));
+    println!("{}", UI::center_text(&t!("worktree.cd_hint", path = path.display())));
+    Ok(())
+}
+
+/// `syncgit worktree remove <branch|path>`. The branch itself is kept.
+pub fn remove(target: &str) -> Result<()> {
+    let repo = GitRepo::from_current_dir()?;
+    let wanted = env::current_dir().map_err(|e| GitError::io(t!("error.current_dir"), e))?.join(target);
+    let worktrees = list(&repo);
+    let (index, worktree) = worktrees.iter()
+        .enumerate()
+        .find(|(_, w)| w.branch.as_deref() == Some(target) || same_path(&w.path, &wanted))
+        .ok_or_else(|| GitError::Usage(t!("worktree.not_found", target = target)))?;
+    if index == 0 {
+        return Err(GitError::Usage(t!("worktree.cannot_remove_main")));
+    }
+    if same_path(&worktree.path, &repo.root) {
+        return Err(GitError::Usage(t!("worktree.cannot_remove_current")));
+    }
+
+    if worktree.prunable.is_some() {
+        // The directory is already gone; only git's record of it is left
+        repo.run_command(&["worktree", "prune"])?;
+    } else {
+        let path_arg = worktree.path.to_string_lossy().to_string();
+        let mut args = vec!["worktree", "remove"];
+        let changed = GitRepo::find_from_path(&worktree.path).map_or(0, |w| changed_files(&w));
+        if changed > 0 {
+            println!("{}", UI::center_text(&tn!("worktree.remove_dirty", changed)));
+            if !UI::prompt_yes_no(&t!("worktree.remove_force")) {
+                return Err(GitError::Cancelled(t!("cancel.operation")));
+            }
+            args.push("--force");
+        }
+        args.push(&path_arg);
+        repo.run_command(&args)?;
+    }
+
+    println!("{}", UI::center_text(&t!("worktree.removed", path = worktree.path.display())));
+    if let Some(branch) = &worktree.branch {
+        println!("{}", UI::center_text(&t!("worktree.branch_kept", branch = branch)));
+    }
+    Ok(())
+}
@@ -0,0 +1,192 @@
+// Pushing must only require a GitHub token for HTTPS GitHub remotes. These
+// tests drive the binary against local bare repositories with every token
+// source (env vars, credential helpers, gh, netrc) switched off.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+struct Sandbox {
+    root: PathBuf,
+}
+
+impl Sandbox {
+    fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("syncgit-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("home")).unwrap();
+        Sandbox { root }
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.root.join(name)
+    }
+
+    /// A git command isolated from the user's global and system config.
+    fn git(&self, dir: &Path, args: &[&str]) -> String {
+        let output = self.isolate(&mut Command::new("git"))
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed: {}", args, String::from_utf8_lossy(&output.stderr));
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn isolate<'a>(&self, cmd: &'a mut Command) -> &'a mut Command {
+        cmd.env("HOME", self.path("home"))
+            .env("GIT_CONFIG_GLOBAL", self.path("home/.gitconfig"))
+            .env("GIT_CONFIG_NOSYSTEM", "1")
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .env("GH_CONFIG_DIR", self.path("home/gh"))
+            .env("NETRC", self.path("home/.netrc"))
+            .env("SYNCGIT_LANG", "en")
+            .env_remove("GITHUB_TOKEN")
+            .env_remove("GH_TOKEN")
+            .env_remove("GIT_TOKEN")
+            .env_remove("GITHUB_API_URL")
+            .env_remove("SSH_AUTH_SOCK")
+            .env_remove("XDG_STATE_HOME")
+            .env_remove("LOCALAPPDATA")
+    }
+
+    /// A working copy with one pushed commit and one that is still pending,
+    /// tracking `origin` at `remote_url`.
+    fn repo_with_pending_commit(&self, remote_url: &str, bare: Option<&Path>) -> PathBuf {
+        let work = self.path("work");
+        fs::create_dir_all(&work).unwrap();
+        self.git(&work, &["init", "-q", "-b", "main"]);
+        self.git(&work, &["config", "user.name", "Test"]);
+        self.git(&work, &["config", "user.email", "test@example.com"]);
+        fs::write(work.join("a.txt"), "one\n").unwrap();
+        self.git(&work, &["add", "."]);
+        self.git(&work, &["commit", "-q", "-m", "first"]);
+
+        match bare {
+            Some(bare) => {
+                self.git(&work, &["remote", "add", "origin", &bare.display().to_string()]);
+                self.git(&work, &["push", "-q", "-u", "origin", "main"]);
+                self.git(&work, &["remote", "set-url", "origin", remote_url]);
+            }
+            None => {
+                // Pretend the first commit was pushed to an unreachable remote
+                self.git(&work, &["remote", "add", "origin", remote_url]);
+                self.git(&work, &["update-ref", "refs/remotes/origin/main", "HEAD"]);
+                self.git(&work, &["config", "branch.main.remote", "origin"]);
+                self.git(&work, &["config", "branch.main.merge", "refs/heads/main"]);
+            }
+        }
+
+        fs::write(work.join("a.txt"), "one\ntwo\n").unwrap();
+        self.git(&work, &["commit", "-q", "-am", "second"]);
+        work
+    }
+
+    fn bare_remote(&self) -> PathBuf {
+        let bare = self.path("remote.git");
+        self.git(&self.root, &["init", "-q", "--bare", "-b", "main", &bare.display().to_string()]);
+        bare
+    }
+
+    /// Runs `syncgit` in `dir`, answering every prompt with "y".
+    fn syncgit(&self, dir: &Path) -> Output {
+        let mut child = self.isolate(&mut Command::new(env!("CARGO_BIN_EXE_syncgit")))
+            .current_dir(dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child.stdin.as_mut().unwrap().write_all("y\n".repeat(10).as_bytes()).unwrap();
+        child.wait_with_output().unwrap()
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+fn combined(output: &Output) -> String {
+    format!("{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr))
+}
+
+#[test]
+fn pending_commit_is_pushed_to_local_bare_remote_without_token() {
+    let sandbox = Sandbox::new("local-path");
+    let bare = sandbox.bare_remote();
+    let work = sandbox.repo_with_pending_commit(&bare.display().to_string(), Some(&bare));
+
+    let output = sandbox.syncgit(&work);
+    let text = combined(&output);
+
+    assert!(!text.contains("GitHub token not found"), "{}", text);
+    assert_eq!(sandbox.git(&bare, &["rev-parse", "main"]), sandbox.git(&work, &["rev-parse", "HEAD"]), "{}", text);
+}
+
+#[test]
+fn pending_commit_is_pushed_to_file_url_remote_without_token() {
+    let sandbox = Sandbox::new("file-url");
+    let bare = sandbox.bare_remote();
+    let url = format!("file://{}", bare.display());
+    let work = sandbox.repo_with_pending_commit(&url, Some(&bare));
+
+    let output = sandbox.syncgit(&work);
+    let text = combined(&output);
+
+    assert!(!text.contains("GitHub token not found"), "{}", text);
+    assert_eq!(sandbox.git(&bare, &["rev-parse", "main"]), sandbox.git(&work, &["rev-parse", "HEAD"]), "{}", text);
+}
+
+#[test]
+fn ssh_remote_does_not_ask_for_a_github_token() {
+    let sandbox = Sandbox::new("ssh");
+    let work = sandbox.repo_with_pending_commit("git@unreachable.invalid:owner/repo.git", None);
+
+    let output = sandbox.syncgit(&work);
+    let text = combined(&output);
+
+    assert!(!text.contains("GitHub token not found"), "{}", text);
+    assert_ne!(output.status.code(), Some(4), "{}", text);
+}
+
+#[test]
+fn github_https_remote_still_requires_a_token() {
+    let sandbox = Sandbox::new("github-https");
+    let work = sandbox.repo_with_pending_commit("https://github.com/owner/repo.git", None);
+
+    let output = sandbox.syncgit(&work);
+    let text = combined(&output);
+
+    assert!(text.contains("GitHub token not found"), "{}", text);
+    assert_eq!(output.status.code(), Some(4), "{}", text);
+}
+
+#[test]
+fn ahead_and_behind_counts_are_not_swapped() {
+    let sandbox = Sandbox::new("ahead-behind");
+    let bare = sandbox.bare_remote();
+    let work = sandbox.repo_with_pending_commit(&bare.display().to_string(), Some(&bare));
+
+    // Two commits on the remote that the working copy hasn't fetched yet
+    let other = sandbox.path("other");
+    sandbox.git(&sandbox.root, &["clone", "-q", &bare.display().to_string(), &other.display().to_string()]);
+    sandbox.git(&other, &["config", "user.name", "Other"]);
+    sandbox.git(&other, &["config", "user.email", "other@example.com"]);
+    for name in ["b.txt", "c.txt"] {
+        fs::write(other.join(name), "remote\n").unwrap();
+        sandbox.git(&other, &["add", name]);
+        sandbox.git(&other, &["commit", "-q", "-m", name]);
+    }
+    sandbox.git(&other, &["push", "-q", "origin", "main"]);
+    sandbox.git(&work, &["fetch", "-q", "origin"]);
+
+    let output = sandbox.syncgit(&work);
+    let text = combined(&output);
+
+    assert!(text.contains("1 commit ahead of remote"), "{}", text);
+    assert!(!text.contains("2 commits ahead of remote"), "{}", text);
+}